@@ -1,11 +1,19 @@
+#[macro_use]
+extern crate pyo3_built;
+
 use charmap::Error;
-use lazy_static::lazy_static;
-use pyo3::exceptions::{PyAssertionError, PyRuntimeError, PyTypeError};
+use pyo3::basic::CompareOp;
+use pyo3::exceptions::{
+    PyAssertionError, PyIndexError, PyOverflowError, PyRuntimeError, PyTypeError, PyValueError,
+};
+use pyo3::once_cell::GILOnceCell;
 use pyo3::types::{PyBytes, PyDict, PyInt, PyString, PyTuple};
 use pyo3::AsPyPointer;
-use pyo3::{ffi, prelude::*, wrap_pyfunction};
+use pyo3::{ffi, prelude::*, PyNumberProtocol, PyObjectProtocol, PySequenceProtocol};
+use std::collections::hash_map::DefaultHasher;
 use std::collections::HashSet;
-use std::sync::Mutex;
+use std::convert::TryFrom;
+use std::hash::{Hash, Hasher};
 
 #[allow(dead_code)]
 mod build {
@@ -13,42 +21,335 @@ mod build {
     include!(concat!(env!("OUT_DIR"), "/built.rs"));
 }
 
-lazy_static! {
-    static ref UNICODE_VERSION: Mutex<Option<charmap::UnicodeVersion>> = Mutex::new(None);
-    static ref CHARMAP_CACHE: Mutex<Option<PyObject>> = Mutex::new(None);
-    static ref CATEGORIES_CACHE: Mutex<Option<PyObject>> = Mutex::new(None);
+fn to_py_err(err: Error<'_>) -> PyErr {
+    match err {
+        Error::InvalidCategory(category) => {
+            PyTypeError::new_err(format!("'{}' is not a valid Unicode category.", category))
+        }
+        Error::InvalidCodepoints(left, right) => {
+            // Simulates behavior of the Python version of `charmap`
+            PyAssertionError::new_err(format!("{} < {}", left, right))
+        }
+        Error::InvalidVersion(_) => PyRuntimeError::new_err(err.to_string()),
+        Error::InvalidPropertyValue(_) => PyTypeError::new_err(err.to_string()),
+    }
 }
 
-#[inline]
-fn get_unicode_version() -> charmap::UnicodeVersion {
-    if let Ok(cached) = UNICODE_VERSION.lock() {
-        return cached.expect("Set during import");
-    };
-    panic!("Lock is poisoned!")
+/// Convert an iterable of categories to a vector.
+// The following conversion is here to match the original Python implementation behavior
+fn extract_categories(categories: Option<&PyAny>) -> PyResult<Option<Vec<&str>>> {
+    if let Some(cats) = categories {
+        if let Ok(items) = cats.extract::<Vec<&str>>() {
+            Ok(Some(items))
+        } else if let Ok(items) = cats.extract::<HashSet<&str>>() {
+            Ok(Some(items.into_iter().collect()))
+        } else {
+            Err(PyTypeError::new_err(
+                "Expected an iterable of valid Unicode categories",
+            ))
+        }
+    } else {
+        Ok(None)
+    }
 }
 
-/// A port of Hypothesis's internal "charmap" Python module. It provides utilities for working with
-/// Unicode intervals. Supports Unicode 9.0 - 13.0
-#[pymodule]
-fn charmap(py: Python, module: &PyModule) -> PyResult<()> {
-    let unicodedata = py.import("unicodedata")?;
-    let unicode_version: String = unicodedata.get("unidata_version")?.extract()?;
-    let version = match unicode_version.as_str() {
-        "9.0.0" => charmap::UnicodeVersion::V9,
-        "10.0.0" => charmap::UnicodeVersion::V10,
-        "11.0.0" => charmap::UnicodeVersion::V11,
-        "12.0.0" => charmap::UnicodeVersion::V12,
-        "12.1.0" => charmap::UnicodeVersion::V12_1,
-        "13.0.0" => charmap::UnicodeVersion::V13,
-        _ => return Err(PyRuntimeError::new_err("Unsupported unicode version")),
-    };
-
-    if let Ok(mut cached) = UNICODE_VERSION.lock() {
-        *cached = Some(version);
-    };
-
-    module.add("__build__", pyo3_built::pyo3_built!(py, build))?;
+/// Run a query with the GIL released, so other Python threads may proceed meanwhile.
+#[allow(clippy::too_many_arguments)]
+fn query_intervals(
+    py: Python<'_>,
+    version: charmap::UnicodeVersion,
+    exclude_categories: Option<&PyAny>,
+    include_categories: Option<&PyAny>,
+    min_codepoint: Option<&PyInt>,
+    max_codepoint: Option<&PyInt>,
+    include_characters: Option<&str>,
+    exclude_characters: Option<&str>,
+) -> PyResult<Vec<charmap::Interval>> {
+    let exclude_categories = extract_categories(exclude_categories)?;
+    let include_categories = extract_categories(include_categories)?;
+    let min_codepoint = min_codepoint.map(|x| x.extract::<u32>().unwrap_or(0));
+    let max_codepoint = max_codepoint.map(|x| x.extract::<u32>().unwrap_or(0));
+    py.allow_threads(|| {
+        version.query(
+            exclude_categories.as_deref(),
+            include_categories.as_deref(),
+            min_codepoint,
+            max_codepoint,
+            include_characters,
+            exclude_characters,
+        )
+    })
+    .map_err(to_py_err)
+}
+
+fn charmap_dict(py: Python<'_>, version: charmap::UnicodeVersion) -> &PyDict {
+    let result = unsafe { py.from_owned_ptr::<PyDict>(ffi::_PyDict_NewPresized(30isize)) };
+    for (name, value) in version.table() {
+        result
+            .set_item(name, PyTuple::new(py, *value))
+            .expect("Can't create a charmap");
+    }
+    result
+}
+
+/// UnicodeVersion(version)
+///
+/// >>> UnicodeVersion("12.1.0").query(include_categories=["Lu"], max_codepoint=128)
+/// IntervalSet([(65, 90)])
+///
+/// Unicode data of a specific version. Accepts versions in the `unicodedata.unidata_version`
+/// format, trailing zero components may be omitted.
+#[pyclass(name = "UnicodeVersion", module = "charmap")]
+#[text_signature = "(version)"]
+struct PyUnicodeVersion {
+    inner: charmap::UnicodeVersion,
+}
+
+#[pymethods]
+impl PyUnicodeVersion {
+    #[new]
+    fn new(version: &str) -> PyResult<Self> {
+        charmap::UnicodeVersion::try_from(version)
+            .map(|inner| PyUnicodeVersion { inner })
+            .map_err(|err| PyValueError::new_err(err.to_string()))
+    }
+
+    /// All supported Unicode versions, from the oldest to the newest.
+    #[staticmethod]
+    #[text_signature = "()"]
+    fn supported() -> Vec<PyUnicodeVersion> {
+        charmap::UnicodeVersion::ALL
+            .iter()
+            .map(|&inner| PyUnicodeVersion { inner })
+            .collect()
+    }
+
+    /// Version string, e.g. "12.1.0".
+    #[getter]
+    fn version(&self) -> &'static str {
+        self.inner.as_str()
+    }
+
+    /// Get a mapping from Unicode category abbreviations to their respective intervals.
+    #[text_signature = "($self)"]
+    fn charmap<'p>(&self, py: Python<'p>) -> &'p PyDict {
+        charmap_dict(py, self.inner)
+    }
 
+    /// Unicode categories in a normalised order.
+    #[text_signature = "($self)"]
+    fn categories<'p>(&self, py: Python<'p>) -> &'p PyTuple {
+        PyTuple::new(py, self.inner.categories())
+    }
+
+    /// Expand one-letter designations of a major class to include all subclasses.
+    #[text_signature = "($self, categories)"]
+    fn as_general_categories<'p>(
+        &self,
+        py: Python<'p>,
+        categories: Vec<&str>,
+    ) -> PyResult<&'p PyTuple> {
+        self.inner
+            .as_general_categories(&categories)
+            .map(|cats| PyTuple::new(py, cats))
+            .map_err(to_py_err)
+    }
+
+    /// Return an interval set covering the codepoints for all characters
+    /// that meet the input criteria.
+    #[text_signature = "($self, exclude_categories=(), include_categories=None, min_codepoint=None, max_codepoint=None, include_characters='', exclude_characters='')"]
+    #[allow(clippy::too_many_arguments)]
+    fn query(
+        &self,
+        py: Python<'_>,
+        exclude_categories: Option<&PyAny>,
+        include_categories: Option<&PyAny>,
+        min_codepoint: Option<&PyInt>,
+        max_codepoint: Option<&PyInt>,
+        include_characters: Option<&str>,
+        exclude_characters: Option<&str>,
+    ) -> PyResult<PyIntervalSet> {
+        let intervals = query_intervals(
+            py,
+            self.inner,
+            exclude_categories,
+            include_categories,
+            min_codepoint,
+            max_codepoint,
+            include_characters,
+            exclude_characters,
+        )?;
+        Ok(PyIntervalSet {
+            inner: charmap::IntervalSet::new(intervals).map_err(to_py_err)?,
+        })
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for PyUnicodeVersion {
+    fn __repr__(&self) -> String {
+        format!("UnicodeVersion({:?})", self.inner.as_str())
+    }
+
+    fn __str__(&self) -> &'static str {
+        self.inner.as_str()
+    }
+
+    fn __hash__(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.inner.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    fn __richcmp__(&self, other: PyRef<PyUnicodeVersion>, op: CompareOp) -> PyObject {
+        let py = other.py();
+        match op {
+            CompareOp::Eq => (self.inner == other.inner).into_py(py),
+            CompareOp::Ne => (self.inner != other.inner).into_py(py),
+            _ => py.NotImplemented(),
+        }
+    }
+}
+
+/// IntervalSet(intervals)
+///
+/// >>> IntervalSet([(48, 57), (65, 90)])[10]
+/// 65
+///
+/// Set of codepoints stored as sorted, non-overlapping intervals. Supports `|`, `&`, `-`, `in`,
+/// `len()` and indexing - the `i`-th element is the `i`-th smallest codepoint in the set.
+#[pyclass(name = "IntervalSet", module = "charmap")]
+#[text_signature = "(intervals)"]
+#[derive(Clone)]
+struct PyIntervalSet {
+    inner: charmap::IntervalSet,
+}
+
+#[pymethods]
+impl PyIntervalSet {
+    #[new]
+    fn new(intervals: Vec<(u32, u32)>) -> PyResult<Self> {
+        charmap::IntervalSet::new(intervals)
+            .map(|inner| PyIntervalSet { inner })
+            .map_err(|err| PyValueError::new_err(err.to_string()))
+    }
+
+    /// Create a set of all characters in the given string.
+    #[staticmethod]
+    #[text_signature = "(string)"]
+    fn from_string(string: &str) -> PyIntervalSet {
+        PyIntervalSet {
+            inner: charmap::IntervalSet::from_string(string),
+        }
+    }
+
+    /// Intervals that form the set.
+    #[getter]
+    fn intervals<'p>(&self, py: Python<'p>) -> &'p PyTuple {
+        PyTuple::new(py, self.inner.intervals())
+    }
+
+    /// Index of the given codepoint in the set.
+    #[text_signature = "($self, value)"]
+    fn index(&self, value: u32) -> PyResult<u64> {
+        self.inner
+            .index_of(value)
+            .ok_or_else(|| PyValueError::new_err(format!("{} is not in the set", value)))
+    }
+
+    fn union(&self, other: &PyIntervalSet) -> PyIntervalSet {
+        PyIntervalSet {
+            inner: self.inner.union(&other.inner),
+        }
+    }
+
+    fn intersection(&self, other: &PyIntervalSet) -> PyIntervalSet {
+        PyIntervalSet {
+            inner: self.inner.intersection(&other.inner),
+        }
+    }
+
+    fn difference(&self, other: &PyIntervalSet) -> PyIntervalSet {
+        PyIntervalSet {
+            inner: self.inner.difference(&other.inner),
+        }
+    }
+}
+
+#[pyproto]
+impl PyNumberProtocol for PyIntervalSet {
+    fn __or__(lhs: PyRef<PyIntervalSet>, rhs: PyRef<PyIntervalSet>) -> PyIntervalSet {
+        lhs.union(&rhs)
+    }
+
+    fn __and__(lhs: PyRef<PyIntervalSet>, rhs: PyRef<PyIntervalSet>) -> PyIntervalSet {
+        lhs.intersection(&rhs)
+    }
+
+    fn __sub__(lhs: PyRef<PyIntervalSet>, rhs: PyRef<PyIntervalSet>) -> PyIntervalSet {
+        lhs.difference(&rhs)
+    }
+}
+
+#[pyproto]
+impl PySequenceProtocol for PyIntervalSet {
+    fn __len__(&self) -> PyResult<usize> {
+        usize::try_from(self.inner.len()).map_err(|_| PyOverflowError::new_err("Set is too big"))
+    }
+
+    fn __getitem__(&self, idx: isize) -> PyResult<u32> {
+        // Python already adds the length to negative indices, remaining ones are out of range
+        u64::try_from(idx)
+            .ok()
+            .and_then(|index| self.inner.get(index))
+            .ok_or_else(|| PyIndexError::new_err("IntervalSet index out of range"))
+    }
+
+    fn __contains__(&self, item: &PyAny) -> PyResult<bool> {
+        if let Ok(codepoint) = item.extract::<u32>() {
+            return Ok(self.inner.contains(codepoint));
+        }
+        let string: &str = item.extract()?;
+        let mut chars = string.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Ok(self.inner.contains(c as u32)),
+            _ => Err(PyTypeError::new_err(
+                "Expected a codepoint or a single character",
+            )),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for PyIntervalSet {
+    fn __repr__(&self) -> String {
+        format!("IntervalSet({:?})", self.inner.intervals())
+    }
+
+    fn __richcmp__(&self, other: PyRef<PyIntervalSet>, op: CompareOp) -> PyObject {
+        let py = other.py();
+        match op {
+            CompareOp::Eq => (self.inner == other.inner).into_py(py),
+            CompareOp::Ne => (self.inner != other.inner).into_py(py),
+            _ => py.NotImplemented(),
+        }
+    }
+}
+
+/// Per-interpreter state of the `charmap` module.
+///
+/// Every interpreter initializes the module separately, and module-level functions are bound
+/// methods of its own state instance. Therefore, cached objects are never shared between
+/// interpreters, and the GIL guards their initialization.
+#[pyclass(module = "charmap")]
+struct ModuleState {
+    version: charmap::UnicodeVersion,
+    charmap: GILOnceCell<Py<PyDict>>,
+    categories: GILOnceCell<Py<PyTuple>>,
+}
+
+#[pymethods]
+impl ModuleState {
     /// charmap()
     ///
     /// >>> charmap()['Co']
@@ -59,28 +360,14 @@ fn charmap(py: Python, module: &PyModule) -> PyResult<()> {
     /// charmap : dict[str, tuple]
     ///
     /// Get a mapping from Unicode category abbreviations to their respective intervals.
-    #[pyfunction]
     #[text_signature = "()"]
-    fn charmap(py: Python) -> PyResult<&PyDict> {
-        if let Ok(cached) = CHARMAP_CACHE.lock() {
-            if let Some(obj) = cached.as_ref() {
-                return Ok(unsafe { py.from_borrowed_ptr::<PyDict>(obj.as_ptr()) });
-            }
-        };
-        let result = unsafe { py.from_owned_ptr::<PyDict>(ffi::_PyDict_NewPresized(30isize)) };
-        for (name, value) in get_unicode_version().table() {
-            result
-                .set_item(name, PyTuple::new(py, *value))
-                .expect("Can't create a charmap");
-        }
-        if let Ok(mut cached) = CHARMAP_CACHE.lock() {
-            *cached = Some(result.into());
-        };
-        Ok(result)
+    fn charmap<'p>(&self, py: Python<'p>) -> &'p PyDict {
+        self.charmap
+            .get_or_init(py, || charmap_dict(py, self.version).into())
+            .clone_ref(py)
+            .into_ref(py)
     }
 
-    module.add_wrapped(wrap_pyfunction!(charmap))?;
-
     /// categories()
     ///
     /// >>> categories()
@@ -91,22 +378,13 @@ fn charmap(py: Python, module: &PyModule) -> PyResult<()> {
     /// categories : tuple
     ///
     /// Unicode categories in a normalised order.
-    #[pyfunction]
     #[text_signature = "()"]
-    fn categories(py: Python) -> PyResult<&PyTuple> {
-        if let Ok(cached) = CATEGORIES_CACHE.lock() {
-            if let Some(obj) = cached.as_ref() {
-                return Ok(unsafe { py.from_borrowed_ptr::<PyTuple>(obj.as_ptr()) });
-            }
-        };
-        let categories = get_unicode_version().categories();
-        let result = PyTuple::new(py, categories);
-        if let Ok(mut cached) = CATEGORIES_CACHE.lock() {
-            *cached = Some(result.into());
-        };
-        Ok(result)
+    fn categories<'p>(&self, py: Python<'p>) -> &'p PyTuple {
+        self.categories
+            .get_or_init(py, || PyTuple::new(py, self.version.categories()).into())
+            .clone_ref(py)
+            .into_ref(py)
     }
-    module.add_wrapped(wrap_pyfunction!(categories))?;
 
     /// as_general_categories(categories, name="cats")
     ///
@@ -115,9 +393,9 @@ fn charmap(py: Python, module: &PyModule) -> PyResult<()> {
     /// as_general_categories : tuple
     ///
     /// Expand one-letter designations of a major class to include all subclasses.
-    #[pyfunction]
     #[text_signature = "(categories, name=\"cats\")"]
     fn as_general_categories<'p>(
+        &self,
         py: Python<'p>,
         categories: Vec<&PyString>,
         name: Option<&str>,
@@ -143,7 +421,7 @@ fn charmap(py: Python, module: &PyModule) -> PyResult<()> {
             }
         }
 
-        get_unicode_version()
+        self.version
             .as_general_categories(out.as_slice())
             .map_err(|err| {
                 let name = name.unwrap_or("cats");
@@ -155,11 +433,12 @@ fn charmap(py: Python, module: &PyModule) -> PyResult<()> {
                     Error::InvalidCodepoints(left, right) => {
                         PyAssertionError::new_err(format!("{} < {}", left, right))
                     }
+                    Error::InvalidVersion(_) => PyRuntimeError::new_err(err.to_string()),
+                    Error::InvalidPropertyValue(_) => PyTypeError::new_err(err.to_string()),
                 }
             })
             .map(|cats| PyTuple::new(py, cats))
     }
-    module.add_wrapped(wrap_pyfunction!(as_general_categories))?;
 
     /// query(exclude_categories=(), include_categories=None, min_codepoint=None, max_codepoint=None, include_characters='', exclude_characters='')
     ///
@@ -169,9 +448,10 @@ fn charmap(py: Python, module: &PyModule) -> PyResult<()> {
     ///
     /// Return a tuple of intervals covering the codepoints for all characters
     /// that meet the input criteria.
-    #[pyfunction]
     #[text_signature = "(exclude_categories=(), include_categories=None, min_codepoint=None, max_codepoint=None, include_characters='', exclude_characters='')"]
+    #[allow(clippy::too_many_arguments)]
     fn query<'p>(
+        &self,
         py: Python<'p>,
         exclude_categories: Option<&PyAny>,
         include_categories: Option<&PyAny>,
@@ -180,58 +460,45 @@ fn charmap(py: Python, module: &PyModule) -> PyResult<()> {
         include_characters: Option<&str>,
         exclude_characters: Option<&str>,
     ) -> PyResult<&'p PyTuple> {
-        // The following conversion is here to match the original Python implementation behavior
-        let exclude_categories = if let Some(cats) = exclude_categories {
-            if let Ok(items) = cats.extract::<Vec<&str>>() {
-                Some(items)
-            } else if let Ok(items) = cats.extract::<HashSet<&str>>() {
-                Some(items.into_iter().collect())
-            } else {
-                return Err(PyTypeError::new_err(
-                    "Expected an iterable of valid Unicode categories",
-                ));
-            }
-        } else {
-            None
-        };
-        let include_categories = if let Some(cats) = include_categories {
-            if let Ok(items) = cats.extract::<Vec<&str>>() {
-                Some(items)
-            } else if let Ok(items) = cats.extract::<HashSet<&str>>() {
-                Some(items.into_iter().collect())
-            } else {
-                return Err(PyTypeError::new_err(
-                    "Expected an iterable of valid Unicode categories",
-                ));
-            }
-        } else {
-            None
-        };
-        let min_codepoint = min_codepoint.map(|x| x.extract::<u32>().unwrap_or(0));
-        let max_codepoint = max_codepoint.map(|x| x.extract::<u32>().unwrap_or(0));
-        let result = get_unicode_version().query(
-            exclude_categories.as_deref(),
-            include_categories.as_deref(),
+        let result = query_intervals(
+            py,
+            self.version,
+            exclude_categories,
+            include_categories,
             min_codepoint,
             max_codepoint,
             include_characters,
             exclude_characters,
-        );
-        match result {
-            Ok(result) => Ok(PyTuple::new(py, result)),
-            Err(e) => match e {
-                Error::InvalidCategory(category) => Err(PyTypeError::new_err(format!(
-                    "'{}' is not a valid Unicode category.",
-                    category
-                ))),
-                Error::InvalidCodepoints(left, right) => {
-                    // Simulates behavior of the Python version of `charmap`
-                    Err(PyAssertionError::new_err(format!("{} < {}", left, right)))
-                }
-            },
-        }
+        )?;
+        Ok(PyTuple::new(py, result))
+    }
+}
+
+/// A port of Hypothesis's internal "charmap" Python module. It provides utilities for working with
+/// Unicode intervals. Supports Unicode 9.0 - 13.0
+#[pymodule]
+fn charmap(py: Python, module: &PyModule) -> PyResult<()> {
+    let unicodedata = py.import("unicodedata")?;
+    let unicode_version: String = unicodedata.get("unidata_version")?.extract()?;
+    let version = charmap::UnicodeVersion::try_from(unicode_version.as_str())
+        .map_err(|_| PyRuntimeError::new_err("Unsupported unicode version"))?;
+
+    module.add("__build__", pyo3_built!(py, build))?;
+    module.add("unicode_version", version.as_str())?;
+
+    let state = PyCell::new(
+        py,
+        ModuleState {
+            version,
+            charmap: GILOnceCell::new(),
+            categories: GILOnceCell::new(),
+        },
+    )?;
+    for name in &["charmap", "categories", "as_general_categories", "query"] {
+        module.add(name, state.getattr(name)?)?;
     }
-    module.add_wrapped(wrap_pyfunction!(query))?;
+    module.add_class::<PyUnicodeVersion>()?;
+    module.add_class::<PyIntervalSet>()?;
 
     Ok(())
 }
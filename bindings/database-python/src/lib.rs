@@ -19,15 +19,35 @@
     unused_qualifications,
     unreachable_pub,
     variant_size_differences,
-    clippy::integer_arithmetic,
+    clippy::arithmetic_side_effects,
     clippy::unwrap_used
 )]
 use database as db;
-use database::ExampleDatabase;
-use pyo3::exceptions::PyValueError;
+use database::{DatabaseError, ExampleDatabase};
+use pyo3::exceptions::{PyOSError, PyValueError};
 use pyo3::prelude::*;
 use pyo3::types::PyBytes;
-use pyo3::PyIterProtocol;
+use pyo3::{PyIterProtocol, PyObjectProtocol};
+
+/// Convert a database error to `OSError`.
+///
+/// Errors carrying an OS error code are created as `OSError(errno, strerror, filename)`, therefore
+/// Python picks a matching subclass, e.g. `PermissionError`.
+fn to_py_err(error: DatabaseError) -> PyErr {
+    let message = error.to_string();
+    match error {
+        DatabaseError::Io(path, source) | DatabaseError::PermissionDenied(path, source) => {
+            match source.raw_os_error() {
+                Some(errno) => {
+                    let filename = path.to_string_lossy().into_owned();
+                    PyOSError::new_err((errno, source.to_string(), filename))
+                }
+                None => PyOSError::new_err(message),
+            }
+        }
+        DatabaseError::Corrupted(_, _) => PyOSError::new_err(message),
+    }
+}
 
 #[pyclass(module = "database")]
 struct InMemoryExampleDatabase {
@@ -47,30 +67,27 @@ impl InMemoryExampleDatabase {
     /// Save `value` under `key`.
     /// If this value is already present for this key, silently do nothing
     #[inline]
-    fn save(&mut self, key: &[u8], value: &[u8]) -> PyResult<()> {
-        self.inner.save(key, value);
-        Ok(())
+    fn save(&self, key: &[u8], value: &[u8]) -> PyResult<()> {
+        self.inner.save(key, value).map_err(to_py_err)
     }
     /// Remove this `value` from this `key`.
     /// If this value is not present, silently do nothing.
     #[inline]
-    fn delete(&mut self, key: &[u8], value: &[u8]) -> PyResult<()> {
-        self.inner.delete(key, value);
-        Ok(())
+    fn delete(&self, key: &[u8], value: &[u8]) -> PyResult<()> {
+        self.inner.delete(key, value).map_err(to_py_err)
     }
 
     /// Move `value` from key `src` to key `dst`.
     #[inline]
-    fn r#move(&mut self, src: &[u8], dst: &[u8], value: &[u8]) -> PyResult<()> {
-        self.inner.r#move(src, dst, value);
-        Ok(())
+    fn r#move(&self, src: &[u8], dst: &[u8], value: &[u8]) -> PyResult<()> {
+        self.inner.r#move(src, dst, value).map_err(to_py_err)
     }
 
     /// Return an iterable over all values matching this key.
     #[inline]
     fn fetch(&self, key: &[u8]) -> PyResult<InMemoryFetch> {
         Ok(InMemoryFetch {
-            inner: self.inner.fetch(key).into_iter(),
+            inner: self.inner.fetch(key).map_err(to_py_err)?.into_iter(),
         })
     }
 }
@@ -118,27 +135,28 @@ impl DirectoryBasedExampleDatabase {
 
     /// Save `value` under `key`.
     /// If this value is already present for this key, silently do nothing
-    fn save(&mut self, key: &[u8], value: &[u8]) -> PyResult<()> {
-        self.inner.save(key, value);
-        Ok(())
+    fn save(&self, py: Python, key: &[u8], value: &[u8]) -> PyResult<()> {
+        // Filesystem operations don't need the GIL, other Python threads may run meanwhile
+        py.allow_threads(|| self.inner.save(key, value))
+            .map_err(to_py_err)
     }
     /// Remove this `value` from this `key`.
     /// If this value is not present, silently do nothing.
-    fn delete(&mut self, key: &[u8], value: &[u8]) -> PyResult<()> {
-        self.inner.delete(key, value);
-        Ok(())
+    fn delete(&self, py: Python, key: &[u8], value: &[u8]) -> PyResult<()> {
+        py.allow_threads(|| self.inner.delete(key, value))
+            .map_err(to_py_err)
     }
 
     /// Move `value` from key `src` to key `dst`.
-    fn r#move(&mut self, src: &[u8], dst: &[u8], value: &[u8]) -> PyResult<()> {
-        self.inner.r#move(src, dst, value);
-        Ok(())
+    fn r#move(&self, py: Python, src: &[u8], dst: &[u8], value: &[u8]) -> PyResult<()> {
+        py.allow_threads(|| self.inner.r#move(src, dst, value))
+            .map_err(to_py_err)
     }
 
     /// Return an iterable over all values matching this key.
     fn fetch(&self, key: &[u8]) -> PyResult<DirectoryFetch> {
         Ok(DirectoryFetch {
-            inner: self.inner.fetch(key).into_iter(),
+            inner: self.inner.fetch(key).map_err(to_py_err)?.into_iter(),
         })
     }
 
@@ -168,9 +186,53 @@ impl PyIterProtocol for DirectoryFetch {
     }
 }
 
+/// A wrapper to make the given database read-only.
+///
+/// Mirrors `database::ReadOnlyDatabase`, but wraps any Python object with the example database
+/// interface, e.g. databases from this module or from `hypothesis.database`.
+#[pyclass(module = "database")]
+struct ReadOnlyDatabase {
+    wrapped: PyObject,
+}
+
+#[pymethods]
+impl ReadOnlyDatabase {
+    #[new]
+    fn new(db: PyObject) -> Self {
+        Self { wrapped: db }
+    }
+
+    /// Do nothing, the database is read-only.
+    fn save(&self, _key: &[u8], _value: &[u8]) {}
+
+    /// Do nothing, the database is read-only.
+    fn delete(&self, _key: &[u8], _value: &[u8]) {}
+
+    /// Do nothing, the database is read-only.
+    fn r#move(&self, _src: &[u8], _dst: &[u8], _value: &[u8]) {}
+
+    /// Return an iterable over all values matching this key in the wrapped database.
+    fn fetch(&self, py: Python, key: &[u8]) -> PyResult<PyObject> {
+        self.wrapped
+            .call_method1(py, "fetch", (PyBytes::new(py, key),))
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for ReadOnlyDatabase {
+    fn __repr__(&self) -> PyResult<String> {
+        let py = unsafe { Python::assume_gil_acquired() };
+        Ok(format!(
+            "ReadOnlyDatabase({})",
+            self.wrapped.as_ref(py).repr()?
+        ))
+    }
+}
+
 #[pymodule]
 fn database(_: Python, module: &PyModule) -> PyResult<()> {
     module.add_class::<InMemoryExampleDatabase>()?;
     module.add_class::<DirectoryBasedExampleDatabase>()?;
+    module.add_class::<ReadOnlyDatabase>()?;
     Ok(())
 }
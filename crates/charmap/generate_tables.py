@@ -1,9 +1,11 @@
+import os
 import sys
 import tempfile
 import urllib
 import subprocess
 import urllib.request
 import zipfile
+from typing import Dict, Iterator, Set, Tuple
 
 
 CATEGORY_ABBREVIATIONS = {
@@ -40,6 +42,333 @@ CATEGORY_ABBREVIATIONS = {
 }
 
 
+EAST_ASIAN_WIDTH_NAMES = {
+    "A": "AMBIGUOUS",
+    "F": "FULLWIDTH",
+    "H": "HALFWIDTH",
+    "N": "NEUTRAL",
+    "Na": "NARROW",
+    "W": "WIDE",
+}
+
+BIDI_CLASS_NAMES = {
+    "AL": "ARABIC_LETTER",
+    "AN": "ARABIC_NUMBER",
+    "B": "PARAGRAPH_SEPARATOR",
+    "BN": "BOUNDARY_NEUTRAL",
+    "CS": "COMMON_SEPARATOR",
+    "EN": "EUROPEAN_NUMBER",
+    "ES": "EUROPEAN_SEPARATOR",
+    "ET": "EUROPEAN_TERMINATOR",
+    "FSI": "FIRST_STRONG_ISOLATE",
+    "L": "LEFT_TO_RIGHT",
+    "LRE": "LEFT_TO_RIGHT_EMBEDDING",
+    "LRI": "LEFT_TO_RIGHT_ISOLATE",
+    "LRO": "LEFT_TO_RIGHT_OVERRIDE",
+    "NSM": "NONSPACING_MARK",
+    "ON": "OTHER_NEUTRAL",
+    "PDF": "POP_DIRECTIONAL_FORMAT",
+    "PDI": "POP_DIRECTIONAL_ISOLATE",
+    "R": "RIGHT_TO_LEFT",
+    "RLE": "RIGHT_TO_LEFT_EMBEDDING",
+    "RLI": "RIGHT_TO_LEFT_ISOLATE",
+    "RLO": "RIGHT_TO_LEFT_OVERRIDE",
+    "S": "SEGMENT_SEPARATOR",
+    "WS": "WHITE_SPACE",
+}
+
+
+CASE_PROPERTY_NAMES = {
+    "Case_Unstable": "CASE_UNSTABLE",
+    "Changes_When_Casefolded": "CHANGES_WHEN_CASEFOLDED",
+    "Changes_When_Lowercased": "CHANGES_WHEN_LOWERCASED",
+    "Changes_When_Titlecased": "CHANGES_WHEN_TITLECASED",
+    "Changes_When_Uppercased": "CHANGES_WHEN_UPPERCASED",
+    "Multi_Codepoint_Casefold": "MULTI_CODEPOINT_CASEFOLD",
+    "Multi_Codepoint_Lowercase": "MULTI_CODEPOINT_LOWERCASE",
+    "Multi_Codepoint_Titlecase": "MULTI_CODEPOINT_TITLECASE",
+    "Multi_Codepoint_Uppercase": "MULTI_CODEPOINT_UPPERCASE",
+}
+
+GRAPHEME_CLUSTER_BREAK_NAMES = {
+    "CR": "CR",
+    "Control": "CONTROL",
+    "E_Base": "E_BASE",
+    "E_Base_GAZ": "E_BASE_GAZ",
+    "E_Modifier": "E_MODIFIER",
+    "Extend": "EXTEND",
+    "Glue_After_Zwj": "GLUE_AFTER_ZWJ",
+    "L": "L",
+    "LF": "LF",
+    "LV": "LV",
+    "LVT": "LVT",
+    "Prepend": "PREPEND",
+    "Regional_Indicator": "REGIONAL_INDICATOR",
+    "SpacingMark": "SPACING_MARK",
+    "T": "T",
+    "V": "V",
+    "ZWJ": "ZWJ",
+}
+
+EMOJI_SEQUENCE_KIND_NAMES = {
+    "Flag": "FLAG",
+    "Keycap": "KEYCAP",
+    "Modifier": "MODIFIER",
+    "Presentation": "PRESENTATION",
+    "Tag": "TAG",
+    "ZWJ": "ZWJ",
+}
+
+# Emoji versions released together with the Unicode ones
+EMOJI_VERSIONS = {
+    "9.0.0": "4.0",
+    "10.0.0": "5.0",
+    "11.0.0": "11.0",
+    "12.0.0": "12.0",
+    "12.1.0": "12.1",
+    "13.0.0": "13.0",
+}
+
+QUICK_CHECK_NAMES = {
+    "NFC_M": "NFC_MAYBE",
+    "NFC_N": "NFC_NO",
+    "NFD_M": "NFD_MAYBE",
+    "NFD_N": "NFD_NO",
+    "NFKC_M": "NFKC_MAYBE",
+    "NFKC_N": "NFKC_NO",
+    "NFKD_M": "NFKD_MAYBE",
+    "NFKD_N": "NFKD_NO",
+}
+
+
+def parse_ucd_file(path: str) -> Iterator[Tuple[int, int, str]]:
+    """Yield `(first, last, value)` from a UCD file with `XXXX..YYYY ; value # comment` lines."""
+    with open(path, "r", encoding="utf-8") as fd:
+        for line in fd:
+            line = line.split("#", 1)[0].strip()
+            if not line:
+                continue
+            codepoints, value = (part.strip() for part in line.split(";")[:2])
+            first, _, last = codepoints.partition("..")
+            yield int(first, 16), int(last or first, 16), value
+
+
+def assigned_codepoints(directory: str) -> Set[int]:
+    out = set()
+    for first, last, category in parse_ucd_file(os.path.join(directory, "extracted", "DerivedGeneralCategory.txt")):
+        if category != "Cn":
+            out.update(range(first, last + 1))
+    return out
+
+
+def write_table(filename: str, command: str, unicode_version: str, codepoints: Dict[str, Set[int]], names: Dict[str, str]) -> None:
+    """Write sets of codepoints as intervals, one constant per name."""
+    intervals: Dict[str, list] = {value: [] for value in names}
+    for value, group in intervals.items():
+        for codepoint in sorted(codepoints.get(value, ())):
+            if group and group[-1][1] == codepoint - 1:
+                group[-1][1] = codepoint
+            else:
+                group.append([codepoint, codepoint])
+    with open(filename, "w") as fd:
+        fd.write("// DO NOT EDIT THIS FILE. IT WAS AUTOMATICALLY GENERATED BY:\n")
+        fd.write("//\n")
+        fd.write(f"//   {command}\n")
+        fd.write("//\n")
+        fd.write(f"// Unicode version: {unicode_version}.\n\n")
+        fd.write("pub const BY_NAME: &'static [(&'static str, &'static [(u32, u32)])] = &[\n")
+        for value in sorted(names):
+            fd.write(f'    ("{value}", {names[value]}),\n')
+        fd.write("];\n")
+        for value in sorted(names):
+            fd.write(f"\npub const {names[value]}: &'static [(u32, u32)] = &[\n")
+            for first, last in intervals[value]:
+                fd.write(f"    ({first}, {last}),\n")
+            fd.write("];\n")
+
+
+def write_property_table(filename: str, command: str, unicode_version: str, values: Dict[int, str], names: Dict[str, str]) -> None:
+    """Write intervals of codepoints grouped by their property values.
+
+    Only assigned codepoints are present in `values` - Python's `unicodedata` reports no meaningful
+    values for unassigned ones, therefore they are not a part of any interval.
+    """
+    codepoints: Dict[str, Set[int]] = {}
+    for codepoint, value in values.items():
+        codepoints.setdefault(value, set()).add(codepoint)
+    write_table(filename, command, unicode_version, codepoints, names)
+
+
+def generate_property_tables(directory: str, unicode_version: str) -> None:
+    version = unicode_version.replace(".", "_")
+    command = f"python generate_tables.py {unicode_version}"
+    assigned = assigned_codepoints(directory)
+    # Codepoints missing in `EastAsianWidth.txt` are `N`
+    widths = {codepoint: "N" for codepoint in assigned}
+    for first, last, value in parse_ucd_file(os.path.join(directory, "EastAsianWidth.txt")):
+        for codepoint in range(first, last + 1):
+            if codepoint in assigned:
+                widths[codepoint] = value
+    write_property_table(
+        f"src/tables/east_asian_width/v{version}.rs", command, unicode_version, widths, EAST_ASIAN_WIDTH_NAMES
+    )
+    classes = {}
+    for first, last, value in parse_ucd_file(os.path.join(directory, "extracted", "DerivedBidiClass.txt")):
+        for codepoint in range(first, last + 1):
+            if codepoint in assigned:
+                classes[codepoint] = value
+    write_property_table(f"src/tables/bidi_class/v{version}.rs", command, unicode_version, classes, BIDI_CLASS_NAMES)
+
+
+def parse_case_mappings(directory: str) -> Dict[str, Dict[int, Tuple[int, ...]]]:
+    """Full unconditional case mappings of all codepoints that have any."""
+    mappings: Dict[str, Dict[int, Tuple[int, ...]]] = {"upper": {}, "lower": {}, "title": {}, "fold": {}}
+    with open(os.path.join(directory, "UnicodeData.txt"), "r", encoding="utf-8") as fd:
+        for line in fd:
+            fields = line.split(";")
+            codepoint = int(fields[0], 16)
+            for kind, field in (("upper", fields[12]), ("lower", fields[13]), ("title", fields[14])):
+                if field.strip():
+                    mappings[kind][codepoint] = (int(field, 16),)
+            # Titlecase mapping defaults to the uppercase one
+            if not fields[14].strip() and fields[12].strip():
+                mappings["title"][codepoint] = (int(fields[12], 16),)
+    with open(os.path.join(directory, "SpecialCasing.txt"), "r", encoding="utf-8") as fd:
+        for line in fd:
+            fields = [field.strip() for field in line.split("#", 1)[0].split(";")]
+            # Conditional mappings (e.g. final sigma) depend on the context and are skipped
+            if len(fields) < 5 or fields[4]:
+                continue
+            codepoint = int(fields[0], 16)
+            for kind, field in (("lower", fields[1]), ("title", fields[2]), ("upper", fields[3])):
+                mappings[kind][codepoint] = tuple(int(value, 16) for value in field.split())
+    with open(os.path.join(directory, "CaseFolding.txt"), "r", encoding="utf-8") as fd:
+        for line in fd:
+            fields = [field.strip() for field in line.split("#", 1)[0].split(";")]
+            if len(fields) < 3 or fields[1] not in ("C", "F"):
+                continue
+            mappings["fold"][int(fields[0], 16)] = tuple(int(value, 16) for value in fields[2].split())
+    return mappings
+
+
+def generate_case_tables(directory: str, unicode_version: str) -> None:
+    version = unicode_version.replace(".", "_")
+    mappings = parse_case_mappings(directory)
+
+    def apply(kind: str, codepoints: Tuple[int, ...]) -> Tuple[int, ...]:
+        # Every codepoint is mapped on its own, without any context
+        return tuple(value for codepoint in codepoints for value in mappings[kind].get(codepoint, (codepoint,)))
+
+    properties: Dict[str, Set[int]] = {name: set() for name in CASE_PROPERTY_NAMES}
+    for kind, suffix in (("upper", "Uppercase"), ("lower", "Lowercase"), ("title", "Titlecase"), ("fold", "Casefold")):
+        changes = "Changes_When_Casefolded" if kind == "fold" else f"Changes_When_{suffix}d"
+        for codepoint, mapped in mappings[kind].items():
+            if mapped != (codepoint,):
+                properties[changes].add(codepoint)
+            if len(mapped) > 1:
+                properties[f"Multi_Codepoint_{suffix}"].add(codepoint)
+    # A codepoint is unstable if its upper- or lowercase counterpart doesn't map back to it
+    for kind, opposite in (("upper", "lower"), ("lower", "upper")):
+        for codepoint, mapped in mappings[kind].items():
+            if mapped != (codepoint,) and apply(opposite, mapped) != (codepoint,):
+                properties["Case_Unstable"].add(codepoint)
+    write_table(
+        f"src/tables/case/v{version}.rs",
+        f"python generate_tables.py {unicode_version}",
+        unicode_version,
+        properties,
+        CASE_PROPERTY_NAMES,
+    )
+
+
+def generate_quick_check_tables(directory: str, unicode_version: str) -> None:
+    """Codepoints with the "No" and "Maybe" quick check values for each normalization form.
+
+    For a single codepoint "No" is exactly the set of codepoints that change when normalized on their own.
+    "Maybe" codepoints may compose with a preceding character, e.g. combining marks after a base letter.
+    Decomposition forms have no "Maybe" values, their tables are empty.
+    """
+    version = unicode_version.replace(".", "_")
+    values: Dict[str, Set[int]] = {name: set() for name in QUICK_CHECK_NAMES}
+    with open(os.path.join(directory, "DerivedNormalizationProps.txt"), "r", encoding="utf-8") as fd:
+        for line in fd:
+            fields = [field.strip() for field in line.split("#", 1)[0].split(";")]
+            if len(fields) < 3 or not fields[1].endswith("_QC") or fields[2] not in ("N", "M"):
+                continue
+            first, _, last = fields[0].partition("..")
+            values[f"{fields[1][: -len('_QC')]}_{fields[2]}"].update(range(int(first, 16), int(last or first, 16) + 1))
+    write_table(
+        f"src/tables/quick_check/v{version}.rs",
+        f"python generate_tables.py {unicode_version}",
+        unicode_version,
+        values,
+        QUICK_CHECK_NAMES,
+    )
+
+
+def generate_grapheme_tables(directory: str, unicode_version: str) -> None:
+    """Grapheme_Cluster_Break values. Codepoints missing in the data file are `Other`."""
+    version = unicode_version.replace(".", "_")
+    values: Dict[str, Set[int]] = {}
+    for first, last, value in parse_ucd_file(os.path.join(directory, "auxiliary", "GraphemeBreakProperty.txt")):
+        values.setdefault(value, set()).update(range(first, last + 1))
+    write_table(
+        f"src/tables/grapheme_cluster_break/v{version}.rs",
+        f"python generate_tables.py {unicode_version}",
+        unicode_version,
+        values,
+        GRAPHEME_CLUSTER_BREAK_NAMES,
+    )
+
+
+def emoji_sequence_kind(codepoints: Tuple[int, ...]) -> str:
+    if 0x200D in codepoints:
+        return "ZWJ"
+    if all(0x1F1E6 <= codepoint <= 0x1F1FF for codepoint in codepoints):
+        return "Flag"
+    if any(0xE0020 <= codepoint <= 0xE007F for codepoint in codepoints):
+        return "Tag"
+    if 0x20E3 in codepoints:
+        return "Keycap"
+    if any(0x1F3FB <= codepoint <= 0x1F3FF for codepoint in codepoints):
+        return "Modifier"
+    return "Presentation"
+
+
+def generate_emoji_tables(unicode_version: str) -> None:
+    """Fully-qualified multi-codepoint emoji sequences of the matching emoji version, grouped by kind."""
+    version = unicode_version.replace(".", "_")
+    path, _ = urllib.request.urlretrieve(
+        f"https://www.unicode.org/Public/emoji/{EMOJI_VERSIONS[unicode_version]}/emoji-test.txt"
+    )
+    sequences: Dict[str, list] = {kind: [] for kind in EMOJI_SEQUENCE_KIND_NAMES}
+    with open(path, "r", encoding="utf-8") as fd:
+        for line in fd:
+            fields = [field.strip() for field in line.split("#", 1)[0].split(";")]
+            if len(fields) < 2 or fields[1] != "fully-qualified":
+                continue
+            codepoints = tuple(int(value, 16) for value in fields[0].split())
+            if len(codepoints) > 1:
+                sequences[emoji_sequence_kind(codepoints)].append(codepoints)
+    with open(f"src/tables/emoji_sequences/v{version}.rs", "w") as fd:
+        fd.write("// DO NOT EDIT THIS FILE. IT WAS AUTOMATICALLY GENERATED BY:\n")
+        fd.write("//\n")
+        fd.write(f"//   python generate_tables.py {unicode_version}\n")
+        fd.write("//\n")
+        fd.write(f"// Unicode version: {unicode_version}.\n")
+        fd.write(f"// Emoji version: {EMOJI_VERSIONS[unicode_version]}.\n\n")
+        fd.write("pub const BY_NAME: &'static [(&'static str, &'static [&'static str])] = &[\n")
+        for kind in sorted(EMOJI_SEQUENCE_KIND_NAMES):
+            fd.write(f'    ("{kind}", {EMOJI_SEQUENCE_KIND_NAMES[kind]}),\n')
+        fd.write("];\n")
+        for kind in sorted(EMOJI_SEQUENCE_KIND_NAMES):
+            fd.write(f"\npub const {EMOJI_SEQUENCE_KIND_NAMES[kind]}: &'static [&'static str] = &[\n")
+            for codepoints in sorted(sequences[kind]):
+                literal = "".join(f"\\u{{{codepoint:x}}}" for codepoint in codepoints)
+                fd.write(f'    "{literal}",\n')
+            fd.write("];\n")
+
+
 def main() -> None:
     unicode_version = sys.argv[1]
     response, _ = urllib.request.urlretrieve(f"https://www.unicode.org/Public/zipped/{unicode_version}/UCD.zip")
@@ -62,6 +391,11 @@ def main() -> None:
             content = content.replace(category, abbreviation)
     with open(filename, "w") as fd:
         fd.write(content)
+    generate_property_tables(directory, unicode_version)
+    generate_case_tables(directory, unicode_version)
+    generate_quick_check_tables(directory, unicode_version)
+    generate_grapheme_tables(directory, unicode_version)
+    generate_emoji_tables(unicode_version)
 
 
 if __name__ == "__main__":
@@ -0,0 +1,101 @@
+use crate::Error;
+use std::convert::TryFrom;
+use std::fmt::{self, Display, Formatter};
+
+/// Case-related properties of codepoints.
+///
+/// All mappings are full (i.e. may produce several codepoints) and unconditional - context-dependent
+/// rules like the final sigma or locale-specific ones are not applied.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum CaseProperty {
+    /// Uppercase mapping differs from the codepoint itself.
+    ChangesWhenUppercased,
+    /// Lowercase mapping differs from the codepoint itself.
+    ChangesWhenLowercased,
+    /// Titlecase mapping differs from the codepoint itself.
+    ChangesWhenTitlecased,
+    /// Case folding differs from the codepoint itself.
+    ChangesWhenCasefolded,
+    /// Uppercase mapping consists of several codepoints, e.g. `ß` -> `SS`.
+    MultiCodepointUppercase,
+    /// Lowercase mapping consists of several codepoints, e.g. `İ` -> `i̇`.
+    MultiCodepointLowercase,
+    /// Titlecase mapping consists of several codepoints, e.g. `ﬀ` -> `Ff`.
+    MultiCodepointTitlecase,
+    /// Case folding consists of several codepoints, e.g. `ß` -> `ss`.
+    MultiCodepointCasefold,
+    /// Upper- or lowercasing the codepoint and then applying the opposite mapping doesn't give the
+    /// codepoint back, e.g. `ſ` -> `S` -> `s`.
+    CaseUnstable,
+}
+
+impl CaseProperty {
+    /// All case properties.
+    pub const ALL: [CaseProperty; 9] = [
+        CaseProperty::ChangesWhenUppercased,
+        CaseProperty::ChangesWhenLowercased,
+        CaseProperty::ChangesWhenTitlecased,
+        CaseProperty::ChangesWhenCasefolded,
+        CaseProperty::MultiCodepointUppercase,
+        CaseProperty::MultiCodepointLowercase,
+        CaseProperty::MultiCodepointTitlecase,
+        CaseProperty::MultiCodepointCasefold,
+        CaseProperty::CaseUnstable,
+    ];
+
+    /// Property name as used in the case tables, e.g. `Changes_When_Casefolded`.
+    #[inline]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            CaseProperty::ChangesWhenUppercased => "Changes_When_Uppercased",
+            CaseProperty::ChangesWhenLowercased => "Changes_When_Lowercased",
+            CaseProperty::ChangesWhenTitlecased => "Changes_When_Titlecased",
+            CaseProperty::ChangesWhenCasefolded => "Changes_When_Casefolded",
+            CaseProperty::MultiCodepointUppercase => "Multi_Codepoint_Uppercase",
+            CaseProperty::MultiCodepointLowercase => "Multi_Codepoint_Lowercase",
+            CaseProperty::MultiCodepointTitlecase => "Multi_Codepoint_Titlecase",
+            CaseProperty::MultiCodepointCasefold => "Multi_Codepoint_Casefold",
+            CaseProperty::CaseUnstable => "Case_Unstable",
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a str> for CaseProperty {
+    type Error = Error<'a>;
+
+    /// Parse a property name as returned by `CaseProperty::as_str`.
+    #[inline]
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        CaseProperty::ALL
+            .iter()
+            .copied()
+            .find(|property| property.as_str() == value)
+            .ok_or(Error::InvalidPropertyValue(value))
+    }
+}
+
+impl Display for CaseProperty {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        for property in CaseProperty::ALL.iter().copied() {
+            assert_eq!(CaseProperty::try_from(property.as_str()), Ok(property));
+        }
+    }
+
+    #[test]
+    fn invalid_name() {
+        assert_eq!(
+            CaseProperty::try_from("Lowercase"),
+            Err(Error::InvalidPropertyValue("Lowercase"))
+        );
+    }
+}
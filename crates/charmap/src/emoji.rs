@@ -0,0 +1,196 @@
+use crate::{Error, UnicodeVersion};
+use std::convert::TryFrom;
+use std::fmt::{self, Display, Formatter};
+
+/// Kinds of fully-qualified multi-codepoint emoji sequences.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum EmojiSequenceKind {
+    /// A pair of regional indicators, e.g. `🇯🇵`.
+    Flag,
+    /// A tag sequence, e.g. the flag of Scotland.
+    Tag,
+    /// A keycap sequence, e.g. `#️⃣`.
+    Keycap,
+    /// An emoji followed by a skin tone modifier, e.g. `👍🏽`.
+    Modifier,
+    /// A character followed by the emoji presentation selector U+FE0F, e.g. `☺️`.
+    Presentation,
+    /// Emoji joined by ZERO WIDTH JOINER, e.g. `👩‍💻`.
+    Zwj,
+}
+
+impl EmojiSequenceKind {
+    /// All emoji sequence kinds.
+    pub const ALL: [EmojiSequenceKind; 6] = [
+        EmojiSequenceKind::Flag,
+        EmojiSequenceKind::Tag,
+        EmojiSequenceKind::Keycap,
+        EmojiSequenceKind::Modifier,
+        EmojiSequenceKind::Presentation,
+        EmojiSequenceKind::Zwj,
+    ];
+
+    /// Kind name as used in the emoji sequence tables, e.g. `ZWJ`.
+    #[inline]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            EmojiSequenceKind::Flag => "Flag",
+            EmojiSequenceKind::Tag => "Tag",
+            EmojiSequenceKind::Keycap => "Keycap",
+            EmojiSequenceKind::Modifier => "Modifier",
+            EmojiSequenceKind::Presentation => "Presentation",
+            EmojiSequenceKind::Zwj => "ZWJ",
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a str> for EmojiSequenceKind {
+    type Error = Error<'a>;
+
+    /// Parse a kind name as returned by `EmojiSequenceKind::as_str`.
+    #[inline]
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        EmojiSequenceKind::ALL
+            .iter()
+            .copied()
+            .find(|kind| kind.as_str() == value)
+            .ok_or(Error::InvalidPropertyValue(value))
+    }
+}
+
+impl Display for EmojiSequenceKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl UnicodeVersion {
+    /// Fully-qualified emoji sequences of the given kind, sorted by their codepoints.
+    ///
+    /// Sequences come from the emoji version released together with this Unicode version, e.g.
+    /// Emoji 4.0 for Unicode 9.0.
+    #[inline]
+    pub fn emoji_sequences(self, kind: EmojiSequenceKind) -> &'static [&'static str] {
+        self.emoji_sequence_table()
+            .iter()
+            .find(|(name, _)| *name == kind.as_str())
+            .map(|(_, sequences)| *sequences)
+            .expect("Every sequence kind is present in the emoji sequence table")
+    }
+
+    /// Total number of emoji sequences of all kinds.
+    #[inline]
+    pub fn emoji_sequence_count(self) -> usize {
+        self.emoji_sequence_table()
+            .iter()
+            .map(|(_, sequences)| sequences.len())
+            .sum()
+    }
+
+    /// The `index`-th emoji sequence among all kinds, in the `EmojiSequenceKind::ALL` order.
+    /// Indices from `0` to `emoji_sequence_count() - 1` enumerate every sequence exactly once.
+    #[inline]
+    // `index` is always less than the length of the skipped tables
+    #[allow(clippy::arithmetic_side_effects)]
+    pub fn emoji_sequence(self, mut index: usize) -> Option<&'static str> {
+        for kind in &EmojiSequenceKind::ALL {
+            let sequences = self.emoji_sequences(*kind);
+            if index < sequences.len() {
+                return Some(sequences[index]);
+            }
+            index -= sequences.len();
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test]
+    fn roundtrip() {
+        for kind in EmojiSequenceKind::ALL.iter().copied() {
+            assert_eq!(EmojiSequenceKind::try_from(kind.as_str()), Ok(kind));
+        }
+    }
+
+    #[test]
+    fn invalid_name() {
+        assert_eq!(
+            EmojiSequenceKind::try_from("Zwj"),
+            Err(Error::InvalidPropertyValue("Zwj"))
+        );
+    }
+
+    #[test]
+    fn sequences_are_valid() {
+        for version in UnicodeVersion::ALL.iter().copied() {
+            for kind in EmojiSequenceKind::ALL.iter().copied() {
+                for sequence in version.emoji_sequences(kind) {
+                    assert!(sequence.chars().count() > 1, "{:?}", sequence);
+                    for c in sequence.chars() {
+                        assert_ne!(version.category(c as u32), Some("Cn"), "{:?}", sequence);
+                    }
+                    match kind {
+                        EmojiSequenceKind::Zwj => assert!(sequence.contains('\u{200d}')),
+                        EmojiSequenceKind::Keycap => assert!(sequence.ends_with('\u{20e3}')),
+                        EmojiSequenceKind::Presentation => {
+                            assert!(sequence.ends_with('\u{fe0f}'))
+                        }
+                        _ => assert!(!sequence.contains('\u{200d}')),
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn enumerates_all_sequences() {
+        let version = UnicodeVersion::V13;
+        let count = version.emoji_sequence_count();
+        let all: Vec<&str> = (0..count)
+            .map(|index| version.emoji_sequence(index).expect("Valid index"))
+            .collect();
+        assert_eq!(
+            all.first(),
+            version.emoji_sequences(EmojiSequenceKind::Flag).first()
+        );
+        assert_eq!(
+            all.last(),
+            version.emoji_sequences(EmojiSequenceKind::Zwj).last()
+        );
+        assert_eq!(version.emoji_sequence(count), None);
+    }
+
+    // Woman technologist, Emoji 4.0
+    #[test_case(UnicodeVersion::V9, "\u{1f469}\u{200d}\u{1f4bb}", true)]
+    // Flag of Scotland, Emoji 5.0
+    #[test_case(
+        UnicodeVersion::V9,
+        "\u{1f3f4}\u{e0067}\u{e0062}\u{e0073}\u{e0063}\u{e0074}\u{e007f}",
+        false
+    )]
+    #[test_case(
+        UnicodeVersion::V10,
+        "\u{1f3f4}\u{e0067}\u{e0062}\u{e0073}\u{e0063}\u{e0074}\u{e007f}",
+        true
+    )]
+    // Man with red hair, Emoji 11.0
+    #[test_case(UnicodeVersion::V10, "\u{1f468}\u{200d}\u{1f9b0}", false)]
+    #[test_case(UnicodeVersion::V11, "\u{1f468}\u{200d}\u{1f9b0}", true)]
+    // Transgender flag, Emoji 13.0
+    #[test_case(
+        UnicodeVersion::V12_1,
+        "\u{1f3f3}\u{fe0f}\u{200d}\u{26a7}\u{fe0f}",
+        false
+    )]
+    #[test_case(UnicodeVersion::V13, "\u{1f3f3}\u{fe0f}\u{200d}\u{26a7}\u{fe0f}", true)]
+    fn availability(version: UnicodeVersion, sequence: &str, expected: bool) {
+        let found = EmojiSequenceKind::ALL
+            .iter()
+            .any(|kind| version.emoji_sequences(*kind).contains(&sequence));
+        assert_eq!(found, expected)
+    }
+}
@@ -1,7 +1,11 @@
-use crate::{Category, CharMap, Interval, TableEntry, UnicodeVersion, MAX_CODEPOINT};
+use crate::{
+    AnnotatedInterval, Category, CharMap, Error, Interval, Source, Table, TableEntry,
+    UnicodeVersion, MAX_CODEPOINT,
+};
 use ahash::{AHashMap, AHashSet};
 use lazy_static::lazy_static;
 use smallvec::SmallVec;
+use std::cmp::{max, min, Ordering};
 use std::convert::TryInto;
 use std::sync::Mutex;
 
@@ -48,7 +52,7 @@ pub fn union_intervals(mut left: Vec<Interval>, right: &[Interval]) -> Vec<Inter
 #[inline]
 // Practically all interval values are < u32::MAX
 // Therefore there will be no panic (debug) / wrapping (release)
-#[allow(clippy::integer_arithmetic)]
+#[allow(clippy::arithmetic_side_effects)]
 pub fn subtract_intervals(mut left: Vec<Interval>, right: &[Interval]) -> Vec<Interval> {
     if right.is_empty() {
         left
@@ -85,6 +89,83 @@ pub fn subtract_intervals(mut left: Vec<Interval>, right: &[Interval]) -> Vec<In
     }
 }
 
+#[inline]
+pub fn intersect_intervals(left: &[Interval], right: &[Interval]) -> Vec<Interval> {
+    let (mut i, mut j) = (0usize, 0usize);
+    let mut result = Vec::with_capacity(min(left.len(), right.len()));
+    while i < left.len() && j < right.len() {
+        let (ll, lr) = left[i];
+        let (rl, rr) = right[j];
+        if ll.max(rl) <= lr.min(rr) {
+            result.push((ll.max(rl), lr.min(rr)));
+        }
+        // Advance the one that ends first, it can't intersect anything else
+        if lr < rr {
+            i = i.saturating_add(1);
+        } else {
+            j = j.saturating_add(1);
+        }
+    }
+    result
+}
+
+/// Restrict intervals to the `[min_codepoint; max_codepoint]` range.
+#[inline]
+pub fn clip_intervals(
+    base: Vec<Interval>,
+    min_codepoint: u32,
+    max_codepoint: u32,
+) -> Vec<Interval> {
+    let mut result = Vec::with_capacity(base.len());
+    for (u, v) in base {
+        if v >= min_codepoint && u <= max_codepoint {
+            result.push((max(u, min_codepoint), min(v, max_codepoint)))
+        }
+    }
+    result
+}
+
+/// Whether sorted, non-overlapping intervals contain the given codepoint.
+#[inline]
+pub fn contains(intervals: &[Interval], codepoint: u32) -> bool {
+    intervals
+        .binary_search_by(|&(left, right)| {
+            if right < codepoint {
+                Ordering::Less
+            } else if left > codepoint {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }
+        })
+        .is_ok()
+}
+
+/// Find the name of the table entry that contains the given codepoint.
+#[inline]
+pub fn lookup(table: &'static Table, codepoint: u32) -> Option<&'static str> {
+    table
+        .iter()
+        .find(|(_, intervals)| contains(intervals, codepoint))
+        .map(|(name, _)| *name)
+}
+
+/// Union of the intervals for all given property values.
+#[inline]
+pub fn property_intervals<'a>(
+    table: &'static Table,
+    values: &[&'a str],
+) -> Result<Vec<Interval>, Error<'a>> {
+    let mut result = vec![];
+    for value in values {
+        match table.iter().find(|(name, _)| name == value) {
+            Some((_, intervals)) => result = union_intervals(result, intervals),
+            None => return Err(Error::InvalidPropertyValue(value)),
+        }
+    }
+    Ok(result)
+}
+
 #[inline]
 pub fn intervals(string: &str) -> Vec<Interval> {
     if string.is_empty() {
@@ -97,10 +178,18 @@ pub fn intervals(string: &str) -> Vec<Interval> {
     intervals.to_vec()
 }
 
+/// Sort and merge arbitrary intervals into a normalized form.
+#[inline]
+pub fn normalize_intervals(mut intervals: Vec<Interval>) -> Vec<Interval> {
+    let border = merge_intervals(&mut intervals);
+    intervals.truncate(border);
+    intervals
+}
+
 // Note, `#[inline]` leads to worse performance
 // Practically all interval values are < u32::MAX
 // Therefore there will be no panic (debug) / wrapping (release)
-#[allow(clippy::integer_arithmetic)]
+#[allow(clippy::arithmetic_side_effects)]
 fn merge_intervals(intervals: &mut [Interval]) -> usize {
     // Note! merge sort is faster than quicksort on the test dataset - worth exploring why
     #[allow(clippy::stable_sort_primitive)]
@@ -124,6 +213,49 @@ fn merge_intervals(intervals: &mut [Interval]) -> usize {
     border + 1
 }
 
+/// Split intervals coming from multiple sources into disjoint intervals, each annotated with
+/// all sources that cover it. Sources are listed in the same order as in `pieces`.
+// Practically all interval values are < u32::MAX
+// Therefore there will be no panic (debug) / wrapping (release)
+#[allow(clippy::arithmetic_side_effects)]
+pub fn annotate_intervals(pieces: &[(Source, Vec<Interval>)]) -> Vec<AnnotatedInterval> {
+    // Sweep over interval borders: (position, whether an interval starts there, source index)
+    let mut events = Vec::with_capacity(pieces.iter().map(|(_, v)| v.len() * 2).sum());
+    for (idx, (_, intervals)) in pieces.iter().enumerate() {
+        for &(left, right) in intervals {
+            events.push((left, true, idx));
+            events.push((right + 1, false, idx));
+        }
+    }
+    events.sort_unstable();
+    let mut active: SmallVec<[usize; 4]> = SmallVec::new();
+    let mut result: Vec<AnnotatedInterval> = vec![];
+    let mut previous = 0;
+    for (position, is_start, idx) in events {
+        if position > previous && !active.is_empty() {
+            let sources: Vec<Source> = active.iter().map(|i| pieces[*i].0).collect();
+            match result.last_mut() {
+                Some(last) if last.interval.1 + 1 == previous && last.sources == sources => {
+                    last.interval.1 = position - 1
+                }
+                _ => result.push(AnnotatedInterval {
+                    interval: (previous, position - 1),
+                    sources,
+                }),
+            }
+        }
+        previous = position;
+        match active.binary_search(&idx) {
+            Ok(found) if !is_start => {
+                active.remove(found);
+            }
+            Err(missing) if is_start => active.insert(missing, idx),
+            _ => {}
+        }
+    }
+    result
+}
+
 /// Return a normalised tuple of all Unicode categories that are in `include`, but not in `exclude`.
 #[inline]
 pub fn category_key(
@@ -151,8 +283,10 @@ pub fn category_key(
     out
 }
 
+type CategoryIndexCacheKey = (UnicodeVersion, Vec<Category>);
+
 lazy_static! {
-    static ref CATEGORY_INDEX_CACHE: Mutex<AHashMap<Vec<Category>, Vec<Interval>>> =
+    static ref CATEGORY_INDEX_CACHE: Mutex<AHashMap<CategoryIndexCacheKey, Vec<Interval>>> =
         Mutex::new(AHashMap::new());
 }
 
@@ -166,7 +300,7 @@ pub fn query_for_key(version: UnicodeVersion, key: &[Category]) -> Vec<Interval>
     }
     if let Some((last, left)) = key.split_last() {
         if let Ok(cache) = CATEGORY_INDEX_CACHE.lock() {
-            if let Some(cached) = cache.get(key) {
+            if let Some(cached) = cache.get(&(version, key.to_vec())) {
                 return cached.clone();
             }
         }
@@ -178,7 +312,7 @@ pub fn query_for_key(version: UnicodeVersion, key: &[Category]) -> Vec<Interval>
             .expect("It should be a valid Unicode category");
         let result = union_intervals(left, right);
         if let Ok(mut cache) = CATEGORY_INDEX_CACHE.lock() {
-            cache.insert(key.to_vec(), result.clone());
+            cache.insert((version, key.to_vec()), result.clone());
         }
         result
     } else {
@@ -223,6 +357,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn intersect_works() {
+        assert_eq!(intersect_intervals(&[], &[(1, 2)]), &[]);
+        assert_eq!(
+            intersect_intervals(&[(0, 10)], &[(2, 3), (9, 15)]),
+            &[(2, 3), (9, 10)]
+        );
+        assert_eq!(
+            intersect_intervals(&[(0, 1), (3, 5), (7, 7)], &[(1, 3), (5, 8)]),
+            &[(1, 1), (3, 3), (5, 5), (7, 7)]
+        );
+    }
+
+    #[test]
+    fn clip_works() {
+        assert_eq!(
+            clip_intervals(vec![(0, 10), (20, 30)], 5, 25),
+            &[(5, 10), (20, 25)]
+        );
+        assert_eq!(clip_intervals(vec![(0, 10)], 11, 25), &[]);
+    }
+
     #[test]
     fn intervals_works() {
         assert_eq!(intervals("\u{10A07}"), &[(68103, 68103)]);
@@ -296,6 +452,57 @@ mod tests {
         );
     }
 
+    #[test]
+    fn annotate_overlapping_sources() {
+        assert_eq!(
+            annotate_intervals(&[
+                (Source::Category("Lu"), vec![(65, 90)]),
+                (Source::Category("Nd"), vec![(48, 57)]),
+                (Source::IncludeCharacters, vec![(57, 65), (9731, 9731)]),
+            ]),
+            vec![
+                AnnotatedInterval {
+                    interval: (48, 56),
+                    sources: vec![Source::Category("Nd")]
+                },
+                AnnotatedInterval {
+                    interval: (57, 57),
+                    sources: vec![Source::Category("Nd"), Source::IncludeCharacters]
+                },
+                AnnotatedInterval {
+                    interval: (58, 64),
+                    sources: vec![Source::IncludeCharacters]
+                },
+                AnnotatedInterval {
+                    interval: (65, 65),
+                    sources: vec![Source::Category("Lu"), Source::IncludeCharacters]
+                },
+                AnnotatedInterval {
+                    interval: (66, 90),
+                    sources: vec![Source::Category("Lu")]
+                },
+                AnnotatedInterval {
+                    interval: (9731, 9731),
+                    sources: vec![Source::IncludeCharacters]
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn annotate_up_to_max_codepoint() {
+        assert_eq!(
+            annotate_intervals(&[
+                (Source::Category("Cn"), vec![(0, MAX_CODEPOINT)]),
+                (Source::IncludeCharacters, vec![]),
+            ]),
+            vec![AnnotatedInterval {
+                interval: (0, MAX_CODEPOINT),
+                sources: vec![Source::Category("Cn")]
+            }]
+        );
+    }
+
     #[test]
     fn successive_union() {
         let mut x = vec![];
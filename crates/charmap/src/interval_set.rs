@@ -0,0 +1,251 @@
+use crate::inner::{
+    intersect_intervals, intervals, normalize_intervals, subtract_intervals, union_intervals,
+};
+use crate::{Error, Interval, MAX_CODEPOINT};
+use std::ops::{BitAnd, BitOr, Sub};
+
+/// Set of codepoints stored as sorted, non-overlapping and non-adjacent intervals.
+///
+/// Mirrors `hypothesis.internal.intervalsets.IntervalSet` - codepoints are indexed in ascending
+/// order, which allows drawing them by index.
+/// ```rust
+/// use charmap::IntervalSet;
+///
+/// let digits = IntervalSet::from_string("0123456789");
+/// let letters = IntervalSet::new(vec![(65, 90)]).expect("Valid intervals");
+/// let both = &digits | &letters;
+/// assert_eq!(both.intervals(), &[(48, 57), (65, 90)]);
+/// assert_eq!(both.len(), 36);
+/// assert_eq!(both.get(10), Some(65));
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct IntervalSet {
+    intervals: Vec<Interval>,
+    /// Number of codepoints before each interval.
+    offsets: Vec<u64>,
+    size: u64,
+}
+
+impl IntervalSet {
+    /// Create a set from arbitrary intervals. They may overlap and go in any order, but must be
+    /// within the codepoint range.
+    #[inline]
+    pub fn new<'a>(intervals: Vec<Interval>) -> Result<IntervalSet, Error<'a>> {
+        if let Some(&(left, right)) = intervals
+            .iter()
+            .find(|(left, right)| left > right || *right > MAX_CODEPOINT)
+        {
+            return Err(Error::InvalidCodepoints(left, right));
+        }
+        Ok(IntervalSet::from_normalized(normalize_intervals(intervals)))
+    }
+
+    /// Create a set of all characters in the given string.
+    #[inline]
+    pub fn from_string(string: &str) -> IntervalSet {
+        IntervalSet::from_normalized(intervals(string))
+    }
+
+    // Practically all interval values are < u32::MAX
+    // Therefore there will be no panic (debug) / wrapping (release)
+    #[allow(clippy::arithmetic_side_effects)]
+    fn from_normalized(intervals: Vec<Interval>) -> IntervalSet {
+        let mut offsets = Vec::with_capacity(intervals.len());
+        let mut size = 0u64;
+        for &(left, right) in &intervals {
+            offsets.push(size);
+            size += u64::from(right - left) + 1;
+        }
+        IntervalSet {
+            intervals,
+            offsets,
+            size,
+        }
+    }
+
+    /// Intervals that form the set.
+    #[inline]
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    /// Number of codepoints in the set.
+    #[inline]
+    pub fn len(&self) -> u64 {
+        self.size
+    }
+
+    /// Whether the set has no codepoints.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    /// Whether the set contains the given codepoint.
+    #[inline]
+    pub fn contains(&self, codepoint: u32) -> bool {
+        self.position(codepoint).is_some()
+    }
+
+    /// The `index`-th smallest codepoint in the set.
+    #[inline]
+    // `position` is at least 1, as the first offset is always 0
+    // The difference is less than the interval size that fits into u32
+    #[allow(clippy::arithmetic_side_effects, clippy::cast_possible_truncation)]
+    pub fn get(&self, index: u64) -> Option<u32> {
+        if index >= self.size {
+            return None;
+        }
+        let position = self.offsets.partition_point(|&offset| offset <= index) - 1;
+        Some(self.intervals[position].0 + (index - self.offsets[position]) as u32)
+    }
+
+    /// Index of the given codepoint in the set, the inverse of `get`.
+    #[inline]
+    // The codepoint is within the interval at `position`
+    #[allow(clippy::arithmetic_side_effects)]
+    pub fn index_of(&self, codepoint: u32) -> Option<u64> {
+        self.position(codepoint).map(|position| {
+            self.offsets[position] + u64::from(codepoint - self.intervals[position].0)
+        })
+    }
+
+    fn position(&self, codepoint: u32) -> Option<usize> {
+        // The first interval whose right border is not less than the codepoint
+        let position = self
+            .intervals
+            .partition_point(|&(_, right)| right < codepoint);
+        match self.intervals.get(position) {
+            Some(&(left, _)) if left <= codepoint => Some(position),
+            _ => None,
+        }
+    }
+
+    /// Codepoints that are in either set.
+    #[inline]
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        IntervalSet::from_normalized(union_intervals(self.intervals.clone(), &other.intervals))
+    }
+
+    /// Codepoints that are in both sets.
+    #[inline]
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        IntervalSet::from_normalized(intersect_intervals(&self.intervals, &other.intervals))
+    }
+
+    /// Codepoints that are in this set, but not in the other one.
+    #[inline]
+    pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+        IntervalSet::from_normalized(subtract_intervals(self.intervals.clone(), &other.intervals))
+    }
+}
+
+impl From<IntervalSet> for Vec<Interval> {
+    #[inline]
+    fn from(set: IntervalSet) -> Self {
+        set.intervals
+    }
+}
+
+impl BitOr for &IntervalSet {
+    type Output = IntervalSet;
+
+    #[inline]
+    fn bitor(self, rhs: Self) -> IntervalSet {
+        self.union(rhs)
+    }
+}
+
+impl BitAnd for &IntervalSet {
+    type Output = IntervalSet;
+
+    #[inline]
+    fn bitand(self, rhs: Self) -> IntervalSet {
+        self.intersection(rhs)
+    }
+}
+
+impl Sub for &IntervalSet {
+    type Output = IntervalSet;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> IntervalSet {
+        self.difference(rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    fn set(intervals: &[Interval]) -> IntervalSet {
+        IntervalSet::new(intervals.to_vec()).expect("Valid intervals")
+    }
+
+    #[test_case(&[], &[])]
+    #[test_case(&[(5, 10), (0, 3)], &[(0, 3), (5, 10)])]
+    #[test_case(&[(0, 3), (4, 10), (2, 5)], &[(0, 10)])]
+    fn new_normalizes(intervals: &[Interval], expected: &[Interval]) {
+        assert_eq!(set(intervals).intervals(), expected)
+    }
+
+    #[test]
+    fn new_rejects_reversed_intervals() {
+        assert_eq!(
+            IntervalSet::new(vec![(0, 1), (10, 5)]),
+            Err(Error::InvalidCodepoints(10, 5))
+        )
+    }
+
+    #[test]
+    fn new_rejects_intervals_beyond_max_codepoint() {
+        assert_eq!(
+            IntervalSet::new(vec![(0, u32::MAX), (1, 2)]),
+            Err(Error::InvalidCodepoints(0, u32::MAX))
+        )
+    }
+
+    #[test]
+    fn indexing() {
+        let value = set(&[(1, 3), (10, 10), (20, 21)]);
+        assert_eq!(value.len(), 6);
+        let codepoints: Vec<u32> = (0..value.len()).filter_map(|idx| value.get(idx)).collect();
+        assert_eq!(codepoints, &[1, 2, 3, 10, 20, 21]);
+        assert_eq!(value.get(6), None);
+        for (index, codepoint) in codepoints.iter().enumerate() {
+            assert_eq!(value.index_of(*codepoint), Some(index as u64));
+            assert!(value.contains(*codepoint));
+        }
+        for codepoint in &[0, 4, 9, 11, 22, u32::MAX] {
+            assert_eq!(value.index_of(*codepoint), None);
+            assert!(!value.contains(*codepoint));
+        }
+    }
+
+    #[test_case(&[(0, 10)], &[(5, 20)], &[(0, 20)], &[(5, 10)], &[(0, 4)])]
+    #[test_case(&[(0, 1), (5, 6)], &[(2, 4)], &[(0, 6)], &[], &[(0, 1), (5, 6)])]
+    #[test_case(&[], &[(2, 4)], &[(2, 4)], &[], &[])]
+    // `-` is a set difference here
+    #[allow(clippy::arithmetic_side_effects)]
+    fn operators(
+        left: &[Interval],
+        right: &[Interval],
+        union: &[Interval],
+        intersection: &[Interval],
+        difference: &[Interval],
+    ) {
+        let (left, right) = (set(left), set(right));
+        assert_eq!((&left | &right).intervals(), union);
+        assert_eq!((&left & &right).intervals(), intersection);
+        assert_eq!((&left - &right).intervals(), difference);
+    }
+
+    #[test]
+    fn from_string() {
+        assert_eq!(
+            IntervalSet::from_string("cab☃").intervals(),
+            &[(97, 99), (9731, 9731)]
+        )
+    }
+}
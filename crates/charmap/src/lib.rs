@@ -38,19 +38,32 @@
     unused_import_braces,
     unused_qualifications,
     variant_size_differences,
-    clippy::integer_arithmetic,
+    clippy::arithmetic_side_effects,
     clippy::unwrap_used
 )]
+mod case;
+mod emoji;
 mod inner;
+mod interval_set;
+mod normalization;
+mod render;
+mod sampling;
 mod tables;
 use ahash::AHashMap;
 use lazy_static::lazy_static;
-use std::cmp::{max, min};
+use std::convert::TryFrom;
 use std::fmt::{Display, Formatter};
 use std::ops::Deref;
 use std::sync::Mutex;
 use std::{error, fmt};
 
+pub use case::CaseProperty;
+pub use emoji::EmojiSequenceKind;
+pub use interval_set::IntervalSet;
+pub use normalization::{NormalizationForm, QuickCheck};
+pub use render::{to_listing, to_matches_pattern, to_regex_class, RegexFlavor};
+pub use sampling::{Sampler, Weights, DEFAULT_WEIGHTS};
+
 /// Unicode category abbreviation
 pub type Category = &'static str;
 /// Interval between two Unicode codepoints.
@@ -61,6 +74,8 @@ pub type TableEntry = (Category, &'static [Interval]);
 pub type Table = [TableEntry];
 /// Mapping from Unicode category abbreviations to their respective intervals.
 pub type CharMap = AHashMap<Category, &'static [Interval]>;
+/// Table with emoji sequences grouped by their kinds.
+pub type SequenceTable = [(&'static str, &'static [&'static str])];
 
 lazy_static! {
     static ref CHARMAP_V9: CharMap = inner::make_charmap(UnicodeVersion::V9);
@@ -79,8 +94,51 @@ lazy_static! {
 
 static MAX_CODEPOINT: u32 = 1114111;
 
+/// Unicode properties, besides general categories, that query results can be restricted to.
+///
+/// Explicitly included characters are added to the result regardless of the filter, the same way
+/// as they are for categories.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub struct Filter<'a> {
+    /// Keep only codepoints with one of these East Asian Width values, e.g. `["W", "F"]`.
+    /// Only assigned codepoints have a width.
+    pub east_asian_widths: Option<&'a [&'a str]>,
+    /// Keep only codepoints with one of these Bidi Class values, e.g. `["R", "AL"]`.
+    /// Only assigned codepoints have a class.
+    pub bidi_classes: Option<&'a [&'a str]>,
+    /// Keep only codepoints with one of these Grapheme Cluster Break values, e.g. `["Extend"]`.
+    /// Codepoints with the `Other` value are not a part of any of them.
+    pub grapheme_cluster_breaks: Option<&'a [&'a str]>,
+    /// Keep only codepoints having all of these case properties.
+    pub case_properties: Option<&'a [CaseProperty]>,
+    /// Keep only codepoints that never change under any of these normalization forms, i.e. whose
+    /// quick check value is `Yes`.
+    pub normalization_stable: Option<&'a [NormalizationForm]>,
+    /// Keep only codepoints that may change under all of these normalization forms, on their own
+    /// or after some preceding characters, i.e. whose quick check value is `No` or `Maybe`.
+    pub normalization_unstable: Option<&'a [NormalizationForm]>,
+}
+
+/// Origin of an interval in a query result.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Source {
+    /// Codepoints belong to this Unicode category.
+    Category(Category),
+    /// Codepoints were explicitly requested via `include_characters`.
+    IncludeCharacters,
+}
+
+/// Interval annotated with the sources that contributed it to a query result.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct AnnotatedInterval {
+    /// Interval between two Unicode codepoints.
+    pub interval: Interval,
+    /// Sources of all codepoints in the interval. Categories go first, in the category key order.
+    pub sources: Vec<Source>,
+}
+
 /// Supported Unicode versions
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub enum UnicodeVersion {
     /// Unicode 9.0
     V9,
@@ -103,6 +161,10 @@ pub enum Error<'a> {
     InvalidCategory(&'a str),
     /// Provided codepoints do not agree. Maximum should be greater or equal to minimum.
     InvalidCodepoints(u32, u32),
+    /// The provided Unicode version is not supported.
+    InvalidVersion(&'a str),
+    /// The provided value is not valid for the filtered Unicode property.
+    InvalidPropertyValue(&'a str),
 }
 
 impl error::Error for Error<'_> {}
@@ -117,11 +179,66 @@ impl Display for Error<'_> {
                 "Minimum codepoint should be less or equal than maximum codepoint. Got {} {}",
                 left, right
             )),
+            Error::InvalidVersion(version) => {
+                f.write_fmt(format_args!("Unsupported Unicode version: {}", version))
+            }
+            Error::InvalidPropertyValue(value) => f.write_fmt(format_args!(
+                "{} is not a valid Unicode property value",
+                value
+            )),
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a str> for UnicodeVersion {
+    type Error = Error<'a>;
+
+    /// Parse a version string in the `unicodedata.unidata_version` format, e.g. "12.1.0".
+    /// Trailing zero components may be omitted.
+    #[inline]
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        match value {
+            "9.0.0" | "9.0" | "9" => Ok(UnicodeVersion::V9),
+            "10.0.0" | "10.0" | "10" => Ok(UnicodeVersion::V10),
+            "11.0.0" | "11.0" | "11" => Ok(UnicodeVersion::V11),
+            "12.0.0" | "12.0" | "12" => Ok(UnicodeVersion::V12),
+            "12.1.0" | "12.1" => Ok(UnicodeVersion::V12_1),
+            "13.0.0" | "13.0" | "13" => Ok(UnicodeVersion::V13),
+            _ => Err(Error::InvalidVersion(value)),
         }
     }
 }
 
+impl Display for UnicodeVersion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 impl UnicodeVersion {
+    /// All supported Unicode versions, from the oldest to the newest.
+    pub const ALL: [UnicodeVersion; 6] = [
+        UnicodeVersion::V9,
+        UnicodeVersion::V10,
+        UnicodeVersion::V11,
+        UnicodeVersion::V12,
+        UnicodeVersion::V12_1,
+        UnicodeVersion::V13,
+    ];
+
+    /// Version string in the `unicodedata.unidata_version` format.
+    #[inline]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            UnicodeVersion::V9 => "9.0.0",
+            UnicodeVersion::V10 => "10.0.0",
+            UnicodeVersion::V11 => "11.0.0",
+            UnicodeVersion::V12 => "12.0.0",
+            UnicodeVersion::V12_1 => "12.1.0",
+            UnicodeVersion::V13 => "13.0.0",
+        }
+    }
+
     /// Get a raw table with character mappings.
     #[inline]
     pub const fn table(self) -> &'static Table {
@@ -162,6 +279,174 @@ impl UnicodeVersion {
         vec.as_slice()
     }
 
+    /// Get the Unicode category of the given codepoint.
+    /// Returns `None` if the codepoint is greater than the maximum Unicode codepoint.
+    #[inline]
+    pub fn category(self, codepoint: u32) -> Option<Category> {
+        inner::lookup(self.table(), codepoint)
+    }
+
+    /// Get a raw table with East Asian Width mappings of assigned codepoints.
+    #[inline]
+    pub const fn east_asian_width_table(self) -> &'static Table {
+        match self {
+            UnicodeVersion::V9 => tables::east_asian_width::v9_0_0::BY_NAME,
+            UnicodeVersion::V10 => tables::east_asian_width::v10_0_0::BY_NAME,
+            UnicodeVersion::V11 => tables::east_asian_width::v11_0_0::BY_NAME,
+            UnicodeVersion::V12 => tables::east_asian_width::v12_0_0::BY_NAME,
+            UnicodeVersion::V12_1 => tables::east_asian_width::v12_1_0::BY_NAME,
+            UnicodeVersion::V13 => tables::east_asian_width::v13_0_0::BY_NAME,
+        }
+    }
+
+    /// Get a raw table with Bidi Class mappings of assigned codepoints.
+    #[inline]
+    pub const fn bidi_class_table(self) -> &'static Table {
+        match self {
+            UnicodeVersion::V9 => tables::bidi_class::v9_0_0::BY_NAME,
+            UnicodeVersion::V10 => tables::bidi_class::v10_0_0::BY_NAME,
+            UnicodeVersion::V11 => tables::bidi_class::v11_0_0::BY_NAME,
+            UnicodeVersion::V12 => tables::bidi_class::v12_0_0::BY_NAME,
+            UnicodeVersion::V12_1 => tables::bidi_class::v12_1_0::BY_NAME,
+            UnicodeVersion::V13 => tables::bidi_class::v13_0_0::BY_NAME,
+        }
+    }
+
+    /// Get a raw table with Grapheme Cluster Break mappings. The `Other` value is omitted.
+    #[inline]
+    pub const fn grapheme_cluster_break_table(self) -> &'static Table {
+        match self {
+            UnicodeVersion::V9 => tables::grapheme_cluster_break::v9_0_0::BY_NAME,
+            UnicodeVersion::V10 => tables::grapheme_cluster_break::v10_0_0::BY_NAME,
+            UnicodeVersion::V11 => tables::grapheme_cluster_break::v11_0_0::BY_NAME,
+            UnicodeVersion::V12 => tables::grapheme_cluster_break::v12_0_0::BY_NAME,
+            UnicodeVersion::V12_1 => tables::grapheme_cluster_break::v12_1_0::BY_NAME,
+            UnicodeVersion::V13 => tables::grapheme_cluster_break::v13_0_0::BY_NAME,
+        }
+    }
+
+    /// Get a raw table with emoji sequences grouped by their kinds.
+    #[inline]
+    pub const fn emoji_sequence_table(self) -> &'static SequenceTable {
+        match self {
+            UnicodeVersion::V9 => tables::emoji_sequences::v9_0_0::BY_NAME,
+            UnicodeVersion::V10 => tables::emoji_sequences::v10_0_0::BY_NAME,
+            UnicodeVersion::V11 => tables::emoji_sequences::v11_0_0::BY_NAME,
+            UnicodeVersion::V12 => tables::emoji_sequences::v12_0_0::BY_NAME,
+            UnicodeVersion::V12_1 => tables::emoji_sequences::v12_1_0::BY_NAME,
+            UnicodeVersion::V13 => tables::emoji_sequences::v13_0_0::BY_NAME,
+        }
+    }
+
+    /// Get a raw table with case properties of codepoints.
+    #[inline]
+    pub const fn case_table(self) -> &'static Table {
+        match self {
+            UnicodeVersion::V9 => tables::case::v9_0_0::BY_NAME,
+            UnicodeVersion::V10 => tables::case::v10_0_0::BY_NAME,
+            UnicodeVersion::V11 => tables::case::v11_0_0::BY_NAME,
+            UnicodeVersion::V12 => tables::case::v12_0_0::BY_NAME,
+            UnicodeVersion::V12_1 => tables::case::v12_1_0::BY_NAME,
+            UnicodeVersion::V13 => tables::case::v13_0_0::BY_NAME,
+        }
+    }
+
+    /// Intervals of codepoints that have the given case property.
+    #[inline]
+    pub fn case_property(self, property: CaseProperty) -> &'static [Interval] {
+        self.case_table()
+            .iter()
+            .find(|(name, _)| *name == property.as_str())
+            .map(|(_, intervals)| *intervals)
+            .expect("Every case property is present in the case table")
+    }
+
+    /// Get a raw table with codepoints whose quick check value is "No" or "Maybe" for each
+    /// normalization form, e.g. `NFC_M`.
+    #[inline]
+    pub const fn quick_check_table(self) -> &'static Table {
+        match self {
+            UnicodeVersion::V9 => tables::quick_check::v9_0_0::BY_NAME,
+            UnicodeVersion::V10 => tables::quick_check::v10_0_0::BY_NAME,
+            UnicodeVersion::V11 => tables::quick_check::v11_0_0::BY_NAME,
+            UnicodeVersion::V12 => tables::quick_check::v12_0_0::BY_NAME,
+            UnicodeVersion::V12_1 => tables::quick_check::v12_1_0::BY_NAME,
+            UnicodeVersion::V13 => tables::quick_check::v13_0_0::BY_NAME,
+        }
+    }
+
+    /// Intervals of codepoints with the given quick check value for the given form. Codepoints
+    /// with the `Yes` value are not stored, therefore there are no intervals for it.
+    #[inline]
+    pub fn quick_check_intervals(
+        self,
+        form: NormalizationForm,
+        value: QuickCheck,
+    ) -> &'static [Interval] {
+        if value == QuickCheck::Yes {
+            return &[];
+        }
+        let name = format!("{}_{}", form.as_str(), value.as_str());
+        self.quick_check_table()
+            .iter()
+            .find(|(entry, _)| *entry == name)
+            .map(|(_, intervals)| *intervals)
+            .expect("Every normalization form is present in the quick check table")
+    }
+
+    /// Intervals of codepoints that change when normalized on their own to the given form.
+    ///
+    /// Combining marks that may compose with a preceding character are not included, see
+    /// `UnicodeVersion::quick_check`.
+    #[inline]
+    pub fn changes_when_normalized(self, form: NormalizationForm) -> &'static [Interval] {
+        self.quick_check_intervals(form, QuickCheck::No)
+    }
+
+    /// Get the quick check value of the given codepoint for the given form.
+    #[inline]
+    pub fn quick_check(self, codepoint: u32, form: NormalizationForm) -> QuickCheck {
+        if inner::contains(self.quick_check_intervals(form, QuickCheck::No), codepoint) {
+            QuickCheck::No
+        } else if inner::contains(
+            self.quick_check_intervals(form, QuickCheck::Maybe),
+            codepoint,
+        ) {
+            QuickCheck::Maybe
+        } else {
+            QuickCheck::Yes
+        }
+    }
+
+    /// Codepoints that may change under the given form, on their own or after other characters.
+    fn normalization_unstable(self, form: NormalizationForm) -> Vec<Interval> {
+        inner::union_intervals(
+            self.quick_check_intervals(form, QuickCheck::No).to_vec(),
+            self.quick_check_intervals(form, QuickCheck::Maybe),
+        )
+    }
+
+    /// Get the East Asian Width (e.g. "W" or "Na") of the given codepoint.
+    /// Returns `None` for unassigned codepoints.
+    #[inline]
+    pub fn east_asian_width(self, codepoint: u32) -> Option<&'static str> {
+        inner::lookup(self.east_asian_width_table(), codepoint)
+    }
+
+    /// Get the Bidi Class (e.g. "R" or "AL") of the given codepoint.
+    /// Returns `None` for unassigned codepoints.
+    #[inline]
+    pub fn bidi_class(self, codepoint: u32) -> Option<&'static str> {
+        inner::lookup(self.bidi_class_table(), codepoint)
+    }
+
+    /// Get the Grapheme Cluster Break value (e.g. "Extend" or "ZWJ") of the given codepoint.
+    /// Returns `None` for codepoints with the `Other` value.
+    #[inline]
+    pub fn grapheme_cluster_break(self, codepoint: u32) -> Option<&'static str> {
+        inner::lookup(self.grapheme_cluster_break_table(), codepoint)
+    }
+
     /// Expand one-letter designations of a major class to include all subclasses.
     #[inline]
     pub fn as_general_categories<'a>(
@@ -200,32 +485,15 @@ impl UnicodeVersion {
         include_characters: Option<&str>,
         exclude_characters: Option<&str>,
     ) -> Result<Vec<Interval>, Error<'a>> {
-        let exclude_categories = exclude_categories.unwrap_or(&[]);
-        // Category validation
-        let all_categories = self.categories();
-        for category in exclude_categories {
-            if !all_categories.contains(category) {
-                return Err(Error::InvalidCategory(category));
-            }
-        }
-        if let Some(categories) = include_categories {
-            for category in categories {
-                if !all_categories.contains(category) {
-                    return Err(Error::InvalidCategory(category));
-                }
-            }
-        }
-
-        // Min codepoint <= Max codepoint
-        let min_codepoint = min_codepoint.unwrap_or(0);
-        let max_codepoint = max_codepoint.unwrap_or(MAX_CODEPOINT);
-        if min_codepoint > max_codepoint {
-            return Err(Error::InvalidCodepoints(min_codepoint, max_codepoint));
-        }
-
-        let category_key = inner::category_key(self, exclude_categories, include_categories);
+        let (category_key, min_codepoint, max_codepoint) = self.validate_query(
+            exclude_categories,
+            include_categories,
+            min_codepoint,
+            max_codepoint,
+        )?;
 
         let cache_key = (
+            self,
             category_key.clone(),
             min_codepoint,
             max_codepoint,
@@ -245,12 +513,7 @@ impl UnicodeVersion {
         let exclude_intervals = inner::intervals(exclude_characters);
 
         let base = inner::query_for_key(self, category_key.as_slice());
-        let mut result = vec![];
-        for (u, v) in base {
-            if v >= min_codepoint && u <= max_codepoint {
-                result.push((max(u, min_codepoint), min(v, max_codepoint)))
-            }
-        }
+        let result = inner::clip_intervals(base, min_codepoint, max_codepoint);
         let result = inner::union_intervals(result, character_intervals.as_slice());
         let result = inner::subtract_intervals(result, exclude_intervals.as_slice());
         if let Ok(mut cache) = QUERY_CACHE.lock() {
@@ -258,9 +521,150 @@ impl UnicodeVersion {
         }
         Ok(result)
     }
+
+    /// Same as `query`, but the codepoints matched by categories are additionally restricted to
+    /// the ones having the Unicode properties given in `filter`.
+    #[inline]
+    #[allow(clippy::too_many_arguments)]
+    pub fn query_filtered<'a>(
+        self,
+        exclude_categories: Option<&[&'a str]>,
+        include_categories: Option<&[&'a str]>,
+        min_codepoint: Option<u32>,
+        max_codepoint: Option<u32>,
+        include_characters: Option<&str>,
+        exclude_characters: Option<&str>,
+        filter: &Filter<'a>,
+    ) -> Result<Vec<Interval>, Error<'a>> {
+        let (category_key, min_codepoint, max_codepoint) = self.validate_query(
+            exclude_categories,
+            include_categories,
+            min_codepoint,
+            max_codepoint,
+        )?;
+        let base = inner::query_for_key(self, category_key.as_slice());
+        let mut result = inner::clip_intervals(base, min_codepoint, max_codepoint);
+        if let Some(values) = filter.east_asian_widths {
+            let allowed = inner::property_intervals(self.east_asian_width_table(), values)?;
+            result = inner::intersect_intervals(&result, &allowed);
+        }
+        if let Some(values) = filter.bidi_classes {
+            let allowed = inner::property_intervals(self.bidi_class_table(), values)?;
+            result = inner::intersect_intervals(&result, &allowed);
+        }
+        if let Some(values) = filter.grapheme_cluster_breaks {
+            let allowed = inner::property_intervals(self.grapheme_cluster_break_table(), values)?;
+            result = inner::intersect_intervals(&result, &allowed);
+        }
+        for property in filter.case_properties.unwrap_or(&[]) {
+            result = inner::intersect_intervals(&result, self.case_property(*property));
+        }
+        for form in filter.normalization_stable.unwrap_or(&[]) {
+            result = inner::subtract_intervals(result, &self.normalization_unstable(*form));
+        }
+        for form in filter.normalization_unstable.unwrap_or(&[]) {
+            result = inner::intersect_intervals(&result, &self.normalization_unstable(*form));
+        }
+        let character_intervals = inner::intervals(include_characters.unwrap_or(""));
+        let exclude_intervals = inner::intervals(exclude_characters.unwrap_or(""));
+        let result = inner::union_intervals(result, character_intervals.as_slice());
+        Ok(inner::subtract_intervals(
+            result,
+            exclude_intervals.as_slice(),
+        ))
+    }
+
+    /// Same as `query`, but every returned interval is annotated with the categories and
+    /// explicitly included characters it comes from.
+    ///
+    /// Intervals are split wherever their sources change, therefore their union is equal to the
+    /// `query` result, but adjacent intervals are not necessarily merged.
+    #[inline]
+    pub fn query_with_provenance<'a>(
+        self,
+        exclude_categories: Option<&[&'a str]>,
+        include_categories: Option<&[&'a str]>,
+        min_codepoint: Option<u32>,
+        max_codepoint: Option<u32>,
+        include_characters: Option<&str>,
+        exclude_characters: Option<&str>,
+    ) -> Result<Vec<AnnotatedInterval>, Error<'a>> {
+        let (category_key, min_codepoint, max_codepoint) = self.validate_query(
+            exclude_categories,
+            include_categories,
+            min_codepoint,
+            max_codepoint,
+        )?;
+        let character_intervals = inner::intervals(include_characters.unwrap_or(""));
+        let exclude_intervals = inner::intervals(exclude_characters.unwrap_or(""));
+
+        let mut pieces = Vec::with_capacity(category_key.len().saturating_add(1));
+        for category in category_key {
+            let intervals = inner::clip_intervals(
+                inner::query_for_key(self, &[category]),
+                min_codepoint,
+                max_codepoint,
+            );
+            pieces.push((
+                Source::Category(category),
+                inner::subtract_intervals(intervals, exclude_intervals.as_slice()),
+            ));
+        }
+        pieces.push((
+            Source::IncludeCharacters,
+            inner::subtract_intervals(character_intervals, exclude_intervals.as_slice()),
+        ));
+        Ok(inner::annotate_intervals(&pieces))
+    }
+
+    /// Validate query arguments and build the category key with codepoint bounds.
+    #[inline]
+    fn validate_query<'a>(
+        self,
+        exclude_categories: Option<&[&'a str]>,
+        include_categories: Option<&[&'a str]>,
+        min_codepoint: Option<u32>,
+        max_codepoint: Option<u32>,
+    ) -> Result<(Vec<Category>, u32, u32), Error<'a>> {
+        let exclude_categories = exclude_categories.unwrap_or(&[]);
+        // Category validation
+        let all_categories = self.categories();
+        for category in exclude_categories {
+            if !all_categories.contains(category) {
+                return Err(Error::InvalidCategory(category));
+            }
+        }
+        if let Some(categories) = include_categories {
+            for category in categories {
+                if !all_categories.contains(category) {
+                    return Err(Error::InvalidCategory(category));
+                }
+            }
+        }
+
+        // Min codepoint <= Max codepoint
+        let min_codepoint = min_codepoint.unwrap_or(0);
+        let max_codepoint = max_codepoint.unwrap_or(MAX_CODEPOINT);
+        if min_codepoint > max_codepoint {
+            return Err(Error::InvalidCodepoints(min_codepoint, max_codepoint));
+        }
+
+        Ok((
+            inner::category_key(self, exclude_categories, include_categories),
+            min_codepoint,
+            max_codepoint,
+        ))
+    }
 }
 
-type QueryCacheKey = (Vec<Category>, u32, u32, Option<String>, Option<String>);
+type QueryCacheKey = (
+    UnicodeVersion,
+    Vec<Category>,
+    u32,
+    u32,
+    Option<String>,
+    Option<String>,
+);
 
 lazy_static! {
     static ref QUERY_CACHE: Mutex<AHashMap<QueryCacheKey, Vec<Interval>>> =
@@ -288,17 +692,126 @@ mod tests {
     use super::*;
     use test_case::test_case;
 
-    #[test_case(&["N"], vec!["Nl", "Nd", "No"])]
-    #[test_case(&["N", "N"], vec!["Nl", "Nd", "No"])]
-    fn as_general_categories_work(categories: &[&'static str], expected: Vec<&str>) {
+    #[test_case(&["N"], &["Nl", "Nd", "No"])]
+    #[test_case(&["N", "N"], &["Nl", "Nd", "No"])]
+    fn as_general_categories_work(categories: &[&'static str], expected: &[&str]) {
         assert_eq!(
             UnicodeVersion::V13
                 .as_general_categories(categories)
-                .unwrap(),
+                .expect("Valid categories"),
             expected
         )
     }
 
+    #[test_case(65, Some("Lu"))]
+    #[test_case(0x2603, Some("So"))]
+    #[test_case(0xD800, Some("Cs"))]
+    #[test_case(0x10FFFF, Some("Cn"))]
+    #[test_case(0x110000, None)]
+    fn category_works(codepoint: u32, expected: Option<Category>) {
+        assert_eq!(UnicodeVersion::V13.category(codepoint), expected)
+    }
+
+    #[test]
+    fn category_agrees_with_charmap() {
+        for version in &UnicodeVersion::ALL {
+            for (name, intervals) in version.table() {
+                for (left, right) in *intervals {
+                    assert_eq!(version.category(*left), Some(*name));
+                    assert_eq!(version.category(*right), Some(*name));
+                }
+            }
+        }
+    }
+
+    #[test_case("13.0.0", &Ok(UnicodeVersion::V13))]
+    #[test_case("12.1", &Ok(UnicodeVersion::V12_1))]
+    #[test_case("9", &Ok(UnicodeVersion::V9))]
+    #[test_case("8.0.0", &Err(Error::InvalidVersion("8.0.0")))]
+    fn version_from_str(value: &str, expected: &Result<UnicodeVersion, Error<'_>>) {
+        assert_eq!(&UnicodeVersion::try_from(value), expected)
+    }
+
+    #[test]
+    fn version_roundtrip() {
+        for version in &UnicodeVersion::ALL {
+            assert_eq!(UnicodeVersion::try_from(version.as_str()), Ok(*version))
+        }
+    }
+
+    #[test_case(None, None, None, None, None, None)]
+    #[test_case(Some(&["Lu", "Cn"]), None, Some(100), None, None, None)]
+    #[test_case(None, Some(&["Lu", "Nd"]), None, Some(0x1000), Some("☃AB-"), Some("Z0"))]
+    #[test_case(None, Some(&["Zs"]), None, None, Some("\u{10A07}"), Some(" "))]
+    fn query_with_provenance_agrees_with_query(
+        exclude_categories: Option<&[&str]>,
+        include_categories: Option<&[&str]>,
+        min_codepoint: Option<u32>,
+        max_codepoint: Option<u32>,
+        include_characters: Option<&str>,
+        exclude_characters: Option<&str>,
+    ) {
+        let version = UnicodeVersion::V13;
+        let annotated = version
+            .query_with_provenance(
+                exclude_categories,
+                include_categories,
+                min_codepoint,
+                max_codepoint,
+                include_characters,
+                exclude_characters,
+            )
+            .expect("Valid query input");
+        let mut union = vec![];
+        for item in &annotated {
+            assert!(!item.sources.is_empty());
+            for source in &item.sources {
+                if let Source::Category(category) = source {
+                    for codepoint in &[item.interval.0, item.interval.1] {
+                        assert_eq!(version.category(*codepoint), Some(*category))
+                    }
+                }
+            }
+            union = inner::union_intervals(union, &[item.interval]);
+        }
+        assert_eq!(
+            union,
+            version
+                .query(
+                    exclude_categories,
+                    include_categories,
+                    min_codepoint,
+                    max_codepoint,
+                    include_characters,
+                    exclude_characters
+                )
+                .expect("Valid query input")
+        )
+    }
+
+    #[test]
+    fn query_with_provenance_reports_sources() {
+        assert_eq!(
+            UnicodeVersion::V13
+                .query_with_provenance(None, Some(&["Lu"]), None, Some(90), Some("Z☃"), None)
+                .expect("Valid query input"),
+            vec![
+                AnnotatedInterval {
+                    interval: (65, 89),
+                    sources: vec![Source::Category("Lu")]
+                },
+                AnnotatedInterval {
+                    interval: (90, 90),
+                    sources: vec![Source::Category("Lu"), Source::IncludeCharacters]
+                },
+                AnnotatedInterval {
+                    interval: (9731, 9731),
+                    sources: vec![Source::IncludeCharacters]
+                },
+            ]
+        )
+    }
+
     #[test_case(None, None, None, None, None, None, &[(0, 1114111)])]
     #[test_case(None, None, Some(0), Some(128), None, None, &[(0, 128)])]
     #[test_case(None, Some(&["Lu"]), Some(0), Some(128), None, None, &[(65, 90)])]
@@ -323,8 +836,313 @@ mod tests {
                     include_characters,
                     exclude_characters
                 )
-                .unwrap(),
+                .expect("Valid query input"),
             expected
         )
     }
+
+    #[test_case(0x41, Some("Na"))]
+    #[test_case(0xA1, Some("A"))]
+    #[test_case(0x3000, Some("F"))]
+    #[test_case(0xFF61, Some("H"))]
+    #[test_case(0x4E00, Some("W"))]
+    #[test_case(0x0300, Some("A"))]
+    #[test_case(0x0378, None)]
+    fn east_asian_width_works(codepoint: u32, expected: Option<&str>) {
+        assert_eq!(UnicodeVersion::V13.east_asian_width(codepoint), expected)
+    }
+
+    #[test_case(UnicodeVersion::V13, 0x41, Some("L"))]
+    #[test_case(UnicodeVersion::V13, 0x5D0, Some("R"))]
+    #[test_case(UnicodeVersion::V13, 0x627, Some("AL"))]
+    #[test_case(UnicodeVersion::V13, 0x661, Some("AN"))]
+    #[test_case(UnicodeVersion::V13, 0x31, Some("EN"))]
+    #[test_case(UnicodeVersion::V13, 0x20, Some("WS"))]
+    #[test_case(UnicodeVersion::V13, 0x0378, None)]
+    #[test_case(UnicodeVersion::V10, 0x111C9, Some("L"))]
+    #[test_case(UnicodeVersion::V11, 0x111C9, Some("NSM"))]
+    fn bidi_class_works(version: UnicodeVersion, codepoint: u32, expected: Option<&str>) {
+        assert_eq!(version.bidi_class(codepoint), expected)
+    }
+
+    #[test_case(UnicodeVersion::V13, 0x0D, Some("CR"))]
+    #[test_case(UnicodeVersion::V13, 0x41, None)]
+    #[test_case(UnicodeVersion::V13, 0x0301, Some("Extend"))]
+    #[test_case(UnicodeVersion::V13, 0x200D, Some("ZWJ"))]
+    #[test_case(UnicodeVersion::V13, 0x1F1E6, Some("Regional_Indicator"))]
+    #[test_case(UnicodeVersion::V13, 0xAC00, Some("LV"))]
+    #[test_case(UnicodeVersion::V13, 0xE0000, Some("Control"))]
+    #[test_case(UnicodeVersion::V9, 0x1F3FB, Some("E_Modifier"))]
+    #[test_case(UnicodeVersion::V11, 0x1F3FB, Some("Extend"))]
+    fn grapheme_cluster_break_works(
+        version: UnicodeVersion,
+        codepoint: u32,
+        expected: Option<&str>,
+    ) {
+        assert_eq!(version.grapheme_cluster_break(codepoint), expected)
+    }
+
+    #[test]
+    fn property_tables_cover_assigned_codepoints() {
+        for version in UnicodeVersion::ALL.iter().copied() {
+            let assigned = version
+                .query(Some(&["Cn"]), None, None, None, None, None)
+                .expect("Valid query");
+            for table in &[version.east_asian_width_table(), version.bidi_class_table()] {
+                let mut covered = vec![];
+                for (_, intervals) in *table {
+                    covered = inner::union_intervals(covered, intervals);
+                }
+                assert_eq!(covered, assigned, "{}", version);
+            }
+        }
+    }
+
+    #[test]
+    fn query_filtered_works() {
+        let filter = Filter {
+            east_asian_widths: Some(&["W"]),
+            ..Filter::default()
+        };
+        assert_eq!(
+            UnicodeVersion::V13
+                .query_filtered(
+                    None,
+                    Some(&["Po"]),
+                    None,
+                    Some(0x3010),
+                    Some("A"),
+                    Some("\u{3002}"),
+                    &filter
+                )
+                .expect("Valid query"),
+            &[(65, 65), (12289, 12289), (12291, 12291)]
+        );
+        let filter = Filter {
+            bidi_classes: Some(&["R", "AL"]),
+            ..Filter::default()
+        };
+        assert_eq!(
+            UnicodeVersion::V13
+                .query_filtered(None, Some(&["Nd"]), None, None, None, None, &filter)
+                .expect("Valid query"),
+            &[(1984, 1993), (125264, 125273)]
+        );
+    }
+
+    #[test]
+    fn query_filtered_by_grapheme_cluster_break() {
+        let filter = Filter {
+            grapheme_cluster_breaks: Some(&["Extend", "ZWJ"]),
+            ..Filter::default()
+        };
+        assert_eq!(
+            UnicodeVersion::V13
+                .query_filtered(
+                    None,
+                    Some(&["Cf"]),
+                    Some(0x2000),
+                    Some(0x2010),
+                    None,
+                    None,
+                    &filter
+                )
+                .expect("Valid query"),
+            &[(8204, 8205)]
+        );
+    }
+
+    #[test]
+    fn query_filtered_without_filter_agrees_with_query() {
+        let version = UnicodeVersion::V12_1;
+        assert_eq!(
+            version
+                .query_filtered(
+                    None,
+                    Some(&["Lu", "Ll", "Zs"]),
+                    Some(10),
+                    Some(0x20000),
+                    Some("☃"),
+                    Some("a"),
+                    &Filter::default()
+                )
+                .expect("Valid query"),
+            version
+                .query(
+                    None,
+                    Some(&["Lu", "Ll", "Zs"]),
+                    Some(10),
+                    Some(0x20000),
+                    Some("☃"),
+                    Some("a")
+                )
+                .expect("Valid query"),
+        )
+    }
+
+    #[test]
+    fn query_filtered_rejects_unknown_values() {
+        let filter = Filter {
+            east_asian_widths: Some(&["Wide"]),
+            ..Filter::default()
+        };
+        assert_eq!(
+            UnicodeVersion::V13.query_filtered(None, None, None, None, None, None, &filter),
+            Err(Error::InvalidPropertyValue("Wide"))
+        )
+    }
+
+    #[test_case(0xDF, CaseProperty::MultiCodepointUppercase, true)]
+    #[test_case(0xDF, CaseProperty::MultiCodepointCasefold, true)]
+    #[test_case(0xDF, CaseProperty::ChangesWhenLowercased, false)]
+    #[test_case(0xDF, CaseProperty::CaseUnstable, true)]
+    #[test_case(0x130, CaseProperty::MultiCodepointLowercase, true)]
+    #[test_case(0x130, CaseProperty::CaseUnstable, true)]
+    #[test_case(0x17F, CaseProperty::CaseUnstable, true)]
+    #[test_case(0x212A, CaseProperty::CaseUnstable, true)]
+    #[test_case(0x1C5, CaseProperty::ChangesWhenTitlecased, false)]
+    #[test_case(0x1C5, CaseProperty::CaseUnstable, true)]
+    #[test_case(0x41, CaseProperty::ChangesWhenLowercased, true)]
+    #[test_case(0x41, CaseProperty::ChangesWhenCasefolded, true)]
+    #[test_case(0x41, CaseProperty::ChangesWhenUppercased, false)]
+    #[test_case(0x41, CaseProperty::CaseUnstable, false)]
+    #[test_case(0xFB00, CaseProperty::MultiCodepointTitlecase, true)]
+    #[test_case(0x31, CaseProperty::ChangesWhenCasefolded, false)]
+    fn case_property_works(codepoint: u32, property: CaseProperty, expected: bool) {
+        assert_eq!(
+            UnicodeVersion::V13
+                .case_property(property)
+                .iter()
+                .any(|&(left, right)| left <= codepoint && codepoint <= right),
+            expected
+        )
+    }
+
+    #[test]
+    fn case_properties_depend_on_version() {
+        // Georgian Mtavruli capital letters were added in Unicode 11.0
+        let contains = |version: UnicodeVersion| {
+            version
+                .case_property(CaseProperty::ChangesWhenUppercased)
+                .iter()
+                .any(|&(left, right)| left <= 0x10D0 && 0x10D0 <= right)
+        };
+        assert!(!contains(UnicodeVersion::V10));
+        assert!(contains(UnicodeVersion::V11));
+    }
+
+    #[test]
+    fn query_filtered_by_case_properties() {
+        let filter = Filter {
+            case_properties: Some(&[
+                CaseProperty::MultiCodepointUppercase,
+                CaseProperty::ChangesWhenCasefolded,
+            ]),
+            ..Filter::default()
+        };
+        assert_eq!(
+            UnicodeVersion::V13
+                .query_filtered(None, Some(&["Ll"]), None, Some(0x200), None, None, &filter)
+                .expect("Valid query"),
+            &[(223, 223), (329, 329), (496, 496)]
+        );
+    }
+
+    #[test_case(0xC5, NormalizationForm::Nfc, false)]
+    #[test_case(0xC5, NormalizationForm::Nfd, true)]
+    #[test_case(0x212B, NormalizationForm::Nfc, true)]
+    #[test_case(0xFB01, NormalizationForm::Nfc, false)]
+    #[test_case(0xFB01, NormalizationForm::Nfkc, true)]
+    #[test_case(0xFB01, NormalizationForm::Nfkd, true)]
+    #[test_case(0xAC00, NormalizationForm::Nfc, false)]
+    #[test_case(0xAC00, NormalizationForm::Nfd, true)]
+    #[test_case(0x0301, NormalizationForm::Nfc, false)]
+    #[test_case(0x41, NormalizationForm::Nfkd, false)]
+    fn changes_when_normalized_works(codepoint: u32, form: NormalizationForm, expected: bool) {
+        assert_eq!(
+            UnicodeVersion::V13
+                .changes_when_normalized(form)
+                .iter()
+                .any(|&(left, right)| left <= codepoint && codepoint <= right),
+            expected
+        )
+    }
+
+    #[test_case(0xC5, NormalizationForm::Nfc, QuickCheck::Yes)]
+    #[test_case(0xC5, NormalizationForm::Nfd, QuickCheck::No)]
+    #[test_case(0x0301, NormalizationForm::Nfc, QuickCheck::Maybe)]
+    #[test_case(0x0301, NormalizationForm::Nfkc, QuickCheck::Maybe)]
+    #[test_case(0x0301, NormalizationForm::Nfd, QuickCheck::Yes)]
+    #[test_case(0x0344, NormalizationForm::Nfc, QuickCheck::No)]
+    #[test_case(0x1161, NormalizationForm::Nfc, QuickCheck::Maybe)]
+    #[test_case(0x41, NormalizationForm::Nfkc, QuickCheck::Yes)]
+    fn quick_check_works(codepoint: u32, form: NormalizationForm, expected: QuickCheck) {
+        assert_eq!(UnicodeVersion::V13.quick_check(codepoint, form), expected)
+    }
+
+    #[test]
+    fn composing_marks_are_added_in_unicode_13() {
+        assert_eq!(
+            UnicodeVersion::V12_1.quick_check(0x11930, NormalizationForm::Nfc),
+            QuickCheck::Yes
+        );
+        assert_eq!(
+            UnicodeVersion::V13.quick_check(0x11930, NormalizationForm::Nfc),
+            QuickCheck::Maybe
+        );
+    }
+
+    #[test]
+    fn query_filtered_by_normalization() {
+        let version = UnicodeVersion::V13;
+        let stable = Filter {
+            normalization_stable: Some(&[NormalizationForm::Nfkc]),
+            ..Filter::default()
+        };
+        assert_eq!(
+            version
+                .query_filtered(None, Some(&["No"]), None, Some(0xFF), None, None, &stable)
+                .expect("Valid query"),
+            &[]
+        );
+        let unstable = Filter {
+            normalization_unstable: Some(&[NormalizationForm::Nfc, NormalizationForm::Nfkc]),
+            ..Filter::default()
+        };
+        assert_eq!(
+            version
+                .query_filtered(
+                    None,
+                    None,
+                    Some(0x2100),
+                    Some(0x212F),
+                    None,
+                    None,
+                    &unstable
+                )
+                .expect("Valid query"),
+            &[(8486, 8486), (8490, 8491)]
+        );
+        // Combining marks change only after a base letter, but they are not stable either
+        let stable = Filter {
+            normalization_stable: Some(&[NormalizationForm::Nfc]),
+            ..Filter::default()
+        };
+        assert_eq!(
+            version
+                .query_filtered(None, None, Some(0x300), Some(0x30F), None, None, &stable)
+                .expect("Valid query"),
+            &[(0x305, 0x305), (0x30D, 0x30E)]
+        );
+        let unstable = Filter {
+            normalization_unstable: Some(&[NormalizationForm::Nfc]),
+            ..Filter::default()
+        };
+        assert_eq!(
+            version
+                .query_filtered(None, None, Some(0x300), Some(0x30F), None, None, &unstable)
+                .expect("Valid query"),
+            &[(0x300, 0x304), (0x306, 0x30C), (0x30F, 0x30F)]
+        );
+    }
 }
@@ -0,0 +1,568 @@
+//! Command-line interface to the `charmap` crate.
+//!
+//! ```text
+//! $ charmap --unicode-version 12.1 query --include-categories Lu,Nd --max-codepoint 0x7F
+//! (48, 57)
+//! (65, 90)
+//! ```
+use charmap::{
+    CaseProperty, Category, EmojiSequenceKind, Filter, Interval, NormalizationForm, RegexFlavor,
+    UnicodeVersion,
+};
+use std::convert::TryFrom;
+use std::fmt::Write;
+use std::{env, process};
+
+const USAGE: &str = "Query Unicode intervals by their categories.
+
+USAGE:
+    charmap [OPTIONS] <COMMAND> [ARGS]
+
+    Arguments after `--` are never treated as options.
+
+OPTIONS:
+    -u, --unicode-version <VERSION>    Unicode version to use [default: 13.0.0]
+    -f, --format <FORMAT>              Output format: intervals, json, regex, pcre, rust, listing or chars
+                                       [default: intervals]
+    -h, --help                         Print this message
+
+COMMANDS:
+    query                              Intervals covering all characters that meet the criteria
+        --include-categories <CATS>    Comma-separated categories to include
+        --exclude-categories <CATS>    Comma-separated categories to exclude
+        --min-codepoint <CODEPOINT>    Minimum codepoint, e.g. 48, 0x30 or U+0030
+        --max-codepoint <CODEPOINT>    Maximum codepoint
+        --include-characters <CHARS>   Characters to include regardless of their category
+        --exclude-characters <CHARS>   Characters to exclude regardless of their category
+        --east-asian-widths <VALUES>   Comma-separated East Asian Width values to keep, e.g. W,F
+        --bidi-classes <VALUES>        Comma-separated Bidi Class values to keep, e.g. R,AL
+        --grapheme-cluster-breaks <VALUES>
+                                       Comma-separated Grapheme Cluster Break values to keep,
+                                       e.g. Extend,ZWJ
+        --case-properties <PROPS>      Comma-separated case properties that all must hold, e.g.
+                                       Multi_Codepoint_Uppercase,Case_Unstable
+        --normalization-stable <FORMS> Keep characters that never change under any of these
+                                       forms, e.g. NFC,NFKC
+        --normalization-unstable <FORMS>
+                                       Keep characters that may change under all of these forms,
+                                       on their own or after a preceding character
+    categories                         Unicode categories in a normalised order
+    as-general-categories <CATS>...    Expand major classes (e.g. `L`) to all their subclasses
+    category <CHARS>...                Look up categories of characters or U+XXXX / 0xXXXX codepoints
+    emoji-sequences [KINDS]...         Emoji sequences of the given kinds (all by default), e.g. ZWJ
+";
+
+/// Output format of the command results.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Format {
+    Intervals,
+    Json,
+    Regex,
+    Pcre,
+    Rust,
+    Listing,
+    Chars,
+}
+
+#[derive(Debug, Default, Eq, PartialEq)]
+struct QueryArgs {
+    exclude_categories: Option<Vec<String>>,
+    include_categories: Option<Vec<String>>,
+    min_codepoint: Option<u32>,
+    max_codepoint: Option<u32>,
+    include_characters: Option<String>,
+    exclude_characters: Option<String>,
+    east_asian_widths: Option<Vec<String>>,
+    bidi_classes: Option<Vec<String>>,
+    grapheme_cluster_breaks: Option<Vec<String>>,
+    case_properties: Option<Vec<CaseProperty>>,
+    normalization_stable: Option<Vec<NormalizationForm>>,
+    normalization_unstable: Option<Vec<NormalizationForm>>,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+enum Command {
+    Query(Box<QueryArgs>),
+    Categories,
+    AsGeneralCategories(Vec<String>),
+    Category(Vec<u32>),
+    EmojiSequences(Vec<EmojiSequenceKind>),
+    Help,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+struct Args {
+    version: UnicodeVersion,
+    format: Format,
+    command: Command,
+}
+
+fn parse_format(value: &str) -> Result<Format, String> {
+    match value {
+        "intervals" => Ok(Format::Intervals),
+        "json" => Ok(Format::Json),
+        "regex" => Ok(Format::Regex),
+        "pcre" => Ok(Format::Pcre),
+        "rust" => Ok(Format::Rust),
+        "listing" => Ok(Format::Listing),
+        "chars" => Ok(Format::Chars),
+        _ => Err(format!("Unknown output format: {}", value)),
+    }
+}
+
+/// Parse a codepoint in decimal, `0x` hexadecimal or `U+` hexadecimal notation.
+fn parse_codepoint(value: &str) -> Result<u32, String> {
+    let parsed = if let Some(hex) = value
+        .strip_prefix("0x")
+        .or_else(|| value.strip_prefix("U+"))
+        .or_else(|| value.strip_prefix("u+"))
+    {
+        u32::from_str_radix(hex, 16)
+    } else {
+        value.parse()
+    };
+    parsed.map_err(|_| format!("Invalid codepoint: {}", value))
+}
+
+fn parse_case_properties(value: &str) -> Result<Vec<CaseProperty>, String> {
+    parse_categories(value)
+        .iter()
+        .map(|name| CaseProperty::try_from(name.as_str()).map_err(|e| e.to_string()))
+        .collect()
+}
+
+fn parse_emoji_sequence_kinds(values: &[String]) -> Result<Vec<EmojiSequenceKind>, String> {
+    values
+        .iter()
+        .flat_map(|value| parse_categories(value))
+        .map(|name| EmojiSequenceKind::try_from(name.as_str()).map_err(|e| e.to_string()))
+        .collect()
+}
+
+fn parse_normalization_forms(value: &str) -> Result<Vec<NormalizationForm>, String> {
+    parse_categories(value)
+        .iter()
+        .map(|name| NormalizationForm::try_from(name.as_str()).map_err(|e| e.to_string()))
+        .collect()
+}
+
+fn parse_categories(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|category| !category.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+/// Each argument is either a single codepoint in `0x` / `U+` notation or a string whose characters
+/// are looked up one by one. Arguments that only look like codepoints, e.g. `0xyz`, are strings.
+fn parse_lookup(values: &[String]) -> Vec<u32> {
+    let mut out = vec![];
+    for value in values {
+        let is_hex = value.starts_with("0x") || value.starts_with("U+") || value.starts_with("u+");
+        match parse_codepoint(value) {
+            Ok(codepoint) if is_hex => out.push(codepoint),
+            _ => out.extend(value.chars().map(|c| c as u32)),
+        }
+    }
+    out
+}
+
+fn parse_args<I: IntoIterator<Item = String>>(args: I) -> Result<Args, String> {
+    let mut version = UnicodeVersion::V13;
+    let mut format = Format::Intervals;
+    let mut query = QueryArgs::default();
+    let mut has_query_options = false;
+    let mut positional = vec![];
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        if arg == "--" {
+            // Everything after `--` is positional, e.g. to look up `-a`
+            positional.extend(args.by_ref());
+            break;
+        }
+        if !arg.starts_with('-') || arg == "-" {
+            positional.push(arg);
+            continue;
+        }
+        if arg == "-h" || arg == "--help" {
+            return Ok(Args {
+                version,
+                format,
+                command: Command::Help,
+            });
+        }
+        // Both `--name value` and `--name=value` forms are accepted
+        let (name, inline_value) = match arg.find('=') {
+            Some(idx) => (arg[..idx].to_string(), Some(arg[idx + 1..].to_string())),
+            None => (arg, None),
+        };
+        let value = match inline_value.or_else(|| args.next()) {
+            Some(value) => value,
+            None => return Err(format!("Missing value for {}", name)),
+        };
+        match name.as_str() {
+            "-u" | "--unicode-version" => {
+                version = UnicodeVersion::try_from(value.as_str()).map_err(|e| e.to_string())?;
+                continue;
+            }
+            "-f" | "--format" => {
+                format = parse_format(&value)?;
+                continue;
+            }
+            "--include-categories" => query.include_categories = Some(parse_categories(&value)),
+            "--exclude-categories" => query.exclude_categories = Some(parse_categories(&value)),
+            "--min-codepoint" => query.min_codepoint = Some(parse_codepoint(&value)?),
+            "--max-codepoint" => query.max_codepoint = Some(parse_codepoint(&value)?),
+            "--include-characters" => query.include_characters = Some(value),
+            "--exclude-characters" => query.exclude_characters = Some(value),
+            "--east-asian-widths" => query.east_asian_widths = Some(parse_categories(&value)),
+            "--bidi-classes" => query.bidi_classes = Some(parse_categories(&value)),
+            "--grapheme-cluster-breaks" => {
+                query.grapheme_cluster_breaks = Some(parse_categories(&value))
+            }
+            "--case-properties" => query.case_properties = Some(parse_case_properties(&value)?),
+            "--normalization-stable" => {
+                query.normalization_stable = Some(parse_normalization_forms(&value)?)
+            }
+            "--normalization-unstable" => {
+                query.normalization_unstable = Some(parse_normalization_forms(&value)?)
+            }
+            _ => return Err(format!("Unknown option: {}", name)),
+        }
+        has_query_options = true;
+    }
+    let mut positional = positional.into_iter();
+    let command = match positional.next().as_deref() {
+        Some("query") => Command::Query(Box::new(query)),
+        Some("categories") => Command::Categories,
+        Some("as-general-categories") => Command::AsGeneralCategories(
+            positional
+                .by_ref()
+                .flat_map(|value| parse_categories(&value))
+                .collect(),
+        ),
+        Some("category") => {
+            Command::Category(parse_lookup(&positional.by_ref().collect::<Vec<_>>()))
+        }
+        Some("emoji-sequences") => Command::EmojiSequences(parse_emoji_sequence_kinds(
+            &positional.by_ref().collect::<Vec<_>>(),
+        )?),
+        Some(other) => return Err(format!("Unknown command: {}", other)),
+        None => return Err("No command given".to_string()),
+    };
+    if let Some(extra) = positional.next() {
+        return Err(format!("Unexpected argument: {}", extra));
+    }
+    if has_query_options && !matches!(command, Command::Query(_)) {
+        return Err("Query options are only valid for the `query` command".to_string());
+    }
+    Ok(Args {
+        version,
+        format,
+        command,
+    })
+}
+
+fn write_intervals(format: Format, intervals: &[Interval]) -> String {
+    let mut out = String::new();
+    match format {
+        Format::Intervals => {
+            for (left, right) in intervals {
+                let _ = writeln!(out, "({}, {})", left, right);
+            }
+        }
+        Format::Json => {
+            out.push('[');
+            for (idx, (left, right)) in intervals.iter().enumerate() {
+                if idx != 0 {
+                    out.push(',');
+                }
+                let _ = write!(out, "[{},{}]", left, right);
+            }
+            out.push_str("]\n");
+        }
+        Format::Regex => {
+            out.push_str(&charmap::to_regex_class(intervals, RegexFlavor::Rust));
+            out.push('\n');
+        }
+        Format::Pcre => {
+            out.push_str(&charmap::to_regex_class(intervals, RegexFlavor::Pcre));
+            out.push('\n');
+        }
+        Format::Rust => {
+            // There is no pattern for an empty set
+            if let Some(pattern) = charmap::to_matches_pattern(intervals) {
+                out.push_str(&pattern);
+                out.push('\n');
+            }
+        }
+        Format::Listing => {
+            out.push_str(&charmap::to_listing(intervals));
+            out.push('\n');
+        }
+        Format::Chars => {
+            // Surrogates are not valid `char` values and are skipped
+            out.extend(
+                intervals
+                    .iter()
+                    .flat_map(|&(left, right)| left..=right)
+                    .filter_map(std::char::from_u32),
+            );
+            out.push('\n');
+        }
+    }
+    out
+}
+
+fn write_categories(format: Format, categories: &[Category]) -> Result<String, String> {
+    match format {
+        Format::Intervals => Ok(categories
+            .iter()
+            .map(|category| format!("{}\n", category))
+            .collect()),
+        Format::Json => Ok(format!(
+            "[{}]\n",
+            categories
+                .iter()
+                .map(|category| format!("\"{}\"", category))
+                .collect::<Vec<_>>()
+                .join(",")
+        )),
+        _ => Err("Categories can only be displayed as `intervals` or `json`".to_string()),
+    }
+}
+
+fn write_lookup(
+    format: Format,
+    version: UnicodeVersion,
+    codepoints: &[u32],
+) -> Result<String, String> {
+    let mut found = Vec::with_capacity(codepoints.len());
+    for codepoint in codepoints {
+        match version.category(*codepoint) {
+            Some(category) => found.push((*codepoint, category)),
+            None => return Err(format!("Codepoint out of range: {:#X}", codepoint)),
+        }
+    }
+    match format {
+        Format::Intervals => Ok(found
+            .iter()
+            .map(|(codepoint, category)| format!("U+{:04X}\t{}\n", codepoint, category))
+            .collect()),
+        Format::Json => Ok(format!(
+            "[{}]\n",
+            found
+                .iter()
+                .map(|(codepoint, category)| format!(
+                    "{{\"codepoint\":{},\"category\":\"{}\"}}",
+                    codepoint, category
+                ))
+                .collect::<Vec<_>>()
+                .join(",")
+        )),
+        _ => Err("Categories can only be displayed as `intervals` or `json`".to_string()),
+    }
+}
+
+fn run(args: Args) -> Result<String, String> {
+    let Args {
+        version,
+        format,
+        command,
+    } = args;
+    match command {
+        Command::Query(query) => {
+            let exclude_categories: Option<Vec<&str>> = query
+                .exclude_categories
+                .as_ref()
+                .map(|cats| cats.iter().map(String::as_str).collect());
+            let include_categories: Option<Vec<&str>> = query
+                .include_categories
+                .as_ref()
+                .map(|cats| cats.iter().map(String::as_str).collect());
+            let east_asian_widths: Option<Vec<&str>> = query
+                .east_asian_widths
+                .as_ref()
+                .map(|values| values.iter().map(String::as_str).collect());
+            let bidi_classes: Option<Vec<&str>> = query
+                .bidi_classes
+                .as_ref()
+                .map(|values| values.iter().map(String::as_str).collect());
+            let grapheme_cluster_breaks: Option<Vec<&str>> = query
+                .grapheme_cluster_breaks
+                .as_ref()
+                .map(|values| values.iter().map(String::as_str).collect());
+            let filter = Filter {
+                east_asian_widths: east_asian_widths.as_deref(),
+                bidi_classes: bidi_classes.as_deref(),
+                grapheme_cluster_breaks: grapheme_cluster_breaks.as_deref(),
+                case_properties: query.case_properties.as_deref(),
+                normalization_stable: query.normalization_stable.as_deref(),
+                normalization_unstable: query.normalization_unstable.as_deref(),
+            };
+            let intervals = version
+                .query_filtered(
+                    exclude_categories.as_deref(),
+                    include_categories.as_deref(),
+                    query.min_codepoint,
+                    query.max_codepoint,
+                    query.include_characters.as_deref(),
+                    query.exclude_characters.as_deref(),
+                    &filter,
+                )
+                .map_err(|e| e.to_string())?;
+            Ok(write_intervals(format, &intervals))
+        }
+        Command::Categories => write_categories(format, version.categories()),
+        Command::AsGeneralCategories(categories) => {
+            let categories: Vec<&str> = categories.iter().map(String::as_str).collect();
+            let expanded = version
+                .as_general_categories(&categories)
+                .map_err(|e| e.to_string())?;
+            write_categories(format, &expanded)
+        }
+        Command::Category(codepoints) => write_lookup(format, version, &codepoints),
+        Command::EmojiSequences(kinds) => {
+            let kinds = if kinds.is_empty() {
+                EmojiSequenceKind::ALL.to_vec()
+            } else {
+                kinds
+            };
+            let sequences: Vec<&str> = kinds
+                .iter()
+                .flat_map(|kind| version.emoji_sequences(*kind).iter().copied())
+                .collect();
+            write_categories(format, &sequences)
+        }
+        Command::Help => Ok(USAGE.to_string()),
+    }
+}
+
+fn main() {
+    let args = match parse_args(env::args().skip(1)) {
+        Ok(args) => args,
+        Err(message) => {
+            eprintln!("error: {}\n\n{}", message, USAGE);
+            process::exit(2)
+        }
+    };
+    match run(args) {
+        Ok(output) => print!("{}", output),
+        Err(message) => {
+            eprintln!("error: {}", message);
+            process::exit(1)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    fn args(values: &[&str]) -> Result<Args, String> {
+        parse_args(values.iter().map(|value| value.to_string()))
+    }
+
+    fn execute(values: &[&str]) -> Result<String, String> {
+        run(args(values)?)
+    }
+
+    #[test_case("65", 65)]
+    #[test_case("0x41", 65)]
+    #[test_case("U+0041", 65)]
+    #[test_case("u+41", 65)]
+    fn codepoints_are_parsed(value: &str, expected: u32) {
+        assert_eq!(parse_codepoint(value), Ok(expected))
+    }
+
+    #[test]
+    fn query_options_are_parsed() {
+        assert_eq!(
+            args(&[
+                "-u",
+                "12.1",
+                "query",
+                "--include-categories=Lu, Nd",
+                "--max-codepoint",
+                "0x1000"
+            ]),
+            Ok(Args {
+                version: UnicodeVersion::V12_1,
+                format: Format::Intervals,
+                command: Command::Query(Box::new(QueryArgs {
+                    include_categories: Some(vec!["Lu".to_string(), "Nd".to_string()]),
+                    max_codepoint: Some(0x1000),
+                    ..QueryArgs::default()
+                }))
+            })
+        )
+    }
+
+    #[test_case(&[]; "no command")]
+    #[test_case(&["unknown"]; "unknown command")]
+    #[test_case(&["query", "--unknown", "1"]; "unknown option")]
+    #[test_case(&["query", "--min-codepoint"]; "missing value")]
+    #[test_case(&["categories", "--min-codepoint", "1"]; "query option for other command")]
+    #[test_case(&["-u", "8.0", "categories"]; "unsupported version")]
+    #[test_case(&["-f", "xml", "categories"]; "unknown format")]
+    fn invalid_args(values: &[&str]) {
+        assert!(args(values).is_err())
+    }
+
+    #[test_case(&["query", "--include-categories", "Lu", "--max-codepoint", "128"], "(65, 90)\n")]
+    #[test_case(&["-f", "json", "query", "--include-categories", "Lu,Nd", "--max-codepoint", "128"], "[[48,57],[65,90]]\n")]
+    #[test_case(&["-f", "regex", "query", "--include-categories", "Lu", "--max-codepoint", "128", "--include-characters", "☃"], "[A-Z\\x{2603}]\n")]
+    #[test_case(&["-f", "pcre", "query", "--include-categories", "Ll", "--min-codepoint", "0x430", "--max-codepoint", "0x44F"], "[\\x{430}-\\x{44F}]\n")]
+    #[test_case(&["query", "--include-categories", "Lo", "--max-codepoint", "0x5FF", "--bidi-classes", "R"], "(1488, 1514)\n(1519, 1522)\n")]
+    #[test_case(&["query", "--min-codepoint", "0x2FF0", "--max-codepoint", "0x3002", "--east-asian-widths", "W,F"], "(12272, 12283)\n(12288, 12290)\n")]
+    #[test_case(&["query", "--include-categories", "Ll", "--max-codepoint", "0x200", "--case-properties", "Multi_Codepoint_Uppercase,Changes_When_Casefolded"], "(223, 223)\n(329, 329)\n(496, 496)\n")]
+    #[test_case(&["query", "--min-codepoint", "0x2100", "--max-codepoint", "0x212F", "--normalization-unstable", "NFC"], "(8486, 8486)\n(8490, 8491)\n")]
+    #[test_case(&["-f", "rust", "query", "--include-categories", "Nd", "--max-codepoint", "128", "--include-characters", "☃"], "'0'..='9' | '\\u{2603}'\n")]
+    #[test_case(&["-f", "listing", "query", "--include-categories", "Nd", "--max-codepoint", "128"], "U+0030..U+0039  0..9  (10 codepoints)\nTotal: 10 codepoints in 1 interval\n")]
+    #[test_case(&["-f", "chars", "query", "--include-categories", "Nd", "--max-codepoint", "128"], "0123456789\n")]
+    #[test_case(&["-f", "chars", "query", "--include-categories", "Cs", "--max-codepoint", "0xFFFF"], "\n")]
+    #[test_case(&["query", "--include-categories", "Cf", "--min-codepoint", "0x2000", "--max-codepoint", "0x2010", "--grapheme-cluster-breaks", "Extend,ZWJ"], "(8204, 8205)\n")]
+    #[test_case(&["-f", "json", "as-general-categories", "N"], "[\"Nl\",\"Nd\",\"No\"]\n")]
+    #[test_case(&["category", "A", "U+2603"], "U+0041\tLu\nU+2603\tSo\n")]
+    #[test_case(&["category", "--", "-a"], "U+002D\tPd\nU+0061\tLl\n")]
+    #[test_case(&["--", "category", "--help"], "U+002D\tPd\nU+002D\tPd\nU+0068\tLl\nU+0065\tLl\nU+006C\tLl\nU+0070\tLl\n")]
+    #[test_case(&["category", "0xg"], "U+0030\tNd\nU+0078\tLl\nU+0067\tLl\n")]
+    #[test_case(&["-f", "json", "category", "0x30"], "[{\"codepoint\":48,\"category\":\"Nd\"}]\n")]
+    #[test_case(&["-u", "9.0", "emoji-sequences", "Tag"], "")]
+    #[test_case(&["-f", "json", "emoji-sequences", "Tag"], "[\"\u{1f3f4}\u{e0067}\u{e0062}\u{e0065}\u{e006e}\u{e0067}\u{e007f}\",\"\u{1f3f4}\u{e0067}\u{e0062}\u{e0073}\u{e0063}\u{e0074}\u{e007f}\",\"\u{1f3f4}\u{e0067}\u{e0062}\u{e0077}\u{e006c}\u{e0073}\u{e007f}\"]\n")]
+    fn commands_output(values: &[&str], expected: &str) {
+        assert_eq!(execute(values), Ok(expected.to_string()))
+    }
+
+    #[test]
+    fn empty_regex_matches_nothing() {
+        assert_eq!(
+            execute(&[
+                "-f",
+                "regex",
+                "query",
+                "--include-categories",
+                "Lu",
+                "--max-codepoint",
+                "10"
+            ]),
+            Ok("[^\\x{0}-\\x{10FFFF}]\n".to_string())
+        )
+    }
+
+    #[test_case(&["query", "--include-categories", "Xx"]; "invalid category")]
+    #[test_case(&["query", "--min-codepoint", "10", "--max-codepoint", "5"]; "invalid codepoints")]
+    #[test_case(&["-f", "regex", "categories"]; "unsupported format")]
+    #[test_case(&["category", "0x110000"]; "codepoint out of range")]
+    #[test_case(&["query", "--bidi-classes", "XX"]; "invalid bidi class")]
+    #[test_case(&["query", "--case-properties", "Upper"]; "invalid case property")]
+    #[test_case(&["query", "--grapheme-cluster-breaks", "Other"]; "invalid grapheme cluster break")]
+    #[test_case(&["emoji-sequences", "Emoji"]; "invalid emoji sequence kind")]
+    #[test_case(&["query", "--normalization-stable", "NFX"]; "invalid normalization form")]
+    fn command_errors(values: &[&str]) {
+        assert!(execute(values).is_err())
+    }
+}
@@ -0,0 +1,101 @@
+use crate::Error;
+use std::convert::TryFrom;
+use std::fmt::{self, Display, Formatter};
+
+/// Unicode normalization forms.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum NormalizationForm {
+    /// Canonical decomposition, followed by canonical composition.
+    Nfc,
+    /// Canonical decomposition.
+    Nfd,
+    /// Compatibility decomposition, followed by canonical composition.
+    Nfkc,
+    /// Compatibility decomposition.
+    Nfkd,
+}
+
+impl NormalizationForm {
+    /// All normalization forms.
+    pub const ALL: [NormalizationForm; 4] = [
+        NormalizationForm::Nfc,
+        NormalizationForm::Nfd,
+        NormalizationForm::Nfkc,
+        NormalizationForm::Nfkd,
+    ];
+
+    /// Form name as used in the normalization tables, e.g. `NFKC`.
+    #[inline]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            NormalizationForm::Nfc => "NFC",
+            NormalizationForm::Nfd => "NFD",
+            NormalizationForm::Nfkc => "NFKC",
+            NormalizationForm::Nfkd => "NFKD",
+        }
+    }
+}
+
+/// Quick check value of a codepoint for a normalization form.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum QuickCheck {
+    /// The codepoint never changes when normalized.
+    Yes,
+    /// The codepoint changes when normalized, even on its own.
+    No,
+    /// The codepoint changes when normalized only after some characters, e.g. a combining mark
+    /// that composes with a preceding base letter. Only composition forms have such codepoints.
+    Maybe,
+}
+
+impl QuickCheck {
+    /// Value abbreviation as used in the quick check tables, e.g. `N`.
+    #[inline]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            QuickCheck::Yes => "Y",
+            QuickCheck::No => "N",
+            QuickCheck::Maybe => "M",
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a str> for NormalizationForm {
+    type Error = Error<'a>;
+
+    /// Parse a form name as returned by `NormalizationForm::as_str`.
+    #[inline]
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        NormalizationForm::ALL
+            .iter()
+            .copied()
+            .find(|form| form.as_str() == value)
+            .ok_or(Error::InvalidPropertyValue(value))
+    }
+}
+
+impl Display for NormalizationForm {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        for form in NormalizationForm::ALL.iter().copied() {
+            assert_eq!(NormalizationForm::try_from(form.as_str()), Ok(form));
+        }
+    }
+
+    #[test]
+    fn invalid_name() {
+        assert_eq!(
+            NormalizationForm::try_from("nfc"),
+            Err(Error::InvalidPropertyValue("nfc"))
+        );
+    }
+}
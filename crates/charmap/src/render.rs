@@ -0,0 +1,337 @@
+use crate::inner::subtract_intervals;
+use crate::Interval;
+use std::fmt::Write;
+
+/// Surrogates are not valid `char` values and can't be matched by a regex or a `char` pattern.
+const SURROGATES: [Interval; 1] = [(0xD800, 0xDFFF)];
+
+/// Regular expression dialect that a character class is rendered for.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum RegexFlavor {
+    /// The `regex` crate. Printable non-ASCII letters and digits are written literally.
+    Rust,
+    /// PCRE / PCRE2 in UTF mode. All non-ASCII characters are written as `\x{...}` escapes.
+    Pcre,
+}
+
+impl RegexFlavor {
+    #[inline]
+    fn is_class_meta(self, c: char) -> bool {
+        match self {
+            // Besides the usual class metacharacters, `regex` supports class set operations
+            // (`&&`, `--`, `~~`) and comments in the verbose mode
+            RegexFlavor::Rust => matches!(c, '\\' | '[' | ']' | '^' | '-' | '&' | '~' | '#'),
+            RegexFlavor::Pcre => matches!(c, '\\' | '[' | ']' | '^' | '-'),
+        }
+    }
+
+    fn push(self, out: &mut String, codepoint: u32) {
+        match std::char::from_u32(codepoint) {
+            Some(c) if c.is_ascii_graphic() => {
+                if self.is_class_meta(c) {
+                    out.push('\\');
+                }
+                out.push(c)
+            }
+            Some(c) if self == RegexFlavor::Rust && c.is_alphanumeric() => out.push(c),
+            _ => {
+                let _ = write!(out, "\\x{{{:X}}}", codepoint);
+            }
+        }
+    }
+}
+
+/// Render intervals as a regular expression character class, e.g. `[0-9A-Z\x{2603}]`.
+///
+/// Surrogates are skipped. An empty set is rendered as a class that matches nothing.
+#[inline]
+pub fn to_regex_class(intervals: &[Interval], flavor: RegexFlavor) -> String {
+    let intervals = subtract_intervals(intervals.to_vec(), &SURROGATES);
+    if intervals.is_empty() {
+        return "[^\\x{0}-\\x{10FFFF}]".to_string();
+    }
+    let mut out = String::with_capacity(intervals.len().saturating_mul(8));
+    out.push('[');
+    for &(left, right) in &intervals {
+        flavor.push(&mut out, left);
+        if right > left {
+            // `ab` is as short as `a-b`, ranges pay off only from three codepoints
+            if right > left.saturating_add(1) {
+                out.push('-');
+            }
+            flavor.push(&mut out, right);
+        }
+    }
+    out.push(']');
+    out
+}
+
+fn push_char_literal(out: &mut String, c: char) {
+    if (c.is_ascii_graphic() && c != '\'' && c != '\\') || c == ' ' {
+        out.push('\'');
+        out.push(c);
+        out.push('\'');
+    } else {
+        let _ = write!(out, "'\\u{{{:x}}}'", c as u32);
+    }
+}
+
+/// Render intervals as a Rust pattern suitable for `matches!`, e.g. `'0'..='9' | '\u{2603}'`.
+///
+/// Surrogates are skipped. Returns `None` for an empty set, which can't be expressed as a pattern.
+#[inline]
+pub fn to_matches_pattern(intervals: &[Interval]) -> Option<String> {
+    let intervals = subtract_intervals(intervals.to_vec(), &SURROGATES);
+    let mut out = String::with_capacity(intervals.len().saturating_mul(16));
+    for &(left, right) in &intervals {
+        if let (Some(left), Some(right)) = (std::char::from_u32(left), std::char::from_u32(right)) {
+            if !out.is_empty() {
+                out.push_str(" | ");
+            }
+            push_char_literal(&mut out, left);
+            if right > left {
+                out.push_str("..=");
+                push_char_literal(&mut out, right);
+            }
+        }
+    }
+    if out.is_empty() {
+        None
+    } else {
+        Some(out)
+    }
+}
+
+fn displayable(codepoint: u32) -> Option<char> {
+    std::char::from_u32(codepoint).filter(|c| c.is_ascii_graphic() || c.is_alphanumeric())
+}
+
+/// Render intervals as a human-readable listing, one interval per line.
+///
+/// ```text
+/// U+0030..U+0039  0..9  (10 codepoints)
+/// U+2603
+/// Total: 11 codepoints in 2 intervals
+/// ```
+#[inline]
+// Practically all interval values are < u32::MAX
+// Therefore there will be no panic (debug) / wrapping (release)
+#[allow(clippy::arithmetic_side_effects)]
+pub fn to_listing(intervals: &[Interval]) -> String {
+    let mut out = String::new();
+    let mut total = 0u64;
+    for &(left, right) in intervals {
+        let size = u64::from(right - left) + 1;
+        total += size;
+        if left == right {
+            let _ = write!(out, "U+{:04X}", left);
+            if let Some(c) = displayable(left) {
+                let _ = write!(out, "  {}", c);
+            }
+        } else {
+            let _ = write!(out, "U+{:04X}..U+{:04X}", left, right);
+            if let (Some(l), Some(r)) = (displayable(left), displayable(right)) {
+                let _ = write!(out, "  {}..{}", l, r);
+            }
+            let _ = write!(out, "  ({} codepoints)", size);
+        }
+        out.push('\n');
+    }
+    let _ = write!(
+        out,
+        "Total: {} {} in {} {}",
+        total,
+        plural(total, "codepoint"),
+        intervals.len(),
+        plural(intervals.len() as u64, "interval")
+    );
+    out
+}
+
+fn plural(count: u64, word: &str) -> String {
+    if count == 1 {
+        word.to_string()
+    } else {
+        format!("{}s", word)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::UnicodeVersion;
+    use regex::Regex;
+    use test_case::test_case;
+
+    fn contains(intervals: &[Interval], codepoint: u32) -> bool {
+        intervals
+            .iter()
+            .any(|&(left, right)| left <= codepoint && codepoint <= right)
+    }
+
+    /// Codepoints worth checking: everything in the BMP plus the interval borders
+    fn probes(intervals: &[Interval]) -> Vec<u32> {
+        let mut out: Vec<u32> = (0..=0xFFFF).collect();
+        for &(left, right) in intervals {
+            out.extend_from_slice(&[left.saturating_sub(1), left, right, right.saturating_add(1)]);
+        }
+        out.push(0x10FFFF);
+        out
+    }
+
+    fn sample_queries() -> Vec<Vec<Interval>> {
+        let version = UnicodeVersion::V13;
+        vec![
+            version
+                .query(None, Some(&["Lu", "Nd"]), None, Some(0x1000), None, None)
+                .expect("Valid query"),
+            version
+                .query(None, Some(&["Zs", "Cf", "Mn"]), None, None, None, None)
+                .expect("Valid query"),
+            version
+                .query(None, Some(&["Cs"]), None, None, Some("\\[]^-&~#'"), None)
+                .expect("Valid query"),
+            version
+                .query(None, None, None, None, None, None)
+                .expect("Valid query"),
+            vec![],
+        ]
+    }
+
+    #[test]
+    fn regex_class_roundtrip() {
+        for intervals in sample_queries() {
+            let class = to_regex_class(&intervals, RegexFlavor::Rust);
+            let re = Regex::new(&format!("^{}$", class)).expect("Valid regex");
+            for codepoint in probes(&intervals) {
+                if let Some(c) = std::char::from_u32(codepoint) {
+                    assert_eq!(
+                        re.is_match(c.encode_utf8(&mut [0; 4])),
+                        contains(&intervals, codepoint),
+                        "{:#X} in {}",
+                        codepoint,
+                        class
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn pcre_class_roundtrip() {
+        for intervals in sample_queries() {
+            let class = to_regex_class(&intervals, RegexFlavor::Pcre);
+            let re = pcre2::bytes::RegexBuilder::new()
+                .utf(true)
+                .build(&format!("^{}$", class))
+                .expect("Valid PCRE pattern");
+            for codepoint in probes(&intervals) {
+                if let Some(c) = std::char::from_u32(codepoint) {
+                    assert_eq!(
+                        re.is_match(c.encode_utf8(&mut [0; 4]).as_bytes())
+                            .expect("Matching succeeds"),
+                        contains(&intervals, codepoint),
+                        "{:#X} in {}",
+                        codepoint,
+                        class
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn regex_class_roundtrip_verbose_mode() {
+        let intervals = [(32, 35), (126, 126)];
+        let class = to_regex_class(&intervals, RegexFlavor::Rust);
+        let re = Regex::new(&format!("(?x)^{}$", class)).expect("Valid regex");
+        for codepoint in 0..128 {
+            let c = std::char::from_u32(codepoint).expect("ASCII");
+            assert_eq!(
+                re.is_match(c.encode_utf8(&mut [0; 4])),
+                contains(&intervals, codepoint)
+            );
+        }
+    }
+
+    #[test_case(&[(48, 57), (65, 90), (9731, 9731)], RegexFlavor::Rust, "[0-9A-Z\\x{2603}]")]
+    #[test_case(&[(48, 57), (65, 90), (9731, 9731)], RegexFlavor::Pcre, "[0-9A-Z\\x{2603}]")]
+    #[test_case(&[(0x0430, 0x044F)], RegexFlavor::Rust, "[а-я]")]
+    #[test_case(&[(0x0430, 0x044F)], RegexFlavor::Pcre, "[\\x{430}-\\x{44F}]")]
+    #[test_case(&[(38, 38), (45, 45), (91, 94)], RegexFlavor::Rust, "[\\&\\-\\[-\\^]")]
+    #[test_case(&[(38, 38), (45, 45), (91, 94)], RegexFlavor::Pcre, "[&\\-\\[-\\^]")]
+    #[test_case(&[(97, 98)], RegexFlavor::Rust, "[ab]")]
+    #[test_case(&[(0, 31)], RegexFlavor::Pcre, "[\\x{0}-\\x{1F}]")]
+    #[test_case(&[(0xD7FF, 0xE000)], RegexFlavor::Pcre, "[\\x{D7FF}\\x{E000}]")]
+    #[test_case(&[], RegexFlavor::Rust, "[^\\x{0}-\\x{10FFFF}]")]
+    fn regex_class(intervals: &[Interval], flavor: RegexFlavor, expected: &str) {
+        assert_eq!(to_regex_class(intervals, flavor), expected)
+    }
+
+    /// Parse a pattern produced by `to_matches_pattern` back to intervals
+    fn parse_pattern(pattern: &str) -> Vec<Interval> {
+        let parse_char = |literal: &str| -> u32 {
+            let inner = literal
+                .strip_prefix('\'')
+                .and_then(|l| l.strip_suffix('\''))
+                .expect("Quoted char");
+            if let Some(hex) = inner.strip_prefix("\\u{").and_then(|l| l.strip_suffix('}')) {
+                u32::from_str_radix(hex, 16).expect("Hex codepoint")
+            } else {
+                let mut chars = inner.chars();
+                let c = chars.next().expect("One char");
+                assert_eq!(chars.next(), None);
+                c as u32
+            }
+        };
+        pattern
+            .split(" | ")
+            .map(|item| {
+                if let Some((left, right)) = item.split_once("..=") {
+                    (parse_char(left), parse_char(right))
+                } else {
+                    let c = parse_char(item);
+                    (c, c)
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn matches_pattern_roundtrip() {
+        for intervals in sample_queries() {
+            let expected = subtract_intervals(intervals.clone(), &SURROGATES);
+            match to_matches_pattern(&intervals) {
+                Some(pattern) => assert_eq!(parse_pattern(&pattern), expected),
+                None => assert!(expected.is_empty()),
+            }
+        }
+    }
+
+    #[test_case(&[(48, 57), (9731, 9731)], Some("'0'..='9' | '\\u{2603}'"))]
+    #[test_case(&[(39, 39), (92, 92), (32, 32)], Some("'\\u{27}' | '\\u{5c}' | ' '"))]
+    #[test_case(&[(0xD800, 0xDFFF)], None)]
+    #[test_case(&[], None)]
+    fn matches_pattern(intervals: &[Interval], expected: Option<&str>) {
+        assert_eq!(to_matches_pattern(intervals).as_deref(), expected)
+    }
+
+    #[test]
+    fn listing() {
+        assert_eq!(
+            to_listing(&[(0, 31), (48, 57), (9731, 9731)]),
+            "U+0000..U+001F  (32 codepoints)\n\
+             U+0030..U+0039  0..9  (10 codepoints)\n\
+             U+2603\n\
+             Total: 43 codepoints in 3 intervals"
+        )
+    }
+
+    #[test]
+    fn listing_of_a_single_codepoint() {
+        assert_eq!(
+            to_listing(&[(65, 65)]),
+            "U+0041  A\nTotal: 1 codepoint in 1 interval"
+        )
+    }
+}
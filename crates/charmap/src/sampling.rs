@@ -0,0 +1,313 @@
+use crate::inner::{intersect_intervals, subtract_intervals};
+use crate::{Category, Error, Interval, IntervalSet, UnicodeVersion};
+
+const ASCII: [Interval; 1] = [(0, 127)];
+
+/// Relative weights of codepoint groups used by `Sampler`.
+///
+/// Every group gets a share of samples proportional to its weight, no matter how many codepoints
+/// it contains. Codepoints are distributed uniformly within a group.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Weights<'a> {
+    /// Weight of ASCII codepoints. If set, they form a separate group and are excluded from their
+    /// categories.
+    pub ascii: Option<u32>,
+    /// Weights of individual categories, e.g. `[("Zs", 10), ("Lo", 1)]`.
+    pub categories: &'a [(&'a str, u32)],
+    /// Weight of every category that is not listed in `categories`.
+    pub default: u32,
+}
+
+/// Default weights, biased towards ASCII and categories that tend to uncover bugs (whitespace,
+/// format characters and combining marks) rather than towards the largest categories.
+/// Surrogates are excluded, as they are not valid `char` values.
+pub const DEFAULT_WEIGHTS: Weights<'static> = Weights {
+    ascii: Some(40),
+    categories: &[
+        ("Zs", 8),
+        ("Zl", 4),
+        ("Zp", 4),
+        ("Cf", 8),
+        ("Cc", 4),
+        ("Mn", 6),
+        ("Mc", 4),
+        ("Me", 4),
+        ("Lu", 3),
+        ("Ll", 3),
+        ("Lt", 3),
+        ("Lm", 2),
+        ("Nd", 3),
+        ("Co", 1),
+        ("Cn", 1),
+        ("Cs", 0),
+    ],
+    default: 2,
+};
+
+impl Default for Weights<'static> {
+    #[inline]
+    fn default() -> Self {
+        DEFAULT_WEIGHTS
+    }
+}
+
+/// Codepoints sharing the same weight.
+#[derive(Debug, Clone)]
+struct Group {
+    weight: u64,
+    codepoints: IntervalSet,
+}
+
+impl Group {
+    fn new(weight: u32, intervals: Vec<Interval>) -> Group {
+        Group {
+            weight: u64::from(weight),
+            codepoints: IntervalSet::new(intervals).expect("Query results are valid intervals"),
+        }
+    }
+}
+
+/// Maps indices to codepoints so that categories are drawn according to their weights rather
+/// than their sizes.
+///
+/// The same index always maps to the same codepoint, which makes the output reproducible.
+/// ```rust
+/// use charmap::{UnicodeVersion, DEFAULT_WEIGHTS};
+///
+/// let domain = UnicodeVersion::V13.query(None, None, None, None, None, None).expect("Valid query");
+/// let sampler = UnicodeVersion::V13.sampler(&domain, &DEFAULT_WEIGHTS).expect("Valid weights");
+/// assert_eq!(sampler.get(42), sampler.get(42));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Sampler {
+    groups: Vec<Group>,
+    total_weight: u64,
+}
+
+impl Sampler {
+    #[allow(clippy::arithmetic_side_effects)]
+    fn new(groups: Vec<Group>) -> Sampler {
+        let groups: Vec<Group> = groups
+            .into_iter()
+            .filter(|group| group.weight > 0 && !group.codepoints.is_empty())
+            .collect();
+        // At most 31 groups with u32 weights each
+        let total_weight = groups.iter().map(|group| group.weight).sum();
+        Sampler {
+            groups,
+            total_weight,
+        }
+    }
+
+    /// Map an index to a codepoint. Returns `None` if the sampler can't produce any codepoints.
+    ///
+    /// The lowest part of the index (modulo the total weight) selects a group, the rest selects a
+    /// codepoint within the group. Therefore, consecutive indices cycle through all groups.
+    #[inline]
+    // `total_weight` and group sizes are checked to be non-zero
+    #[allow(clippy::arithmetic_side_effects)]
+    pub fn get(&self, index: u64) -> Option<u32> {
+        if self.total_weight == 0 {
+            return None;
+        }
+        let mut selector = index % self.total_weight;
+        let rest = index / self.total_weight;
+        for group in &self.groups {
+            if selector < group.weight {
+                return group.codepoints.get(rest % group.codepoints.len());
+            }
+            selector -= group.weight;
+        }
+        unreachable!("Selector is always less than the total weight")
+    }
+
+    /// Whether the sampler can't produce any codepoints.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.total_weight == 0
+    }
+
+    /// Number of distinct codepoints the sampler can produce.
+    #[inline]
+    pub fn size(&self) -> u64 {
+        self.groups.iter().map(|group| group.codepoints.len()).sum()
+    }
+
+    /// Probability of drawing a codepoint from the given category for a uniformly random index.
+    #[inline]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn probability(&self, version: UnicodeVersion, category: Category) -> f64 {
+        let intervals = match version.charmap().get(category) {
+            Some(intervals) => *intervals,
+            None => return 0.0,
+        };
+        let mut total = 0.0;
+        for group in &self.groups {
+            let matched: u64 = intersect_intervals(group.codepoints.intervals(), intervals)
+                .iter()
+                .map(|&(left, right)| u64::from(right.saturating_sub(left)).saturating_add(1))
+                .sum();
+            total += (group.weight as f64) * (matched as f64) / (group.codepoints.len() as f64);
+        }
+        total / (self.total_weight as f64)
+    }
+}
+
+impl UnicodeVersion {
+    /// Build a sampler over codepoints from `intervals` (usually a `query` result) weighted by
+    /// their categories.
+    #[inline]
+    pub fn sampler<'a>(
+        self,
+        intervals: &[Interval],
+        weights: &Weights<'a>,
+    ) -> Result<Sampler, Error<'a>> {
+        for (category, _) in weights.categories {
+            if !self.charmap().contains_key(category) {
+                return Err(Error::InvalidCategory(category));
+            }
+        }
+        let mut groups = vec![];
+        let domain = match weights.ascii {
+            Some(weight) => {
+                groups.push(Group::new(weight, intersect_intervals(intervals, &ASCII)));
+                subtract_intervals(intervals.to_vec(), &ASCII)
+            }
+            None => intervals.to_vec(),
+        };
+        for category in self.categories() {
+            let weight = weights
+                .categories
+                .iter()
+                .find(|(name, _)| name == category)
+                .map_or(weights.default, |(_, weight)| *weight);
+            let category_intervals = self
+                .charmap()
+                .get(category)
+                .expect("It should be a valid Unicode category");
+            groups.push(Group::new(
+                weight,
+                intersect_intervals(&domain, category_intervals),
+            ));
+        }
+        Ok(Sampler::new(groups))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn all(version: UnicodeVersion) -> Vec<Interval> {
+        version
+            .query(None, None, None, None, None, None)
+            .expect("Valid query")
+    }
+
+    #[test]
+    fn deterministic() {
+        let version = UnicodeVersion::V13;
+        let first = version
+            .sampler(&all(version), &DEFAULT_WEIGHTS)
+            .expect("Valid weights");
+        let second = version
+            .sampler(&all(version), &DEFAULT_WEIGHTS)
+            .expect("Valid weights");
+        for index in (0..100_000).chain(u64::MAX - 1000..=u64::MAX) {
+            assert_eq!(first.get(index), second.get(index));
+        }
+    }
+
+    #[test]
+    fn stays_within_domain() {
+        let version = UnicodeVersion::V13;
+        let domain = version
+            .query(
+                None,
+                Some(&["Lu", "Zs"]),
+                None,
+                Some(0x3000),
+                Some("☃"),
+                None,
+            )
+            .expect("Valid query");
+        let sampler = version
+            .sampler(&domain, &DEFAULT_WEIGHTS)
+            .expect("Valid weights");
+        for index in 0..10_000 {
+            let codepoint = sampler.get(index).expect("Non-empty sampler");
+            assert!(domain
+                .iter()
+                .any(|&(left, right)| left <= codepoint && codepoint <= right));
+        }
+    }
+
+    #[test]
+    fn covers_all_codepoints() {
+        let version = UnicodeVersion::V13;
+        let domain = [(48, 57), (0x2000, 0x200F)];
+        let sampler = version
+            .sampler(&domain, &DEFAULT_WEIGHTS)
+            .expect("Valid weights");
+        assert_eq!(sampler.size(), 26);
+        let mut seen: Vec<u32> = (0..10_000).filter_map(|index| sampler.get(index)).collect();
+        seen.sort_unstable();
+        seen.dedup();
+        assert_eq!(seen.len(), 26);
+    }
+
+    #[test]
+    fn follows_weights() {
+        let version = UnicodeVersion::V13;
+        let weights = Weights {
+            ascii: Some(1),
+            categories: &[("Zs", 3)],
+            default: 0,
+        };
+        let sampler = version
+            .sampler(&all(version), &weights)
+            .expect("Valid weights");
+        let spaces = (0..4000)
+            .filter_map(|index| sampler.get(index))
+            .filter(|&codepoint| codepoint > 127)
+            .inspect(|&codepoint| assert_eq!(version.category(codepoint), Some("Zs")))
+            .count();
+        assert_eq!(spaces, 3000);
+        // The ASCII group contributes the space character
+        let expected = 0.75 + 0.25 / 128.0;
+        assert!((sampler.probability(version, "Zs") - expected).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn default_weights_prefer_small_categories() {
+        let version = UnicodeVersion::V13;
+        let sampler = version
+            .sampler(&all(version), &Weights::default())
+            .expect("Valid weights");
+        assert!(sampler.probability(version, "Zs") > sampler.probability(version, "Lo"));
+        assert!(sampler.probability(version, "Cf") > sampler.probability(version, "Cn"));
+        assert!(sampler.probability(version, "Cs").abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn empty() {
+        let version = UnicodeVersion::V13;
+        let sampler = version
+            .sampler(&[(0xD800, 0xDFFF)], &DEFAULT_WEIGHTS)
+            .expect("Valid weights");
+        assert!(sampler.is_empty());
+        assert_eq!(sampler.get(0), None);
+    }
+
+    #[test]
+    fn invalid_category() {
+        let weights = Weights {
+            categories: &[("Xx", 1)],
+            ..DEFAULT_WEIGHTS
+        };
+        assert_eq!(
+            UnicodeVersion::V13.sampler(&[], &weights).err(),
+            Some(Error::InvalidCategory("Xx"))
+        );
+    }
+}
@@ -0,0 +1,1467 @@
+// DO NOT EDIT THIS FILE. IT WAS AUTOMATICALLY GENERATED BY:
+//
+//   python generate_tables.py 11.0.0
+//
+// Unicode version: 11.0.0.
+
+pub const BY_NAME: &'static [(&'static str, &'static [(u32, u32)])] = &[
+    ("AL", ARABIC_LETTER),
+    ("AN", ARABIC_NUMBER),
+    ("B", PARAGRAPH_SEPARATOR),
+    ("BN", BOUNDARY_NEUTRAL),
+    ("CS", COMMON_SEPARATOR),
+    ("EN", EUROPEAN_NUMBER),
+    ("ES", EUROPEAN_SEPARATOR),
+    ("ET", EUROPEAN_TERMINATOR),
+    ("FSI", FIRST_STRONG_ISOLATE),
+    ("L", LEFT_TO_RIGHT),
+    ("LRE", LEFT_TO_RIGHT_EMBEDDING),
+    ("LRI", LEFT_TO_RIGHT_ISOLATE),
+    ("LRO", LEFT_TO_RIGHT_OVERRIDE),
+    ("NSM", NONSPACING_MARK),
+    ("ON", OTHER_NEUTRAL),
+    ("PDF", POP_DIRECTIONAL_FORMAT),
+    ("PDI", POP_DIRECTIONAL_ISOLATE),
+    ("R", RIGHT_TO_LEFT),
+    ("RLE", RIGHT_TO_LEFT_EMBEDDING),
+    ("RLI", RIGHT_TO_LEFT_ISOLATE),
+    ("RLO", RIGHT_TO_LEFT_OVERRIDE),
+    ("S", SEGMENT_SEPARATOR),
+    ("WS", WHITE_SPACE),
+];
+
+pub const ARABIC_LETTER: &'static [(u32, u32)] = &[
+    (1544, 1544),
+    (1547, 1547),
+    (1549, 1549),
+    (1563, 1564),
+    (1566, 1610),
+    (1645, 1647),
+    (1649, 1749),
+    (1765, 1766),
+    (1774, 1775),
+    (1786, 1805),
+    (1807, 1808),
+    (1810, 1839),
+    (1869, 1957),
+    (1969, 1969),
+    (2144, 2154),
+    (2208, 2228),
+    (2230, 2237),
+    (64336, 64449),
+    (64467, 64829),
+    (64848, 64911),
+    (64914, 64967),
+    (65008, 65020),
+    (65136, 65140),
+    (65142, 65276),
+    (68864, 68899),
+    (69424, 69445),
+    (69457, 69465),
+    (126065, 126132),
+    (126464, 126467),
+    (126469, 126495),
+    (126497, 126498),
+    (126500, 126500),
+    (126503, 126503),
+    (126505, 126514),
+    (126516, 126519),
+    (126521, 126521),
+    (126523, 126523),
+    (126530, 126530),
+    (126535, 126535),
+    (126537, 126537),
+    (126539, 126539),
+    (126541, 126543),
+    (126545, 126546),
+    (126548, 126548),
+    (126551, 126551),
+    (126553, 126553),
+    (126555, 126555),
+    (126557, 126557),
+    (126559, 126559),
+    (126561, 126562),
+    (126564, 126564),
+    (126567, 126570),
+    (126572, 126578),
+    (126580, 126583),
+    (126585, 126588),
+    (126590, 126590),
+    (126592, 126601),
+    (126603, 126619),
+    (126625, 126627),
+    (126629, 126633),
+    (126635, 126651),
+];
+
+pub const ARABIC_NUMBER: &'static [(u32, u32)] = &[
+    (1536, 1541),
+    (1632, 1641),
+    (1643, 1644),
+    (1757, 1757),
+    (2274, 2274),
+    (68912, 68921),
+    (69216, 69246),
+];
+
+pub const PARAGRAPH_SEPARATOR: &'static [(u32, u32)] =
+    &[(10, 10), (13, 13), (28, 30), (133, 133), (8233, 8233)];
+
+pub const BOUNDARY_NEUTRAL: &'static [(u32, u32)] = &[
+    (0, 8),
+    (14, 27),
+    (127, 132),
+    (134, 159),
+    (173, 173),
+    (6158, 6158),
+    (8203, 8205),
+    (8288, 8292),
+    (8298, 8303),
+    (65279, 65279),
+    (113824, 113827),
+    (119155, 119162),
+    (917505, 917505),
+    (917536, 917631),
+];
+
+pub const COMMON_SEPARATOR: &'static [(u32, u32)] = &[
+    (44, 44),
+    (46, 47),
+    (58, 58),
+    (160, 160),
+    (1548, 1548),
+    (8239, 8239),
+    (8260, 8260),
+    (65104, 65104),
+    (65106, 65106),
+    (65109, 65109),
+    (65292, 65292),
+    (65294, 65295),
+    (65306, 65306),
+];
+
+pub const EUROPEAN_NUMBER: &'static [(u32, u32)] = &[
+    (48, 57),
+    (178, 179),
+    (185, 185),
+    (1776, 1785),
+    (8304, 8304),
+    (8308, 8313),
+    (8320, 8329),
+    (9352, 9371),
+    (65296, 65305),
+    (66273, 66299),
+    (120782, 120831),
+    (127232, 127242),
+];
+
+pub const EUROPEAN_SEPARATOR: &'static [(u32, u32)] = &[
+    (43, 43),
+    (45, 45),
+    (8314, 8315),
+    (8330, 8331),
+    (8722, 8722),
+    (64297, 64297),
+    (65122, 65123),
+    (65291, 65291),
+    (65293, 65293),
+];
+
+pub const EUROPEAN_TERMINATOR: &'static [(u32, u32)] = &[
+    (35, 37),
+    (162, 165),
+    (176, 177),
+    (1423, 1423),
+    (1545, 1546),
+    (1642, 1642),
+    (2546, 2547),
+    (2555, 2555),
+    (2801, 2801),
+    (3065, 3065),
+    (3647, 3647),
+    (6107, 6107),
+    (8240, 8244),
+    (8352, 8383),
+    (8494, 8494),
+    (8723, 8723),
+    (43064, 43065),
+    (65119, 65119),
+    (65129, 65130),
+    (65283, 65285),
+    (65504, 65505),
+    (65509, 65510),
+];
+
+pub const FIRST_STRONG_ISOLATE: &'static [(u32, u32)] = &[(8296, 8296)];
+
+pub const LEFT_TO_RIGHT: &'static [(u32, u32)] = &[
+    (65, 90),
+    (97, 122),
+    (170, 170),
+    (181, 181),
+    (186, 186),
+    (192, 214),
+    (216, 246),
+    (248, 696),
+    (699, 705),
+    (720, 721),
+    (736, 740),
+    (750, 750),
+    (880, 883),
+    (886, 887),
+    (890, 893),
+    (895, 895),
+    (902, 902),
+    (904, 906),
+    (908, 908),
+    (910, 929),
+    (931, 1013),
+    (1015, 1154),
+    (1162, 1327),
+    (1329, 1366),
+    (1369, 1417),
+    (2307, 2361),
+    (2363, 2363),
+    (2365, 2368),
+    (2377, 2380),
+    (2382, 2384),
+    (2392, 2401),
+    (2404, 2432),
+    (2434, 2435),
+    (2437, 2444),
+    (2447, 2448),
+    (2451, 2472),
+    (2474, 2480),
+    (2482, 2482),
+    (2486, 2489),
+    (2493, 2496),
+    (2503, 2504),
+    (2507, 2508),
+    (2510, 2510),
+    (2519, 2519),
+    (2524, 2525),
+    (2527, 2529),
+    (2534, 2545),
+    (2548, 2554),
+    (2556, 2557),
+    (2563, 2563),
+    (2565, 2570),
+    (2575, 2576),
+    (2579, 2600),
+    (2602, 2608),
+    (2610, 2611),
+    (2613, 2614),
+    (2616, 2617),
+    (2622, 2624),
+    (2649, 2652),
+    (2654, 2654),
+    (2662, 2671),
+    (2674, 2676),
+    (2678, 2678),
+    (2691, 2691),
+    (2693, 2701),
+    (2703, 2705),
+    (2707, 2728),
+    (2730, 2736),
+    (2738, 2739),
+    (2741, 2745),
+    (2749, 2752),
+    (2761, 2761),
+    (2763, 2764),
+    (2768, 2768),
+    (2784, 2785),
+    (2790, 2800),
+    (2809, 2809),
+    (2818, 2819),
+    (2821, 2828),
+    (2831, 2832),
+    (2835, 2856),
+    (2858, 2864),
+    (2866, 2867),
+    (2869, 2873),
+    (2877, 2878),
+    (2880, 2880),
+    (2887, 2888),
+    (2891, 2892),
+    (2903, 2903),
+    (2908, 2909),
+    (2911, 2913),
+    (2918, 2935),
+    (2947, 2947),
+    (2949, 2954),
+    (2958, 2960),
+    (2962, 2965),
+    (2969, 2970),
+    (2972, 2972),
+    (2974, 2975),
+    (2979, 2980),
+    (2984, 2986),
+    (2990, 3001),
+    (3006, 3007),
+    (3009, 3010),
+    (3014, 3016),
+    (3018, 3020),
+    (3024, 3024),
+    (3031, 3031),
+    (3046, 3058),
+    (3073, 3075),
+    (3077, 3084),
+    (3086, 3088),
+    (3090, 3112),
+    (3114, 3129),
+    (3133, 3133),
+    (3137, 3140),
+    (3160, 3162),
+    (3168, 3169),
+    (3174, 3183),
+    (3199, 3200),
+    (3202, 3212),
+    (3214, 3216),
+    (3218, 3240),
+    (3242, 3251),
+    (3253, 3257),
+    (3261, 3268),
+    (3270, 3272),
+    (3274, 3275),
+    (3285, 3286),
+    (3294, 3294),
+    (3296, 3297),
+    (3302, 3311),
+    (3313, 3314),
+    (3330, 3331),
+    (3333, 3340),
+    (3342, 3344),
+    (3346, 3386),
+    (3389, 3392),
+    (3398, 3400),
+    (3402, 3404),
+    (3406, 3407),
+    (3412, 3425),
+    (3430, 3455),
+    (3458, 3459),
+    (3461, 3478),
+    (3482, 3505),
+    (3507, 3515),
+    (3517, 3517),
+    (3520, 3526),
+    (3535, 3537),
+    (3544, 3551),
+    (3558, 3567),
+    (3570, 3572),
+    (3585, 3632),
+    (3634, 3635),
+    (3648, 3654),
+    (3663, 3675),
+    (3713, 3714),
+    (3716, 3716),
+    (3719, 3720),
+    (3722, 3722),
+    (3725, 3725),
+    (3732, 3735),
+    (3737, 3743),
+    (3745, 3747),
+    (3749, 3749),
+    (3751, 3751),
+    (3754, 3755),
+    (3757, 3760),
+    (3762, 3763),
+    (3773, 3773),
+    (3776, 3780),
+    (3782, 3782),
+    (3792, 3801),
+    (3804, 3807),
+    (3840, 3863),
+    (3866, 3892),
+    (3894, 3894),
+    (3896, 3896),
+    (3902, 3911),
+    (3913, 3948),
+    (3967, 3967),
+    (3973, 3973),
+    (3976, 3980),
+    (4030, 4037),
+    (4039, 4044),
+    (4046, 4058),
+    (4096, 4140),
+    (4145, 4145),
+    (4152, 4152),
+    (4155, 4156),
+    (4159, 4183),
+    (4186, 4189),
+    (4193, 4208),
+    (4213, 4225),
+    (4227, 4228),
+    (4231, 4236),
+    (4238, 4252),
+    (4254, 4293),
+    (4295, 4295),
+    (4301, 4301),
+    (4304, 4680),
+    (4682, 4685),
+    (4688, 4694),
+    (4696, 4696),
+    (4698, 4701),
+    (4704, 4744),
+    (4746, 4749),
+    (4752, 4784),
+    (4786, 4789),
+    (4792, 4798),
+    (4800, 4800),
+    (4802, 4805),
+    (4808, 4822),
+    (4824, 4880),
+    (4882, 4885),
+    (4888, 4954),
+    (4960, 4988),
+    (4992, 5007),
+    (5024, 5109),
+    (5112, 5117),
+    (5121, 5759),
+    (5761, 5786),
+    (5792, 5880),
+    (5888, 5900),
+    (5902, 5905),
+    (5920, 5937),
+    (5941, 5942),
+    (5952, 5969),
+    (5984, 5996),
+    (5998, 6000),
+    (6016, 6067),
+    (6070, 6070),
+    (6078, 6085),
+    (6087, 6088),
+    (6100, 6106),
+    (6108, 6108),
+    (6112, 6121),
+    (6160, 6169),
+    (6176, 6264),
+    (6272, 6276),
+    (6279, 6312),
+    (6314, 6314),
+    (6320, 6389),
+    (6400, 6430),
+    (6435, 6438),
+    (6441, 6443),
+    (6448, 6449),
+    (6451, 6456),
+    (6470, 6509),
+    (6512, 6516),
+    (6528, 6571),
+    (6576, 6601),
+    (6608, 6618),
+    (6656, 6678),
+    (6681, 6682),
+    (6686, 6741),
+    (6743, 6743),
+    (6753, 6753),
+    (6755, 6756),
+    (6765, 6770),
+    (6784, 6793),
+    (6800, 6809),
+    (6816, 6829),
+    (6916, 6963),
+    (6965, 6965),
+    (6971, 6971),
+    (6973, 6977),
+    (6979, 6987),
+    (6992, 7018),
+    (7028, 7036),
+    (7042, 7073),
+    (7078, 7079),
+    (7082, 7082),
+    (7086, 7141),
+    (7143, 7143),
+    (7146, 7148),
+    (7150, 7150),
+    (7154, 7155),
+    (7164, 7211),
+    (7220, 7221),
+    (7227, 7241),
+    (7245, 7304),
+    (7312, 7354),
+    (7357, 7367),
+    (7379, 7379),
+    (7393, 7393),
+    (7401, 7404),
+    (7406, 7411),
+    (7413, 7415),
+    (7424, 7615),
+    (7680, 7957),
+    (7960, 7965),
+    (7968, 8005),
+    (8008, 8013),
+    (8016, 8023),
+    (8025, 8025),
+    (8027, 8027),
+    (8029, 8029),
+    (8031, 8061),
+    (8064, 8116),
+    (8118, 8124),
+    (8126, 8126),
+    (8130, 8132),
+    (8134, 8140),
+    (8144, 8147),
+    (8150, 8155),
+    (8160, 8172),
+    (8178, 8180),
+    (8182, 8188),
+    (8206, 8206),
+    (8305, 8305),
+    (8319, 8319),
+    (8336, 8348),
+    (8450, 8450),
+    (8455, 8455),
+    (8458, 8467),
+    (8469, 8469),
+    (8473, 8477),
+    (8484, 8484),
+    (8486, 8486),
+    (8488, 8488),
+    (8490, 8493),
+    (8495, 8505),
+    (8508, 8511),
+    (8517, 8521),
+    (8526, 8527),
+    (8544, 8584),
+    (9014, 9082),
+    (9109, 9109),
+    (9372, 9449),
+    (9900, 9900),
+    (10240, 10495),
+    (11264, 11310),
+    (11312, 11358),
+    (11360, 11492),
+    (11499, 11502),
+    (11506, 11507),
+    (11520, 11557),
+    (11559, 11559),
+    (11565, 11565),
+    (11568, 11623),
+    (11631, 11632),
+    (11648, 11670),
+    (11680, 11686),
+    (11688, 11694),
+    (11696, 11702),
+    (11704, 11710),
+    (11712, 11718),
+    (11720, 11726),
+    (11728, 11734),
+    (11736, 11742),
+    (12293, 12295),
+    (12321, 12329),
+    (12334, 12335),
+    (12337, 12341),
+    (12344, 12348),
+    (12353, 12438),
+    (12445, 12447),
+    (12449, 12538),
+    (12540, 12543),
+    (12549, 12591),
+    (12593, 12686),
+    (12688, 12730),
+    (12784, 12828),
+    (12832, 12879),
+    (12896, 12923),
+    (12927, 12976),
+    (12992, 13003),
+    (13008, 13054),
+    (13056, 13174),
+    (13179, 13277),
+    (13280, 13310),
+    (13312, 19893),
+    (19968, 40943),
+    (40960, 42124),
+    (42192, 42508),
+    (42512, 42539),
+    (42560, 42606),
+    (42624, 42653),
+    (42656, 42735),
+    (42738, 42743),
+    (42786, 42887),
+    (42889, 42937),
+    (42999, 43009),
+    (43011, 43013),
+    (43015, 43018),
+    (43020, 43044),
+    (43047, 43047),
+    (43056, 43063),
+    (43072, 43123),
+    (43136, 43203),
+    (43214, 43225),
+    (43250, 43262),
+    (43264, 43301),
+    (43310, 43334),
+    (43346, 43347),
+    (43359, 43388),
+    (43395, 43442),
+    (43444, 43445),
+    (43450, 43451),
+    (43453, 43469),
+    (43471, 43481),
+    (43486, 43492),
+    (43494, 43518),
+    (43520, 43560),
+    (43567, 43568),
+    (43571, 43572),
+    (43584, 43586),
+    (43588, 43595),
+    (43597, 43597),
+    (43600, 43609),
+    (43612, 43643),
+    (43645, 43695),
+    (43697, 43697),
+    (43701, 43702),
+    (43705, 43709),
+    (43712, 43712),
+    (43714, 43714),
+    (43739, 43755),
+    (43758, 43765),
+    (43777, 43782),
+    (43785, 43790),
+    (43793, 43798),
+    (43808, 43814),
+    (43816, 43822),
+    (43824, 43877),
+    (43888, 44004),
+    (44006, 44007),
+    (44009, 44012),
+    (44016, 44025),
+    (44032, 55203),
+    (55216, 55238),
+    (55243, 55291),
+    (55296, 64109),
+    (64112, 64217),
+    (64256, 64262),
+    (64275, 64279),
+    (65313, 65338),
+    (65345, 65370),
+    (65382, 65470),
+    (65474, 65479),
+    (65482, 65487),
+    (65490, 65495),
+    (65498, 65500),
+    (65536, 65547),
+    (65549, 65574),
+    (65576, 65594),
+    (65596, 65597),
+    (65599, 65613),
+    (65616, 65629),
+    (65664, 65786),
+    (65792, 65792),
+    (65794, 65794),
+    (65799, 65843),
+    (65847, 65855),
+    (65933, 65934),
+    (66000, 66044),
+    (66176, 66204),
+    (66208, 66256),
+    (66304, 66339),
+    (66349, 66378),
+    (66384, 66421),
+    (66432, 66461),
+    (66463, 66499),
+    (66504, 66517),
+    (66560, 66717),
+    (66720, 66729),
+    (66736, 66771),
+    (66776, 66811),
+    (66816, 66855),
+    (66864, 66915),
+    (66927, 66927),
+    (67072, 67382),
+    (67392, 67413),
+    (67424, 67431),
+    (69632, 69632),
+    (69634, 69687),
+    (69703, 69709),
+    (69734, 69743),
+    (69762, 69810),
+    (69815, 69816),
+    (69819, 69825),
+    (69837, 69837),
+    (69840, 69864),
+    (69872, 69881),
+    (69891, 69926),
+    (69932, 69932),
+    (69942, 69958),
+    (69968, 70002),
+    (70004, 70006),
+    (70018, 70069),
+    (70079, 70088),
+    (70093, 70093),
+    (70096, 70111),
+    (70113, 70132),
+    (70144, 70161),
+    (70163, 70190),
+    (70194, 70195),
+    (70197, 70197),
+    (70200, 70205),
+    (70272, 70278),
+    (70280, 70280),
+    (70282, 70285),
+    (70287, 70301),
+    (70303, 70313),
+    (70320, 70366),
+    (70368, 70370),
+    (70384, 70393),
+    (70402, 70403),
+    (70405, 70412),
+    (70415, 70416),
+    (70419, 70440),
+    (70442, 70448),
+    (70450, 70451),
+    (70453, 70457),
+    (70461, 70463),
+    (70465, 70468),
+    (70471, 70472),
+    (70475, 70477),
+    (70480, 70480),
+    (70487, 70487),
+    (70493, 70499),
+    (70656, 70711),
+    (70720, 70721),
+    (70725, 70725),
+    (70727, 70745),
+    (70747, 70747),
+    (70749, 70749),
+    (70784, 70834),
+    (70841, 70841),
+    (70843, 70846),
+    (70849, 70849),
+    (70852, 70855),
+    (70864, 70873),
+    (71040, 71089),
+    (71096, 71099),
+    (71102, 71102),
+    (71105, 71131),
+    (71168, 71218),
+    (71227, 71228),
+    (71230, 71230),
+    (71233, 71236),
+    (71248, 71257),
+    (71296, 71338),
+    (71340, 71340),
+    (71342, 71343),
+    (71350, 71350),
+    (71360, 71369),
+    (71424, 71450),
+    (71456, 71457),
+    (71462, 71462),
+    (71472, 71487),
+    (71680, 71726),
+    (71736, 71736),
+    (71739, 71739),
+    (71840, 71922),
+    (71935, 71935),
+    (72192, 72192),
+    (72199, 72200),
+    (72203, 72242),
+    (72249, 72250),
+    (72255, 72262),
+    (72272, 72272),
+    (72279, 72280),
+    (72284, 72323),
+    (72326, 72329),
+    (72343, 72343),
+    (72346, 72354),
+    (72384, 72440),
+    (72704, 72712),
+    (72714, 72751),
+    (72766, 72773),
+    (72784, 72812),
+    (72816, 72847),
+    (72873, 72873),
+    (72881, 72881),
+    (72884, 72884),
+    (72960, 72966),
+    (72968, 72969),
+    (72971, 73008),
+    (73030, 73030),
+    (73040, 73049),
+    (73056, 73061),
+    (73063, 73064),
+    (73066, 73102),
+    (73107, 73108),
+    (73110, 73110),
+    (73112, 73112),
+    (73120, 73129),
+    (73440, 73458),
+    (73461, 73464),
+    (73728, 74649),
+    (74752, 74862),
+    (74864, 74868),
+    (74880, 75075),
+    (77824, 78894),
+    (82944, 83526),
+    (92160, 92728),
+    (92736, 92766),
+    (92768, 92777),
+    (92782, 92783),
+    (92880, 92909),
+    (92917, 92917),
+    (92928, 92975),
+    (92983, 92997),
+    (93008, 93017),
+    (93019, 93025),
+    (93027, 93047),
+    (93053, 93071),
+    (93760, 93850),
+    (93952, 94020),
+    (94032, 94078),
+    (94099, 94111),
+    (94176, 94177),
+    (94208, 100337),
+    (100352, 101106),
+    (110592, 110878),
+    (110960, 111355),
+    (113664, 113770),
+    (113776, 113788),
+    (113792, 113800),
+    (113808, 113817),
+    (113820, 113820),
+    (113823, 113823),
+    (118784, 119029),
+    (119040, 119078),
+    (119081, 119142),
+    (119146, 119154),
+    (119171, 119172),
+    (119180, 119209),
+    (119214, 119272),
+    (119520, 119539),
+    (119648, 119672),
+    (119808, 119892),
+    (119894, 119964),
+    (119966, 119967),
+    (119970, 119970),
+    (119973, 119974),
+    (119977, 119980),
+    (119982, 119993),
+    (119995, 119995),
+    (119997, 120003),
+    (120005, 120069),
+    (120071, 120074),
+    (120077, 120084),
+    (120086, 120092),
+    (120094, 120121),
+    (120123, 120126),
+    (120128, 120132),
+    (120134, 120134),
+    (120138, 120144),
+    (120146, 120485),
+    (120488, 120538),
+    (120540, 120596),
+    (120598, 120654),
+    (120656, 120712),
+    (120714, 120770),
+    (120772, 120779),
+    (120832, 121343),
+    (121399, 121402),
+    (121453, 121460),
+    (121462, 121475),
+    (121477, 121483),
+    (127248, 127278),
+    (127280, 127337),
+    (127344, 127404),
+    (127462, 127490),
+    (127504, 127547),
+    (127552, 127560),
+    (127568, 127569),
+    (131072, 173782),
+    (173824, 177972),
+    (177984, 178205),
+    (178208, 183969),
+    (183984, 191456),
+    (194560, 195101),
+    (983040, 1048573),
+    (1048576, 1114109),
+];
+
+pub const LEFT_TO_RIGHT_EMBEDDING: &'static [(u32, u32)] = &[(8234, 8234)];
+
+pub const LEFT_TO_RIGHT_ISOLATE: &'static [(u32, u32)] = &[(8294, 8294)];
+
+pub const LEFT_TO_RIGHT_OVERRIDE: &'static [(u32, u32)] = &[(8237, 8237)];
+
+pub const NONSPACING_MARK: &'static [(u32, u32)] = &[
+    (768, 879),
+    (1155, 1161),
+    (1425, 1469),
+    (1471, 1471),
+    (1473, 1474),
+    (1476, 1477),
+    (1479, 1479),
+    (1552, 1562),
+    (1611, 1631),
+    (1648, 1648),
+    (1750, 1756),
+    (1759, 1764),
+    (1767, 1768),
+    (1770, 1773),
+    (1809, 1809),
+    (1840, 1866),
+    (1958, 1968),
+    (2027, 2035),
+    (2045, 2045),
+    (2070, 2073),
+    (2075, 2083),
+    (2085, 2087),
+    (2089, 2093),
+    (2137, 2139),
+    (2259, 2273),
+    (2275, 2306),
+    (2362, 2362),
+    (2364, 2364),
+    (2369, 2376),
+    (2381, 2381),
+    (2385, 2391),
+    (2402, 2403),
+    (2433, 2433),
+    (2492, 2492),
+    (2497, 2500),
+    (2509, 2509),
+    (2530, 2531),
+    (2558, 2558),
+    (2561, 2562),
+    (2620, 2620),
+    (2625, 2626),
+    (2631, 2632),
+    (2635, 2637),
+    (2641, 2641),
+    (2672, 2673),
+    (2677, 2677),
+    (2689, 2690),
+    (2748, 2748),
+    (2753, 2757),
+    (2759, 2760),
+    (2765, 2765),
+    (2786, 2787),
+    (2810, 2815),
+    (2817, 2817),
+    (2876, 2876),
+    (2879, 2879),
+    (2881, 2884),
+    (2893, 2893),
+    (2902, 2902),
+    (2914, 2915),
+    (2946, 2946),
+    (3008, 3008),
+    (3021, 3021),
+    (3072, 3072),
+    (3076, 3076),
+    (3134, 3136),
+    (3142, 3144),
+    (3146, 3149),
+    (3157, 3158),
+    (3170, 3171),
+    (3201, 3201),
+    (3260, 3260),
+    (3276, 3277),
+    (3298, 3299),
+    (3328, 3329),
+    (3387, 3388),
+    (3393, 3396),
+    (3405, 3405),
+    (3426, 3427),
+    (3530, 3530),
+    (3538, 3540),
+    (3542, 3542),
+    (3633, 3633),
+    (3636, 3642),
+    (3655, 3662),
+    (3761, 3761),
+    (3764, 3769),
+    (3771, 3772),
+    (3784, 3789),
+    (3864, 3865),
+    (3893, 3893),
+    (3895, 3895),
+    (3897, 3897),
+    (3953, 3966),
+    (3968, 3972),
+    (3974, 3975),
+    (3981, 3991),
+    (3993, 4028),
+    (4038, 4038),
+    (4141, 4144),
+    (4146, 4151),
+    (4153, 4154),
+    (4157, 4158),
+    (4184, 4185),
+    (4190, 4192),
+    (4209, 4212),
+    (4226, 4226),
+    (4229, 4230),
+    (4237, 4237),
+    (4253, 4253),
+    (4957, 4959),
+    (5906, 5908),
+    (5938, 5940),
+    (5970, 5971),
+    (6002, 6003),
+    (6068, 6069),
+    (6071, 6077),
+    (6086, 6086),
+    (6089, 6099),
+    (6109, 6109),
+    (6155, 6157),
+    (6277, 6278),
+    (6313, 6313),
+    (6432, 6434),
+    (6439, 6440),
+    (6450, 6450),
+    (6457, 6459),
+    (6679, 6680),
+    (6683, 6683),
+    (6742, 6742),
+    (6744, 6750),
+    (6752, 6752),
+    (6754, 6754),
+    (6757, 6764),
+    (6771, 6780),
+    (6783, 6783),
+    (6832, 6846),
+    (6912, 6915),
+    (6964, 6964),
+    (6966, 6970),
+    (6972, 6972),
+    (6978, 6978),
+    (7019, 7027),
+    (7040, 7041),
+    (7074, 7077),
+    (7080, 7081),
+    (7083, 7085),
+    (7142, 7142),
+    (7144, 7145),
+    (7149, 7149),
+    (7151, 7153),
+    (7212, 7219),
+    (7222, 7223),
+    (7376, 7378),
+    (7380, 7392),
+    (7394, 7400),
+    (7405, 7405),
+    (7412, 7412),
+    (7416, 7417),
+    (7616, 7673),
+    (7675, 7679),
+    (8400, 8432),
+    (11503, 11505),
+    (11647, 11647),
+    (11744, 11775),
+    (12330, 12333),
+    (12441, 12442),
+    (42607, 42610),
+    (42612, 42621),
+    (42654, 42655),
+    (42736, 42737),
+    (43010, 43010),
+    (43014, 43014),
+    (43019, 43019),
+    (43045, 43046),
+    (43204, 43205),
+    (43232, 43249),
+    (43263, 43263),
+    (43302, 43309),
+    (43335, 43345),
+    (43392, 43394),
+    (43443, 43443),
+    (43446, 43449),
+    (43452, 43452),
+    (43493, 43493),
+    (43561, 43566),
+    (43569, 43570),
+    (43573, 43574),
+    (43587, 43587),
+    (43596, 43596),
+    (43644, 43644),
+    (43696, 43696),
+    (43698, 43700),
+    (43703, 43704),
+    (43710, 43711),
+    (43713, 43713),
+    (43756, 43757),
+    (43766, 43766),
+    (44005, 44005),
+    (44008, 44008),
+    (44013, 44013),
+    (64286, 64286),
+    (65024, 65039),
+    (65056, 65071),
+    (66045, 66045),
+    (66272, 66272),
+    (66422, 66426),
+    (68097, 68099),
+    (68101, 68102),
+    (68108, 68111),
+    (68152, 68154),
+    (68159, 68159),
+    (68325, 68326),
+    (68900, 68903),
+    (69446, 69456),
+    (69633, 69633),
+    (69688, 69702),
+    (69759, 69761),
+    (69811, 69814),
+    (69817, 69818),
+    (69888, 69890),
+    (69927, 69931),
+    (69933, 69940),
+    (70003, 70003),
+    (70016, 70017),
+    (70070, 70078),
+    (70089, 70092),
+    (70191, 70193),
+    (70196, 70196),
+    (70198, 70199),
+    (70206, 70206),
+    (70367, 70367),
+    (70371, 70378),
+    (70400, 70401),
+    (70459, 70460),
+    (70464, 70464),
+    (70502, 70508),
+    (70512, 70516),
+    (70712, 70719),
+    (70722, 70724),
+    (70726, 70726),
+    (70750, 70750),
+    (70835, 70840),
+    (70842, 70842),
+    (70847, 70848),
+    (70850, 70851),
+    (71090, 71093),
+    (71100, 71101),
+    (71103, 71104),
+    (71132, 71133),
+    (71219, 71226),
+    (71229, 71229),
+    (71231, 71232),
+    (71339, 71339),
+    (71341, 71341),
+    (71344, 71349),
+    (71351, 71351),
+    (71453, 71455),
+    (71458, 71461),
+    (71463, 71467),
+    (71727, 71735),
+    (71737, 71738),
+    (72193, 72198),
+    (72201, 72202),
+    (72243, 72248),
+    (72251, 72254),
+    (72263, 72263),
+    (72273, 72278),
+    (72281, 72283),
+    (72330, 72342),
+    (72344, 72345),
+    (72752, 72758),
+    (72760, 72765),
+    (72850, 72871),
+    (72874, 72880),
+    (72882, 72883),
+    (72885, 72886),
+    (73009, 73014),
+    (73018, 73018),
+    (73020, 73021),
+    (73023, 73029),
+    (73031, 73031),
+    (73104, 73105),
+    (73109, 73109),
+    (73111, 73111),
+    (73459, 73460),
+    (92912, 92916),
+    (92976, 92982),
+    (94095, 94098),
+    (113821, 113822),
+    (119143, 119145),
+    (119163, 119170),
+    (119173, 119179),
+    (119210, 119213),
+    (119362, 119364),
+    (121344, 121398),
+    (121403, 121452),
+    (121461, 121461),
+    (121476, 121476),
+    (121499, 121503),
+    (121505, 121519),
+    (122880, 122886),
+    (122888, 122904),
+    (122907, 122913),
+    (122915, 122916),
+    (122918, 122922),
+    (125136, 125142),
+    (125252, 125258),
+    (917760, 917999),
+];
+
+pub const OTHER_NEUTRAL: &'static [(u32, u32)] = &[
+    (33, 34),
+    (38, 42),
+    (59, 64),
+    (91, 96),
+    (123, 126),
+    (161, 161),
+    (166, 169),
+    (171, 172),
+    (174, 175),
+    (180, 180),
+    (182, 184),
+    (187, 191),
+    (215, 215),
+    (247, 247),
+    (697, 698),
+    (706, 719),
+    (722, 735),
+    (741, 749),
+    (751, 767),
+    (884, 885),
+    (894, 894),
+    (900, 901),
+    (903, 903),
+    (1014, 1014),
+    (1418, 1418),
+    (1421, 1422),
+    (1542, 1543),
+    (1550, 1551),
+    (1758, 1758),
+    (1769, 1769),
+    (2038, 2041),
+    (3059, 3064),
+    (3066, 3066),
+    (3192, 3198),
+    (3898, 3901),
+    (5008, 5017),
+    (5120, 5120),
+    (5787, 5788),
+    (6128, 6137),
+    (6144, 6154),
+    (6464, 6464),
+    (6468, 6469),
+    (6622, 6655),
+    (8125, 8125),
+    (8127, 8129),
+    (8141, 8143),
+    (8157, 8159),
+    (8173, 8175),
+    (8189, 8190),
+    (8208, 8231),
+    (8245, 8259),
+    (8261, 8286),
+    (8316, 8318),
+    (8332, 8334),
+    (8448, 8449),
+    (8451, 8454),
+    (8456, 8457),
+    (8468, 8468),
+    (8470, 8472),
+    (8478, 8483),
+    (8485, 8485),
+    (8487, 8487),
+    (8489, 8489),
+    (8506, 8507),
+    (8512, 8516),
+    (8522, 8525),
+    (8528, 8543),
+    (8585, 8587),
+    (8592, 8721),
+    (8724, 9013),
+    (9083, 9108),
+    (9110, 9254),
+    (9280, 9290),
+    (9312, 9351),
+    (9450, 9899),
+    (9901, 10239),
+    (10496, 11123),
+    (11126, 11157),
+    (11160, 11208),
+    (11210, 11262),
+    (11493, 11498),
+    (11513, 11519),
+    (11776, 11854),
+    (11904, 11929),
+    (11931, 12019),
+    (12032, 12245),
+    (12272, 12283),
+    (12289, 12292),
+    (12296, 12320),
+    (12336, 12336),
+    (12342, 12343),
+    (12349, 12351),
+    (12443, 12444),
+    (12448, 12448),
+    (12539, 12539),
+    (12736, 12771),
+    (12829, 12830),
+    (12880, 12895),
+    (12924, 12926),
+    (12977, 12991),
+    (13004, 13007),
+    (13175, 13178),
+    (13278, 13279),
+    (13311, 13311),
+    (19904, 19967),
+    (42128, 42182),
+    (42509, 42511),
+    (42611, 42611),
+    (42622, 42623),
+    (42752, 42785),
+    (42888, 42888),
+    (43048, 43051),
+    (43124, 43127),
+    (64830, 64831),
+    (65021, 65021),
+    (65040, 65049),
+    (65072, 65103),
+    (65105, 65105),
+    (65108, 65108),
+    (65110, 65118),
+    (65120, 65121),
+    (65124, 65126),
+    (65128, 65128),
+    (65131, 65131),
+    (65281, 65282),
+    (65286, 65290),
+    (65307, 65312),
+    (65339, 65344),
+    (65371, 65381),
+    (65506, 65508),
+    (65512, 65518),
+    (65529, 65533),
+    (65793, 65793),
+    (65856, 65932),
+    (65936, 65947),
+    (65952, 65952),
+    (67871, 67871),
+    (68409, 68415),
+    (69714, 69733),
+    (71264, 71276),
+    (119296, 119361),
+    (119365, 119365),
+    (119552, 119638),
+    (120539, 120539),
+    (120597, 120597),
+    (120655, 120655),
+    (120713, 120713),
+    (120771, 120771),
+    (126704, 126705),
+    (126976, 127019),
+    (127024, 127123),
+    (127136, 127150),
+    (127153, 127167),
+    (127169, 127183),
+    (127185, 127221),
+    (127243, 127244),
+    (127279, 127279),
+    (127338, 127339),
+    (127584, 127589),
+    (127744, 128724),
+    (128736, 128748),
+    (128752, 128761),
+    (128768, 128883),
+    (128896, 128984),
+    (129024, 129035),
+    (129040, 129095),
+    (129104, 129113),
+    (129120, 129159),
+    (129168, 129197),
+    (129280, 129291),
+    (129296, 129342),
+    (129344, 129392),
+    (129395, 129398),
+    (129402, 129402),
+    (129404, 129442),
+    (129456, 129465),
+    (129472, 129474),
+    (129488, 129535),
+    (129632, 129645),
+];
+
+pub const POP_DIRECTIONAL_FORMAT: &'static [(u32, u32)] = &[(8236, 8236)];
+
+pub const POP_DIRECTIONAL_ISOLATE: &'static [(u32, u32)] = &[(8297, 8297)];
+
+pub const RIGHT_TO_LEFT: &'static [(u32, u32)] = &[
+    (1470, 1470),
+    (1472, 1472),
+    (1475, 1475),
+    (1478, 1478),
+    (1488, 1514),
+    (1519, 1524),
+    (1984, 2026),
+    (2036, 2037),
+    (2042, 2042),
+    (2046, 2069),
+    (2074, 2074),
+    (2084, 2084),
+    (2088, 2088),
+    (2096, 2110),
+    (2112, 2136),
+    (2142, 2142),
+    (8207, 8207),
+    (64285, 64285),
+    (64287, 64296),
+    (64298, 64310),
+    (64312, 64316),
+    (64318, 64318),
+    (64320, 64321),
+    (64323, 64324),
+    (64326, 64335),
+    (67584, 67589),
+    (67592, 67592),
+    (67594, 67637),
+    (67639, 67640),
+    (67644, 67644),
+    (67647, 67669),
+    (67671, 67742),
+    (67751, 67759),
+    (67808, 67826),
+    (67828, 67829),
+    (67835, 67867),
+    (67872, 67897),
+    (67903, 67903),
+    (67968, 68023),
+    (68028, 68047),
+    (68050, 68096),
+    (68112, 68115),
+    (68117, 68119),
+    (68121, 68149),
+    (68160, 68168),
+    (68176, 68184),
+    (68192, 68255),
+    (68288, 68324),
+    (68331, 68342),
+    (68352, 68405),
+    (68416, 68437),
+    (68440, 68466),
+    (68472, 68497),
+    (68505, 68508),
+    (68521, 68527),
+    (68608, 68680),
+    (68736, 68786),
+    (68800, 68850),
+    (68858, 68863),
+    (69376, 69415),
+    (124928, 125124),
+    (125127, 125135),
+    (125184, 125251),
+    (125264, 125273),
+    (125278, 125279),
+];
+
+pub const RIGHT_TO_LEFT_EMBEDDING: &'static [(u32, u32)] = &[(8235, 8235)];
+
+pub const RIGHT_TO_LEFT_ISOLATE: &'static [(u32, u32)] = &[(8295, 8295)];
+
+pub const RIGHT_TO_LEFT_OVERRIDE: &'static [(u32, u32)] = &[(8238, 8238)];
+
+pub const SEGMENT_SEPARATOR: &'static [(u32, u32)] = &[(9, 9), (11, 11), (31, 31)];
+
+pub const WHITE_SPACE: &'static [(u32, u32)] = &[
+    (12, 12),
+    (32, 32),
+    (5760, 5760),
+    (8192, 8202),
+    (8232, 8232),
+    (8287, 8287),
+    (12288, 12288),
+];
@@ -0,0 +1,6 @@
+pub mod v10_0_0;
+pub mod v11_0_0;
+pub mod v12_0_0;
+pub mod v12_1_0;
+pub mod v13_0_0;
+pub mod v9_0_0;
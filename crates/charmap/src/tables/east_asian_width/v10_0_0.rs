@@ -0,0 +1,1132 @@
+// DO NOT EDIT THIS FILE. IT WAS AUTOMATICALLY GENERATED BY:
+//
+//   python generate_tables.py 10.0.0
+//
+// Unicode version: 10.0.0.
+
+pub const BY_NAME: &'static [(&'static str, &'static [(u32, u32)])] = &[
+    ("A", AMBIGUOUS),
+    ("F", FULLWIDTH),
+    ("H", HALFWIDTH),
+    ("N", NEUTRAL),
+    ("Na", NARROW),
+    ("W", WIDE),
+];
+
+pub const AMBIGUOUS: &'static [(u32, u32)] = &[
+    (161, 161),
+    (164, 164),
+    (167, 168),
+    (170, 170),
+    (173, 174),
+    (176, 180),
+    (182, 186),
+    (188, 191),
+    (198, 198),
+    (208, 208),
+    (215, 216),
+    (222, 225),
+    (230, 230),
+    (232, 234),
+    (236, 237),
+    (240, 240),
+    (242, 243),
+    (247, 250),
+    (252, 252),
+    (254, 254),
+    (257, 257),
+    (273, 273),
+    (275, 275),
+    (283, 283),
+    (294, 295),
+    (299, 299),
+    (305, 307),
+    (312, 312),
+    (319, 322),
+    (324, 324),
+    (328, 331),
+    (333, 333),
+    (338, 339),
+    (358, 359),
+    (363, 363),
+    (462, 462),
+    (464, 464),
+    (466, 466),
+    (468, 468),
+    (470, 470),
+    (472, 472),
+    (474, 474),
+    (476, 476),
+    (593, 593),
+    (609, 609),
+    (708, 708),
+    (711, 711),
+    (713, 715),
+    (717, 717),
+    (720, 720),
+    (728, 731),
+    (733, 733),
+    (735, 735),
+    (768, 879),
+    (913, 929),
+    (931, 937),
+    (945, 961),
+    (963, 969),
+    (1025, 1025),
+    (1040, 1103),
+    (1105, 1105),
+    (8208, 8208),
+    (8211, 8214),
+    (8216, 8217),
+    (8220, 8221),
+    (8224, 8226),
+    (8228, 8231),
+    (8240, 8240),
+    (8242, 8243),
+    (8245, 8245),
+    (8251, 8251),
+    (8254, 8254),
+    (8308, 8308),
+    (8319, 8319),
+    (8321, 8324),
+    (8364, 8364),
+    (8451, 8451),
+    (8453, 8453),
+    (8457, 8457),
+    (8467, 8467),
+    (8470, 8470),
+    (8481, 8482),
+    (8486, 8486),
+    (8491, 8491),
+    (8531, 8532),
+    (8539, 8542),
+    (8544, 8555),
+    (8560, 8569),
+    (8585, 8585),
+    (8592, 8601),
+    (8632, 8633),
+    (8658, 8658),
+    (8660, 8660),
+    (8679, 8679),
+    (8704, 8704),
+    (8706, 8707),
+    (8711, 8712),
+    (8715, 8715),
+    (8719, 8719),
+    (8721, 8721),
+    (8725, 8725),
+    (8730, 8730),
+    (8733, 8736),
+    (8739, 8739),
+    (8741, 8741),
+    (8743, 8748),
+    (8750, 8750),
+    (8756, 8759),
+    (8764, 8765),
+    (8776, 8776),
+    (8780, 8780),
+    (8786, 8786),
+    (8800, 8801),
+    (8804, 8807),
+    (8810, 8811),
+    (8814, 8815),
+    (8834, 8835),
+    (8838, 8839),
+    (8853, 8853),
+    (8857, 8857),
+    (8869, 8869),
+    (8895, 8895),
+    (8978, 8978),
+    (9312, 9449),
+    (9451, 9547),
+    (9552, 9587),
+    (9600, 9615),
+    (9618, 9621),
+    (9632, 9633),
+    (9635, 9641),
+    (9650, 9651),
+    (9654, 9655),
+    (9660, 9661),
+    (9664, 9665),
+    (9670, 9672),
+    (9675, 9675),
+    (9678, 9681),
+    (9698, 9701),
+    (9711, 9711),
+    (9733, 9734),
+    (9737, 9737),
+    (9742, 9743),
+    (9756, 9756),
+    (9758, 9758),
+    (9792, 9792),
+    (9794, 9794),
+    (9824, 9825),
+    (9827, 9829),
+    (9831, 9834),
+    (9836, 9837),
+    (9839, 9839),
+    (9886, 9887),
+    (9919, 9919),
+    (9926, 9933),
+    (9935, 9939),
+    (9941, 9953),
+    (9955, 9955),
+    (9960, 9961),
+    (9963, 9969),
+    (9972, 9972),
+    (9974, 9977),
+    (9979, 9980),
+    (9982, 9983),
+    (10045, 10045),
+    (10102, 10111),
+    (11094, 11097),
+    (12872, 12879),
+    (57344, 63743),
+    (65024, 65039),
+    (65533, 65533),
+    (127232, 127242),
+    (127248, 127277),
+    (127280, 127337),
+    (127344, 127373),
+    (127375, 127376),
+    (127387, 127404),
+    (917760, 917999),
+    (983040, 1048573),
+    (1048576, 1114109),
+];
+
+pub const FULLWIDTH: &'static [(u32, u32)] = &[(12288, 12288), (65281, 65376), (65504, 65510)];
+
+pub const HALFWIDTH: &'static [(u32, u32)] = &[
+    (8361, 8361),
+    (65377, 65470),
+    (65474, 65479),
+    (65482, 65487),
+    (65490, 65495),
+    (65498, 65500),
+    (65512, 65518),
+];
+
+pub const NEUTRAL: &'static [(u32, u32)] = &[
+    (0, 31),
+    (127, 160),
+    (169, 169),
+    (171, 171),
+    (181, 181),
+    (187, 187),
+    (192, 197),
+    (199, 207),
+    (209, 214),
+    (217, 221),
+    (226, 229),
+    (231, 231),
+    (235, 235),
+    (238, 239),
+    (241, 241),
+    (244, 246),
+    (251, 251),
+    (253, 253),
+    (255, 256),
+    (258, 272),
+    (274, 274),
+    (276, 282),
+    (284, 293),
+    (296, 298),
+    (300, 304),
+    (308, 311),
+    (313, 318),
+    (323, 323),
+    (325, 327),
+    (332, 332),
+    (334, 337),
+    (340, 357),
+    (360, 362),
+    (364, 461),
+    (463, 463),
+    (465, 465),
+    (467, 467),
+    (469, 469),
+    (471, 471),
+    (473, 473),
+    (475, 475),
+    (477, 592),
+    (594, 608),
+    (610, 707),
+    (709, 710),
+    (712, 712),
+    (716, 716),
+    (718, 719),
+    (721, 727),
+    (732, 732),
+    (734, 734),
+    (736, 767),
+    (880, 887),
+    (890, 895),
+    (900, 906),
+    (908, 908),
+    (910, 912),
+    (938, 944),
+    (962, 962),
+    (970, 1024),
+    (1026, 1039),
+    (1104, 1104),
+    (1106, 1327),
+    (1329, 1366),
+    (1369, 1375),
+    (1377, 1415),
+    (1417, 1418),
+    (1421, 1423),
+    (1425, 1479),
+    (1488, 1514),
+    (1520, 1524),
+    (1536, 1564),
+    (1566, 1805),
+    (1807, 1866),
+    (1869, 1969),
+    (1984, 2042),
+    (2048, 2093),
+    (2096, 2110),
+    (2112, 2139),
+    (2142, 2142),
+    (2144, 2154),
+    (2208, 2228),
+    (2230, 2237),
+    (2260, 2435),
+    (2437, 2444),
+    (2447, 2448),
+    (2451, 2472),
+    (2474, 2480),
+    (2482, 2482),
+    (2486, 2489),
+    (2492, 2500),
+    (2503, 2504),
+    (2507, 2510),
+    (2519, 2519),
+    (2524, 2525),
+    (2527, 2531),
+    (2534, 2557),
+    (2561, 2563),
+    (2565, 2570),
+    (2575, 2576),
+    (2579, 2600),
+    (2602, 2608),
+    (2610, 2611),
+    (2613, 2614),
+    (2616, 2617),
+    (2620, 2620),
+    (2622, 2626),
+    (2631, 2632),
+    (2635, 2637),
+    (2641, 2641),
+    (2649, 2652),
+    (2654, 2654),
+    (2662, 2677),
+    (2689, 2691),
+    (2693, 2701),
+    (2703, 2705),
+    (2707, 2728),
+    (2730, 2736),
+    (2738, 2739),
+    (2741, 2745),
+    (2748, 2757),
+    (2759, 2761),
+    (2763, 2765),
+    (2768, 2768),
+    (2784, 2787),
+    (2790, 2801),
+    (2809, 2815),
+    (2817, 2819),
+    (2821, 2828),
+    (2831, 2832),
+    (2835, 2856),
+    (2858, 2864),
+    (2866, 2867),
+    (2869, 2873),
+    (2876, 2884),
+    (2887, 2888),
+    (2891, 2893),
+    (2902, 2903),
+    (2908, 2909),
+    (2911, 2915),
+    (2918, 2935),
+    (2946, 2947),
+    (2949, 2954),
+    (2958, 2960),
+    (2962, 2965),
+    (2969, 2970),
+    (2972, 2972),
+    (2974, 2975),
+    (2979, 2980),
+    (2984, 2986),
+    (2990, 3001),
+    (3006, 3010),
+    (3014, 3016),
+    (3018, 3021),
+    (3024, 3024),
+    (3031, 3031),
+    (3046, 3066),
+    (3072, 3075),
+    (3077, 3084),
+    (3086, 3088),
+    (3090, 3112),
+    (3114, 3129),
+    (3133, 3140),
+    (3142, 3144),
+    (3146, 3149),
+    (3157, 3158),
+    (3160, 3162),
+    (3168, 3171),
+    (3174, 3183),
+    (3192, 3203),
+    (3205, 3212),
+    (3214, 3216),
+    (3218, 3240),
+    (3242, 3251),
+    (3253, 3257),
+    (3260, 3268),
+    (3270, 3272),
+    (3274, 3277),
+    (3285, 3286),
+    (3294, 3294),
+    (3296, 3299),
+    (3302, 3311),
+    (3313, 3314),
+    (3328, 3331),
+    (3333, 3340),
+    (3342, 3344),
+    (3346, 3396),
+    (3398, 3400),
+    (3402, 3407),
+    (3412, 3427),
+    (3430, 3455),
+    (3458, 3459),
+    (3461, 3478),
+    (3482, 3505),
+    (3507, 3515),
+    (3517, 3517),
+    (3520, 3526),
+    (3530, 3530),
+    (3535, 3540),
+    (3542, 3542),
+    (3544, 3551),
+    (3558, 3567),
+    (3570, 3572),
+    (3585, 3642),
+    (3647, 3675),
+    (3713, 3714),
+    (3716, 3716),
+    (3719, 3720),
+    (3722, 3722),
+    (3725, 3725),
+    (3732, 3735),
+    (3737, 3743),
+    (3745, 3747),
+    (3749, 3749),
+    (3751, 3751),
+    (3754, 3755),
+    (3757, 3769),
+    (3771, 3773),
+    (3776, 3780),
+    (3782, 3782),
+    (3784, 3789),
+    (3792, 3801),
+    (3804, 3807),
+    (3840, 3911),
+    (3913, 3948),
+    (3953, 3991),
+    (3993, 4028),
+    (4030, 4044),
+    (4046, 4058),
+    (4096, 4293),
+    (4295, 4295),
+    (4301, 4301),
+    (4304, 4351),
+    (4448, 4680),
+    (4682, 4685),
+    (4688, 4694),
+    (4696, 4696),
+    (4698, 4701),
+    (4704, 4744),
+    (4746, 4749),
+    (4752, 4784),
+    (4786, 4789),
+    (4792, 4798),
+    (4800, 4800),
+    (4802, 4805),
+    (4808, 4822),
+    (4824, 4880),
+    (4882, 4885),
+    (4888, 4954),
+    (4957, 4988),
+    (4992, 5017),
+    (5024, 5109),
+    (5112, 5117),
+    (5120, 5788),
+    (5792, 5880),
+    (5888, 5900),
+    (5902, 5908),
+    (5920, 5942),
+    (5952, 5971),
+    (5984, 5996),
+    (5998, 6000),
+    (6002, 6003),
+    (6016, 6109),
+    (6112, 6121),
+    (6128, 6137),
+    (6144, 6158),
+    (6160, 6169),
+    (6176, 6263),
+    (6272, 6314),
+    (6320, 6389),
+    (6400, 6430),
+    (6432, 6443),
+    (6448, 6459),
+    (6464, 6464),
+    (6468, 6509),
+    (6512, 6516),
+    (6528, 6571),
+    (6576, 6601),
+    (6608, 6618),
+    (6622, 6683),
+    (6686, 6750),
+    (6752, 6780),
+    (6783, 6793),
+    (6800, 6809),
+    (6816, 6829),
+    (6832, 6846),
+    (6912, 6987),
+    (6992, 7036),
+    (7040, 7155),
+    (7164, 7223),
+    (7227, 7241),
+    (7245, 7304),
+    (7360, 7367),
+    (7376, 7417),
+    (7424, 7673),
+    (7675, 7957),
+    (7960, 7965),
+    (7968, 8005),
+    (8008, 8013),
+    (8016, 8023),
+    (8025, 8025),
+    (8027, 8027),
+    (8029, 8029),
+    (8031, 8061),
+    (8064, 8116),
+    (8118, 8132),
+    (8134, 8147),
+    (8150, 8155),
+    (8157, 8175),
+    (8178, 8180),
+    (8182, 8190),
+    (8192, 8207),
+    (8209, 8210),
+    (8215, 8215),
+    (8218, 8219),
+    (8222, 8223),
+    (8227, 8227),
+    (8232, 8239),
+    (8241, 8241),
+    (8244, 8244),
+    (8246, 8250),
+    (8252, 8253),
+    (8255, 8292),
+    (8294, 8305),
+    (8309, 8318),
+    (8320, 8320),
+    (8325, 8334),
+    (8336, 8348),
+    (8352, 8360),
+    (8362, 8363),
+    (8365, 8383),
+    (8400, 8432),
+    (8448, 8450),
+    (8452, 8452),
+    (8454, 8456),
+    (8458, 8466),
+    (8468, 8469),
+    (8471, 8480),
+    (8483, 8485),
+    (8487, 8490),
+    (8492, 8530),
+    (8533, 8538),
+    (8543, 8543),
+    (8556, 8559),
+    (8570, 8584),
+    (8586, 8587),
+    (8602, 8631),
+    (8634, 8657),
+    (8659, 8659),
+    (8661, 8678),
+    (8680, 8703),
+    (8705, 8705),
+    (8708, 8710),
+    (8713, 8714),
+    (8716, 8718),
+    (8720, 8720),
+    (8722, 8724),
+    (8726, 8729),
+    (8731, 8732),
+    (8737, 8738),
+    (8740, 8740),
+    (8742, 8742),
+    (8749, 8749),
+    (8751, 8755),
+    (8760, 8763),
+    (8766, 8775),
+    (8777, 8779),
+    (8781, 8785),
+    (8787, 8799),
+    (8802, 8803),
+    (8808, 8809),
+    (8812, 8813),
+    (8816, 8833),
+    (8836, 8837),
+    (8840, 8852),
+    (8854, 8856),
+    (8858, 8868),
+    (8870, 8894),
+    (8896, 8977),
+    (8979, 8985),
+    (8988, 9000),
+    (9003, 9192),
+    (9197, 9199),
+    (9201, 9202),
+    (9204, 9254),
+    (9280, 9290),
+    (9450, 9450),
+    (9548, 9551),
+    (9588, 9599),
+    (9616, 9617),
+    (9622, 9631),
+    (9634, 9634),
+    (9642, 9649),
+    (9652, 9653),
+    (9656, 9659),
+    (9662, 9663),
+    (9666, 9669),
+    (9673, 9674),
+    (9676, 9677),
+    (9682, 9697),
+    (9702, 9710),
+    (9712, 9724),
+    (9727, 9732),
+    (9735, 9736),
+    (9738, 9741),
+    (9744, 9747),
+    (9750, 9755),
+    (9757, 9757),
+    (9759, 9791),
+    (9793, 9793),
+    (9795, 9799),
+    (9812, 9823),
+    (9826, 9826),
+    (9830, 9830),
+    (9835, 9835),
+    (9838, 9838),
+    (9840, 9854),
+    (9856, 9874),
+    (9876, 9885),
+    (9888, 9888),
+    (9890, 9897),
+    (9900, 9916),
+    (9920, 9923),
+    (9954, 9954),
+    (9956, 9959),
+    (9984, 9988),
+    (9990, 9993),
+    (9996, 10023),
+    (10025, 10044),
+    (10046, 10059),
+    (10061, 10061),
+    (10063, 10066),
+    (10070, 10070),
+    (10072, 10101),
+    (10112, 10132),
+    (10136, 10159),
+    (10161, 10174),
+    (10176, 10213),
+    (10222, 10628),
+    (10631, 11034),
+    (11037, 11087),
+    (11089, 11092),
+    (11098, 11123),
+    (11126, 11157),
+    (11160, 11193),
+    (11197, 11208),
+    (11210, 11218),
+    (11244, 11247),
+    (11264, 11310),
+    (11312, 11358),
+    (11360, 11507),
+    (11513, 11557),
+    (11559, 11559),
+    (11565, 11565),
+    (11568, 11623),
+    (11631, 11632),
+    (11647, 11670),
+    (11680, 11686),
+    (11688, 11694),
+    (11696, 11702),
+    (11704, 11710),
+    (11712, 11718),
+    (11720, 11726),
+    (11728, 11734),
+    (11736, 11742),
+    (11744, 11849),
+    (12351, 12351),
+    (19904, 19967),
+    (42192, 42539),
+    (42560, 42743),
+    (42752, 42926),
+    (42928, 42935),
+    (42999, 43051),
+    (43056, 43065),
+    (43072, 43127),
+    (43136, 43205),
+    (43214, 43225),
+    (43232, 43261),
+    (43264, 43347),
+    (43359, 43359),
+    (43392, 43469),
+    (43471, 43481),
+    (43486, 43518),
+    (43520, 43574),
+    (43584, 43597),
+    (43600, 43609),
+    (43612, 43714),
+    (43739, 43766),
+    (43777, 43782),
+    (43785, 43790),
+    (43793, 43798),
+    (43808, 43814),
+    (43816, 43822),
+    (43824, 43877),
+    (43888, 44013),
+    (44016, 44025),
+    (55216, 55238),
+    (55243, 55291),
+    (55296, 57343),
+    (64256, 64262),
+    (64275, 64279),
+    (64285, 64310),
+    (64312, 64316),
+    (64318, 64318),
+    (64320, 64321),
+    (64323, 64324),
+    (64326, 64449),
+    (64467, 64831),
+    (64848, 64911),
+    (64914, 64967),
+    (65008, 65021),
+    (65056, 65071),
+    (65136, 65140),
+    (65142, 65276),
+    (65279, 65279),
+    (65529, 65532),
+    (65536, 65547),
+    (65549, 65574),
+    (65576, 65594),
+    (65596, 65597),
+    (65599, 65613),
+    (65616, 65629),
+    (65664, 65786),
+    (65792, 65794),
+    (65799, 65843),
+    (65847, 65934),
+    (65936, 65947),
+    (65952, 65952),
+    (66000, 66045),
+    (66176, 66204),
+    (66208, 66256),
+    (66272, 66299),
+    (66304, 66339),
+    (66349, 66378),
+    (66384, 66426),
+    (66432, 66461),
+    (66463, 66499),
+    (66504, 66517),
+    (66560, 66717),
+    (66720, 66729),
+    (66736, 66771),
+    (66776, 66811),
+    (66816, 66855),
+    (66864, 66915),
+    (66927, 66927),
+    (67072, 67382),
+    (67392, 67413),
+    (67424, 67431),
+    (67584, 67589),
+    (67592, 67592),
+    (67594, 67637),
+    (67639, 67640),
+    (67644, 67644),
+    (67647, 67669),
+    (67671, 67742),
+    (67751, 67759),
+    (67808, 67826),
+    (67828, 67829),
+    (67835, 67867),
+    (67871, 67897),
+    (67903, 67903),
+    (67968, 68023),
+    (68028, 68047),
+    (68050, 68099),
+    (68101, 68102),
+    (68108, 68115),
+    (68117, 68119),
+    (68121, 68147),
+    (68152, 68154),
+    (68159, 68167),
+    (68176, 68184),
+    (68192, 68255),
+    (68288, 68326),
+    (68331, 68342),
+    (68352, 68405),
+    (68409, 68437),
+    (68440, 68466),
+    (68472, 68497),
+    (68505, 68508),
+    (68521, 68527),
+    (68608, 68680),
+    (68736, 68786),
+    (68800, 68850),
+    (68858, 68863),
+    (69216, 69246),
+    (69632, 69709),
+    (69714, 69743),
+    (69759, 69825),
+    (69840, 69864),
+    (69872, 69881),
+    (69888, 69940),
+    (69942, 69955),
+    (69968, 70006),
+    (70016, 70093),
+    (70096, 70111),
+    (70113, 70132),
+    (70144, 70161),
+    (70163, 70206),
+    (70272, 70278),
+    (70280, 70280),
+    (70282, 70285),
+    (70287, 70301),
+    (70303, 70313),
+    (70320, 70378),
+    (70384, 70393),
+    (70400, 70403),
+    (70405, 70412),
+    (70415, 70416),
+    (70419, 70440),
+    (70442, 70448),
+    (70450, 70451),
+    (70453, 70457),
+    (70460, 70468),
+    (70471, 70472),
+    (70475, 70477),
+    (70480, 70480),
+    (70487, 70487),
+    (70493, 70499),
+    (70502, 70508),
+    (70512, 70516),
+    (70656, 70745),
+    (70747, 70747),
+    (70749, 70749),
+    (70784, 70855),
+    (70864, 70873),
+    (71040, 71093),
+    (71096, 71133),
+    (71168, 71236),
+    (71248, 71257),
+    (71264, 71276),
+    (71296, 71351),
+    (71360, 71369),
+    (71424, 71449),
+    (71453, 71467),
+    (71472, 71487),
+    (71840, 71922),
+    (71935, 71935),
+    (72192, 72263),
+    (72272, 72323),
+    (72326, 72348),
+    (72350, 72354),
+    (72384, 72440),
+    (72704, 72712),
+    (72714, 72758),
+    (72760, 72773),
+    (72784, 72812),
+    (72816, 72847),
+    (72850, 72871),
+    (72873, 72886),
+    (72960, 72966),
+    (72968, 72969),
+    (72971, 73014),
+    (73018, 73018),
+    (73020, 73021),
+    (73023, 73031),
+    (73040, 73049),
+    (73728, 74649),
+    (74752, 74862),
+    (74864, 74868),
+    (74880, 75075),
+    (77824, 78894),
+    (82944, 83526),
+    (92160, 92728),
+    (92736, 92766),
+    (92768, 92777),
+    (92782, 92783),
+    (92880, 92909),
+    (92912, 92917),
+    (92928, 92997),
+    (93008, 93017),
+    (93019, 93025),
+    (93027, 93047),
+    (93053, 93071),
+    (93952, 94020),
+    (94032, 94078),
+    (94095, 94111),
+    (113664, 113770),
+    (113776, 113788),
+    (113792, 113800),
+    (113808, 113817),
+    (113820, 113827),
+    (118784, 119029),
+    (119040, 119078),
+    (119081, 119272),
+    (119296, 119365),
+    (119552, 119638),
+    (119648, 119665),
+    (119808, 119892),
+    (119894, 119964),
+    (119966, 119967),
+    (119970, 119970),
+    (119973, 119974),
+    (119977, 119980),
+    (119982, 119993),
+    (119995, 119995),
+    (119997, 120003),
+    (120005, 120069),
+    (120071, 120074),
+    (120077, 120084),
+    (120086, 120092),
+    (120094, 120121),
+    (120123, 120126),
+    (120128, 120132),
+    (120134, 120134),
+    (120138, 120144),
+    (120146, 120485),
+    (120488, 120779),
+    (120782, 121483),
+    (121499, 121503),
+    (121505, 121519),
+    (122880, 122886),
+    (122888, 122904),
+    (122907, 122913),
+    (122915, 122916),
+    (122918, 122922),
+    (124928, 125124),
+    (125127, 125142),
+    (125184, 125258),
+    (125264, 125273),
+    (125278, 125279),
+    (126464, 126467),
+    (126469, 126495),
+    (126497, 126498),
+    (126500, 126500),
+    (126503, 126503),
+    (126505, 126514),
+    (126516, 126519),
+    (126521, 126521),
+    (126523, 126523),
+    (126530, 126530),
+    (126535, 126535),
+    (126537, 126537),
+    (126539, 126539),
+    (126541, 126543),
+    (126545, 126546),
+    (126548, 126548),
+    (126551, 126551),
+    (126553, 126553),
+    (126555, 126555),
+    (126557, 126557),
+    (126559, 126559),
+    (126561, 126562),
+    (126564, 126564),
+    (126567, 126570),
+    (126572, 126578),
+    (126580, 126583),
+    (126585, 126588),
+    (126590, 126590),
+    (126592, 126601),
+    (126603, 126619),
+    (126625, 126627),
+    (126629, 126633),
+    (126635, 126651),
+    (126704, 126705),
+    (126976, 126979),
+    (126981, 127019),
+    (127024, 127123),
+    (127136, 127150),
+    (127153, 127167),
+    (127169, 127182),
+    (127185, 127221),
+    (127243, 127244),
+    (127278, 127278),
+    (127338, 127339),
+    (127462, 127487),
+    (127777, 127788),
+    (127798, 127798),
+    (127869, 127869),
+    (127892, 127903),
+    (127947, 127950),
+    (127956, 127967),
+    (127985, 127987),
+    (127989, 127991),
+    (128063, 128063),
+    (128065, 128065),
+    (128253, 128254),
+    (128318, 128330),
+    (128335, 128335),
+    (128360, 128377),
+    (128379, 128404),
+    (128407, 128419),
+    (128421, 128506),
+    (128592, 128639),
+    (128710, 128715),
+    (128717, 128719),
+    (128723, 128724),
+    (128736, 128746),
+    (128752, 128755),
+    (128768, 128883),
+    (128896, 128980),
+    (129024, 129035),
+    (129040, 129095),
+    (129104, 129113),
+    (129120, 129159),
+    (129168, 129197),
+    (129280, 129291),
+    (917505, 917505),
+    (917536, 917631),
+];
+
+pub const NARROW: &'static [(u32, u32)] = &[
+    (32, 126),
+    (162, 163),
+    (165, 166),
+    (172, 172),
+    (175, 175),
+    (10214, 10221),
+    (10629, 10630),
+];
+
+pub const WIDE: &'static [(u32, u32)] = &[
+    (4352, 4447),
+    (8986, 8987),
+    (9001, 9002),
+    (9193, 9196),
+    (9200, 9200),
+    (9203, 9203),
+    (9725, 9726),
+    (9748, 9749),
+    (9800, 9811),
+    (9855, 9855),
+    (9875, 9875),
+    (9889, 9889),
+    (9898, 9899),
+    (9917, 9918),
+    (9924, 9925),
+    (9934, 9934),
+    (9940, 9940),
+    (9962, 9962),
+    (9970, 9971),
+    (9973, 9973),
+    (9978, 9978),
+    (9981, 9981),
+    (9989, 9989),
+    (9994, 9995),
+    (10024, 10024),
+    (10060, 10060),
+    (10062, 10062),
+    (10067, 10069),
+    (10071, 10071),
+    (10133, 10135),
+    (10160, 10160),
+    (10175, 10175),
+    (11035, 11036),
+    (11088, 11088),
+    (11093, 11093),
+    (11904, 11929),
+    (11931, 12019),
+    (12032, 12245),
+    (12272, 12283),
+    (12289, 12350),
+    (12353, 12438),
+    (12441, 12543),
+    (12549, 12590),
+    (12593, 12686),
+    (12688, 12730),
+    (12736, 12771),
+    (12784, 12830),
+    (12832, 12871),
+    (12880, 13054),
+    (13056, 19893),
+    (19968, 40938),
+    (40960, 42124),
+    (42128, 42182),
+    (43360, 43388),
+    (44032, 55203),
+    (63744, 64109),
+    (64112, 64217),
+    (65040, 65049),
+    (65072, 65106),
+    (65108, 65126),
+    (65128, 65131),
+    (94176, 94177),
+    (94208, 100332),
+    (100352, 101106),
+    (110592, 110878),
+    (110960, 111355),
+    (126980, 126980),
+    (127183, 127183),
+    (127374, 127374),
+    (127377, 127386),
+    (127488, 127490),
+    (127504, 127547),
+    (127552, 127560),
+    (127568, 127569),
+    (127584, 127589),
+    (127744, 127776),
+    (127789, 127797),
+    (127799, 127868),
+    (127870, 127891),
+    (127904, 127946),
+    (127951, 127955),
+    (127968, 127984),
+    (127988, 127988),
+    (127992, 128062),
+    (128064, 128064),
+    (128066, 128252),
+    (128255, 128317),
+    (128331, 128334),
+    (128336, 128359),
+    (128378, 128378),
+    (128405, 128406),
+    (128420, 128420),
+    (128507, 128591),
+    (128640, 128709),
+    (128716, 128716),
+    (128720, 128722),
+    (128747, 128748),
+    (128756, 128760),
+    (129296, 129342),
+    (129344, 129356),
+    (129360, 129387),
+    (129408, 129431),
+    (129472, 129472),
+    (129488, 129510),
+    (131072, 173782),
+    (173824, 177972),
+    (177984, 178205),
+    (178208, 183969),
+    (183984, 191456),
+    (194560, 195101),
+];
@@ -0,0 +1,1816 @@
+// DO NOT EDIT THIS FILE. IT WAS AUTOMATICALLY GENERATED BY:
+//
+//   python generate_tables.py 11.0.0
+//
+// Unicode version: 11.0.0.
+// Emoji version: 11.0.
+
+pub const BY_NAME: &'static [(&'static str, &'static [&'static str])] = &[
+    ("Flag", FLAG),
+    ("Keycap", KEYCAP),
+    ("Modifier", MODIFIER),
+    ("Presentation", PRESENTATION),
+    ("Tag", TAG),
+    ("ZWJ", ZWJ),
+];
+
+pub const FLAG: &'static [&'static str] = &[
+    "\u{1f1e6}\u{1f1e8}",
+    "\u{1f1e6}\u{1f1e9}",
+    "\u{1f1e6}\u{1f1ea}",
+    "\u{1f1e6}\u{1f1eb}",
+    "\u{1f1e6}\u{1f1ec}",
+    "\u{1f1e6}\u{1f1ee}",
+    "\u{1f1e6}\u{1f1f1}",
+    "\u{1f1e6}\u{1f1f2}",
+    "\u{1f1e6}\u{1f1f4}",
+    "\u{1f1e6}\u{1f1f6}",
+    "\u{1f1e6}\u{1f1f7}",
+    "\u{1f1e6}\u{1f1f8}",
+    "\u{1f1e6}\u{1f1f9}",
+    "\u{1f1e6}\u{1f1fa}",
+    "\u{1f1e6}\u{1f1fc}",
+    "\u{1f1e6}\u{1f1fd}",
+    "\u{1f1e6}\u{1f1ff}",
+    "\u{1f1e7}\u{1f1e6}",
+    "\u{1f1e7}\u{1f1e7}",
+    "\u{1f1e7}\u{1f1e9}",
+    "\u{1f1e7}\u{1f1ea}",
+    "\u{1f1e7}\u{1f1eb}",
+    "\u{1f1e7}\u{1f1ec}",
+    "\u{1f1e7}\u{1f1ed}",
+    "\u{1f1e7}\u{1f1ee}",
+    "\u{1f1e7}\u{1f1ef}",
+    "\u{1f1e7}\u{1f1f1}",
+    "\u{1f1e7}\u{1f1f2}",
+    "\u{1f1e7}\u{1f1f3}",
+    "\u{1f1e7}\u{1f1f4}",
+    "\u{1f1e7}\u{1f1f6}",
+    "\u{1f1e7}\u{1f1f7}",
+    "\u{1f1e7}\u{1f1f8}",
+    "\u{1f1e7}\u{1f1f9}",
+    "\u{1f1e7}\u{1f1fb}",
+    "\u{1f1e7}\u{1f1fc}",
+    "\u{1f1e7}\u{1f1fe}",
+    "\u{1f1e7}\u{1f1ff}",
+    "\u{1f1e8}\u{1f1e6}",
+    "\u{1f1e8}\u{1f1e8}",
+    "\u{1f1e8}\u{1f1e9}",
+    "\u{1f1e8}\u{1f1eb}",
+    "\u{1f1e8}\u{1f1ec}",
+    "\u{1f1e8}\u{1f1ed}",
+    "\u{1f1e8}\u{1f1ee}",
+    "\u{1f1e8}\u{1f1f0}",
+    "\u{1f1e8}\u{1f1f1}",
+    "\u{1f1e8}\u{1f1f2}",
+    "\u{1f1e8}\u{1f1f3}",
+    "\u{1f1e8}\u{1f1f4}",
+    "\u{1f1e8}\u{1f1f5}",
+    "\u{1f1e8}\u{1f1f7}",
+    "\u{1f1e8}\u{1f1fa}",
+    "\u{1f1e8}\u{1f1fb}",
+    "\u{1f1e8}\u{1f1fc}",
+    "\u{1f1e8}\u{1f1fd}",
+    "\u{1f1e8}\u{1f1fe}",
+    "\u{1f1e8}\u{1f1ff}",
+    "\u{1f1e9}\u{1f1ea}",
+    "\u{1f1e9}\u{1f1ec}",
+    "\u{1f1e9}\u{1f1ef}",
+    "\u{1f1e9}\u{1f1f0}",
+    "\u{1f1e9}\u{1f1f2}",
+    "\u{1f1e9}\u{1f1f4}",
+    "\u{1f1e9}\u{1f1ff}",
+    "\u{1f1ea}\u{1f1e6}",
+    "\u{1f1ea}\u{1f1e8}",
+    "\u{1f1ea}\u{1f1ea}",
+    "\u{1f1ea}\u{1f1ec}",
+    "\u{1f1ea}\u{1f1ed}",
+    "\u{1f1ea}\u{1f1f7}",
+    "\u{1f1ea}\u{1f1f8}",
+    "\u{1f1ea}\u{1f1f9}",
+    "\u{1f1ea}\u{1f1fa}",
+    "\u{1f1eb}\u{1f1ee}",
+    "\u{1f1eb}\u{1f1ef}",
+    "\u{1f1eb}\u{1f1f0}",
+    "\u{1f1eb}\u{1f1f2}",
+    "\u{1f1eb}\u{1f1f4}",
+    "\u{1f1eb}\u{1f1f7}",
+    "\u{1f1ec}\u{1f1e6}",
+    "\u{1f1ec}\u{1f1e7}",
+    "\u{1f1ec}\u{1f1e9}",
+    "\u{1f1ec}\u{1f1ea}",
+    "\u{1f1ec}\u{1f1eb}",
+    "\u{1f1ec}\u{1f1ec}",
+    "\u{1f1ec}\u{1f1ed}",
+    "\u{1f1ec}\u{1f1ee}",
+    "\u{1f1ec}\u{1f1f1}",
+    "\u{1f1ec}\u{1f1f2}",
+    "\u{1f1ec}\u{1f1f3}",
+    "\u{1f1ec}\u{1f1f5}",
+    "\u{1f1ec}\u{1f1f6}",
+    "\u{1f1ec}\u{1f1f7}",
+    "\u{1f1ec}\u{1f1f8}",
+    "\u{1f1ec}\u{1f1f9}",
+    "\u{1f1ec}\u{1f1fa}",
+    "\u{1f1ec}\u{1f1fc}",
+    "\u{1f1ec}\u{1f1fe}",
+    "\u{1f1ed}\u{1f1f0}",
+    "\u{1f1ed}\u{1f1f2}",
+    "\u{1f1ed}\u{1f1f3}",
+    "\u{1f1ed}\u{1f1f7}",
+    "\u{1f1ed}\u{1f1f9}",
+    "\u{1f1ed}\u{1f1fa}",
+    "\u{1f1ee}\u{1f1e8}",
+    "\u{1f1ee}\u{1f1e9}",
+    "\u{1f1ee}\u{1f1ea}",
+    "\u{1f1ee}\u{1f1f1}",
+    "\u{1f1ee}\u{1f1f2}",
+    "\u{1f1ee}\u{1f1f3}",
+    "\u{1f1ee}\u{1f1f4}",
+    "\u{1f1ee}\u{1f1f6}",
+    "\u{1f1ee}\u{1f1f7}",
+    "\u{1f1ee}\u{1f1f8}",
+    "\u{1f1ee}\u{1f1f9}",
+    "\u{1f1ef}\u{1f1ea}",
+    "\u{1f1ef}\u{1f1f2}",
+    "\u{1f1ef}\u{1f1f4}",
+    "\u{1f1ef}\u{1f1f5}",
+    "\u{1f1f0}\u{1f1ea}",
+    "\u{1f1f0}\u{1f1ec}",
+    "\u{1f1f0}\u{1f1ed}",
+    "\u{1f1f0}\u{1f1ee}",
+    "\u{1f1f0}\u{1f1f2}",
+    "\u{1f1f0}\u{1f1f3}",
+    "\u{1f1f0}\u{1f1f5}",
+    "\u{1f1f0}\u{1f1f7}",
+    "\u{1f1f0}\u{1f1fc}",
+    "\u{1f1f0}\u{1f1fe}",
+    "\u{1f1f0}\u{1f1ff}",
+    "\u{1f1f1}\u{1f1e6}",
+    "\u{1f1f1}\u{1f1e7}",
+    "\u{1f1f1}\u{1f1e8}",
+    "\u{1f1f1}\u{1f1ee}",
+    "\u{1f1f1}\u{1f1f0}",
+    "\u{1f1f1}\u{1f1f7}",
+    "\u{1f1f1}\u{1f1f8}",
+    "\u{1f1f1}\u{1f1f9}",
+    "\u{1f1f1}\u{1f1fa}",
+    "\u{1f1f1}\u{1f1fb}",
+    "\u{1f1f1}\u{1f1fe}",
+    "\u{1f1f2}\u{1f1e6}",
+    "\u{1f1f2}\u{1f1e8}",
+    "\u{1f1f2}\u{1f1e9}",
+    "\u{1f1f2}\u{1f1ea}",
+    "\u{1f1f2}\u{1f1eb}",
+    "\u{1f1f2}\u{1f1ec}",
+    "\u{1f1f2}\u{1f1ed}",
+    "\u{1f1f2}\u{1f1f0}",
+    "\u{1f1f2}\u{1f1f1}",
+    "\u{1f1f2}\u{1f1f2}",
+    "\u{1f1f2}\u{1f1f3}",
+    "\u{1f1f2}\u{1f1f4}",
+    "\u{1f1f2}\u{1f1f5}",
+    "\u{1f1f2}\u{1f1f6}",
+    "\u{1f1f2}\u{1f1f7}",
+    "\u{1f1f2}\u{1f1f8}",
+    "\u{1f1f2}\u{1f1f9}",
+    "\u{1f1f2}\u{1f1fa}",
+    "\u{1f1f2}\u{1f1fb}",
+    "\u{1f1f2}\u{1f1fc}",
+    "\u{1f1f2}\u{1f1fd}",
+    "\u{1f1f2}\u{1f1fe}",
+    "\u{1f1f2}\u{1f1ff}",
+    "\u{1f1f3}\u{1f1e6}",
+    "\u{1f1f3}\u{1f1e8}",
+    "\u{1f1f3}\u{1f1ea}",
+    "\u{1f1f3}\u{1f1eb}",
+    "\u{1f1f3}\u{1f1ec}",
+    "\u{1f1f3}\u{1f1ee}",
+    "\u{1f1f3}\u{1f1f1}",
+    "\u{1f1f3}\u{1f1f4}",
+    "\u{1f1f3}\u{1f1f5}",
+    "\u{1f1f3}\u{1f1f7}",
+    "\u{1f1f3}\u{1f1fa}",
+    "\u{1f1f3}\u{1f1ff}",
+    "\u{1f1f4}\u{1f1f2}",
+    "\u{1f1f5}\u{1f1e6}",
+    "\u{1f1f5}\u{1f1ea}",
+    "\u{1f1f5}\u{1f1eb}",
+    "\u{1f1f5}\u{1f1ec}",
+    "\u{1f1f5}\u{1f1ed}",
+    "\u{1f1f5}\u{1f1f0}",
+    "\u{1f1f5}\u{1f1f1}",
+    "\u{1f1f5}\u{1f1f2}",
+    "\u{1f1f5}\u{1f1f3}",
+    "\u{1f1f5}\u{1f1f7}",
+    "\u{1f1f5}\u{1f1f8}",
+    "\u{1f1f5}\u{1f1f9}",
+    "\u{1f1f5}\u{1f1fc}",
+    "\u{1f1f5}\u{1f1fe}",
+    "\u{1f1f6}\u{1f1e6}",
+    "\u{1f1f7}\u{1f1ea}",
+    "\u{1f1f7}\u{1f1f4}",
+    "\u{1f1f7}\u{1f1f8}",
+    "\u{1f1f7}\u{1f1fa}",
+    "\u{1f1f7}\u{1f1fc}",
+    "\u{1f1f8}\u{1f1e6}",
+    "\u{1f1f8}\u{1f1e7}",
+    "\u{1f1f8}\u{1f1e8}",
+    "\u{1f1f8}\u{1f1e9}",
+    "\u{1f1f8}\u{1f1ea}",
+    "\u{1f1f8}\u{1f1ec}",
+    "\u{1f1f8}\u{1f1ed}",
+    "\u{1f1f8}\u{1f1ee}",
+    "\u{1f1f8}\u{1f1ef}",
+    "\u{1f1f8}\u{1f1f0}",
+    "\u{1f1f8}\u{1f1f1}",
+    "\u{1f1f8}\u{1f1f2}",
+    "\u{1f1f8}\u{1f1f3}",
+    "\u{1f1f8}\u{1f1f4}",
+    "\u{1f1f8}\u{1f1f7}",
+    "\u{1f1f8}\u{1f1f8}",
+    "\u{1f1f8}\u{1f1f9}",
+    "\u{1f1f8}\u{1f1fb}",
+    "\u{1f1f8}\u{1f1fd}",
+    "\u{1f1f8}\u{1f1fe}",
+    "\u{1f1f8}\u{1f1ff}",
+    "\u{1f1f9}\u{1f1e6}",
+    "\u{1f1f9}\u{1f1e8}",
+    "\u{1f1f9}\u{1f1e9}",
+    "\u{1f1f9}\u{1f1eb}",
+    "\u{1f1f9}\u{1f1ec}",
+    "\u{1f1f9}\u{1f1ed}",
+    "\u{1f1f9}\u{1f1ef}",
+    "\u{1f1f9}\u{1f1f0}",
+    "\u{1f1f9}\u{1f1f1}",
+    "\u{1f1f9}\u{1f1f2}",
+    "\u{1f1f9}\u{1f1f3}",
+    "\u{1f1f9}\u{1f1f4}",
+    "\u{1f1f9}\u{1f1f7}",
+    "\u{1f1f9}\u{1f1f9}",
+    "\u{1f1f9}\u{1f1fb}",
+    "\u{1f1f9}\u{1f1fc}",
+    "\u{1f1f9}\u{1f1ff}",
+    "\u{1f1fa}\u{1f1e6}",
+    "\u{1f1fa}\u{1f1ec}",
+    "\u{1f1fa}\u{1f1f2}",
+    "\u{1f1fa}\u{1f1f3}",
+    "\u{1f1fa}\u{1f1f8}",
+    "\u{1f1fa}\u{1f1fe}",
+    "\u{1f1fa}\u{1f1ff}",
+    "\u{1f1fb}\u{1f1e6}",
+    "\u{1f1fb}\u{1f1e8}",
+    "\u{1f1fb}\u{1f1ea}",
+    "\u{1f1fb}\u{1f1ec}",
+    "\u{1f1fb}\u{1f1ee}",
+    "\u{1f1fb}\u{1f1f3}",
+    "\u{1f1fb}\u{1f1fa}",
+    "\u{1f1fc}\u{1f1eb}",
+    "\u{1f1fc}\u{1f1f8}",
+    "\u{1f1fd}\u{1f1f0}",
+    "\u{1f1fe}\u{1f1ea}",
+    "\u{1f1fe}\u{1f1f9}",
+    "\u{1f1ff}\u{1f1e6}",
+    "\u{1f1ff}\u{1f1f2}",
+    "\u{1f1ff}\u{1f1fc}",
+];
+
+pub const KEYCAP: &'static [&'static str] = &[
+    "\u{23}\u{fe0f}\u{20e3}",
+    "\u{2a}\u{fe0f}\u{20e3}",
+    "\u{30}\u{fe0f}\u{20e3}",
+    "\u{31}\u{fe0f}\u{20e3}",
+    "\u{32}\u{fe0f}\u{20e3}",
+    "\u{33}\u{fe0f}\u{20e3}",
+    "\u{34}\u{fe0f}\u{20e3}",
+    "\u{35}\u{fe0f}\u{20e3}",
+    "\u{36}\u{fe0f}\u{20e3}",
+    "\u{37}\u{fe0f}\u{20e3}",
+    "\u{38}\u{fe0f}\u{20e3}",
+    "\u{39}\u{fe0f}\u{20e3}",
+];
+
+pub const MODIFIER: &'static [&'static str] = &[
+    "\u{261d}\u{1f3fb}",
+    "\u{261d}\u{1f3fc}",
+    "\u{261d}\u{1f3fd}",
+    "\u{261d}\u{1f3fe}",
+    "\u{261d}\u{1f3ff}",
+    "\u{26f9}\u{1f3fb}",
+    "\u{26f9}\u{1f3fc}",
+    "\u{26f9}\u{1f3fd}",
+    "\u{26f9}\u{1f3fe}",
+    "\u{26f9}\u{1f3ff}",
+    "\u{270a}\u{1f3fb}",
+    "\u{270a}\u{1f3fc}",
+    "\u{270a}\u{1f3fd}",
+    "\u{270a}\u{1f3fe}",
+    "\u{270a}\u{1f3ff}",
+    "\u{270b}\u{1f3fb}",
+    "\u{270b}\u{1f3fc}",
+    "\u{270b}\u{1f3fd}",
+    "\u{270b}\u{1f3fe}",
+    "\u{270b}\u{1f3ff}",
+    "\u{270c}\u{1f3fb}",
+    "\u{270c}\u{1f3fc}",
+    "\u{270c}\u{1f3fd}",
+    "\u{270c}\u{1f3fe}",
+    "\u{270c}\u{1f3ff}",
+    "\u{270d}\u{1f3fb}",
+    "\u{270d}\u{1f3fc}",
+    "\u{270d}\u{1f3fd}",
+    "\u{270d}\u{1f3fe}",
+    "\u{270d}\u{1f3ff}",
+    "\u{1f385}\u{1f3fb}",
+    "\u{1f385}\u{1f3fc}",
+    "\u{1f385}\u{1f3fd}",
+    "\u{1f385}\u{1f3fe}",
+    "\u{1f385}\u{1f3ff}",
+    "\u{1f3c2}\u{1f3fb}",
+    "\u{1f3c2}\u{1f3fc}",
+    "\u{1f3c2}\u{1f3fd}",
+    "\u{1f3c2}\u{1f3fe}",
+    "\u{1f3c2}\u{1f3ff}",
+    "\u{1f3c3}\u{1f3fb}",
+    "\u{1f3c3}\u{1f3fc}",
+    "\u{1f3c3}\u{1f3fd}",
+    "\u{1f3c3}\u{1f3fe}",
+    "\u{1f3c3}\u{1f3ff}",
+    "\u{1f3c4}\u{1f3fb}",
+    "\u{1f3c4}\u{1f3fc}",
+    "\u{1f3c4}\u{1f3fd}",
+    "\u{1f3c4}\u{1f3fe}",
+    "\u{1f3c4}\u{1f3ff}",
+    "\u{1f3c7}\u{1f3fb}",
+    "\u{1f3c7}\u{1f3fc}",
+    "\u{1f3c7}\u{1f3fd}",
+    "\u{1f3c7}\u{1f3fe}",
+    "\u{1f3c7}\u{1f3ff}",
+    "\u{1f3ca}\u{1f3fb}",
+    "\u{1f3ca}\u{1f3fc}",
+    "\u{1f3ca}\u{1f3fd}",
+    "\u{1f3ca}\u{1f3fe}",
+    "\u{1f3ca}\u{1f3ff}",
+    "\u{1f3cb}\u{1f3fb}",
+    "\u{1f3cb}\u{1f3fc}",
+    "\u{1f3cb}\u{1f3fd}",
+    "\u{1f3cb}\u{1f3fe}",
+    "\u{1f3cb}\u{1f3ff}",
+    "\u{1f3cc}\u{1f3fb}",
+    "\u{1f3cc}\u{1f3fc}",
+    "\u{1f3cc}\u{1f3fd}",
+    "\u{1f3cc}\u{1f3fe}",
+    "\u{1f3cc}\u{1f3ff}",
+    "\u{1f442}\u{1f3fb}",
+    "\u{1f442}\u{1f3fc}",
+    "\u{1f442}\u{1f3fd}",
+    "\u{1f442}\u{1f3fe}",
+    "\u{1f442}\u{1f3ff}",
+    "\u{1f443}\u{1f3fb}",
+    "\u{1f443}\u{1f3fc}",
+    "\u{1f443}\u{1f3fd}",
+    "\u{1f443}\u{1f3fe}",
+    "\u{1f443}\u{1f3ff}",
+    "\u{1f446}\u{1f3fb}",
+    "\u{1f446}\u{1f3fc}",
+    "\u{1f446}\u{1f3fd}",
+    "\u{1f446}\u{1f3fe}",
+    "\u{1f446}\u{1f3ff}",
+    "\u{1f447}\u{1f3fb}",
+    "\u{1f447}\u{1f3fc}",
+    "\u{1f447}\u{1f3fd}",
+    "\u{1f447}\u{1f3fe}",
+    "\u{1f447}\u{1f3ff}",
+    "\u{1f448}\u{1f3fb}",
+    "\u{1f448}\u{1f3fc}",
+    "\u{1f448}\u{1f3fd}",
+    "\u{1f448}\u{1f3fe}",
+    "\u{1f448}\u{1f3ff}",
+    "\u{1f449}\u{1f3fb}",
+    "\u{1f449}\u{1f3fc}",
+    "\u{1f449}\u{1f3fd}",
+    "\u{1f449}\u{1f3fe}",
+    "\u{1f449}\u{1f3ff}",
+    "\u{1f44a}\u{1f3fb}",
+    "\u{1f44a}\u{1f3fc}",
+    "\u{1f44a}\u{1f3fd}",
+    "\u{1f44a}\u{1f3fe}",
+    "\u{1f44a}\u{1f3ff}",
+    "\u{1f44b}\u{1f3fb}",
+    "\u{1f44b}\u{1f3fc}",
+    "\u{1f44b}\u{1f3fd}",
+    "\u{1f44b}\u{1f3fe}",
+    "\u{1f44b}\u{1f3ff}",
+    "\u{1f44c}\u{1f3fb}",
+    "\u{1f44c}\u{1f3fc}",
+    "\u{1f44c}\u{1f3fd}",
+    "\u{1f44c}\u{1f3fe}",
+    "\u{1f44c}\u{1f3ff}",
+    "\u{1f44d}\u{1f3fb}",
+    "\u{1f44d}\u{1f3fc}",
+    "\u{1f44d}\u{1f3fd}",
+    "\u{1f44d}\u{1f3fe}",
+    "\u{1f44d}\u{1f3ff}",
+    "\u{1f44e}\u{1f3fb}",
+    "\u{1f44e}\u{1f3fc}",
+    "\u{1f44e}\u{1f3fd}",
+    "\u{1f44e}\u{1f3fe}",
+    "\u{1f44e}\u{1f3ff}",
+    "\u{1f44f}\u{1f3fb}",
+    "\u{1f44f}\u{1f3fc}",
+    "\u{1f44f}\u{1f3fd}",
+    "\u{1f44f}\u{1f3fe}",
+    "\u{1f44f}\u{1f3ff}",
+    "\u{1f450}\u{1f3fb}",
+    "\u{1f450}\u{1f3fc}",
+    "\u{1f450}\u{1f3fd}",
+    "\u{1f450}\u{1f3fe}",
+    "\u{1f450}\u{1f3ff}",
+    "\u{1f466}\u{1f3fb}",
+    "\u{1f466}\u{1f3fc}",
+    "\u{1f466}\u{1f3fd}",
+    "\u{1f466}\u{1f3fe}",
+    "\u{1f466}\u{1f3ff}",
+    "\u{1f467}\u{1f3fb}",
+    "\u{1f467}\u{1f3fc}",
+    "\u{1f467}\u{1f3fd}",
+    "\u{1f467}\u{1f3fe}",
+    "\u{1f467}\u{1f3ff}",
+    "\u{1f468}\u{1f3fb}",
+    "\u{1f468}\u{1f3fc}",
+    "\u{1f468}\u{1f3fd}",
+    "\u{1f468}\u{1f3fe}",
+    "\u{1f468}\u{1f3ff}",
+    "\u{1f469}\u{1f3fb}",
+    "\u{1f469}\u{1f3fc}",
+    "\u{1f469}\u{1f3fd}",
+    "\u{1f469}\u{1f3fe}",
+    "\u{1f469}\u{1f3ff}",
+    "\u{1f46e}\u{1f3fb}",
+    "\u{1f46e}\u{1f3fc}",
+    "\u{1f46e}\u{1f3fd}",
+    "\u{1f46e}\u{1f3fe}",
+    "\u{1f46e}\u{1f3ff}",
+    "\u{1f470}\u{1f3fb}",
+    "\u{1f470}\u{1f3fc}",
+    "\u{1f470}\u{1f3fd}",
+    "\u{1f470}\u{1f3fe}",
+    "\u{1f470}\u{1f3ff}",
+    "\u{1f471}\u{1f3fb}",
+    "\u{1f471}\u{1f3fc}",
+    "\u{1f471}\u{1f3fd}",
+    "\u{1f471}\u{1f3fe}",
+    "\u{1f471}\u{1f3ff}",
+    "\u{1f472}\u{1f3fb}",
+    "\u{1f472}\u{1f3fc}",
+    "\u{1f472}\u{1f3fd}",
+    "\u{1f472}\u{1f3fe}",
+    "\u{1f472}\u{1f3ff}",
+    "\u{1f473}\u{1f3fb}",
+    "\u{1f473}\u{1f3fc}",
+    "\u{1f473}\u{1f3fd}",
+    "\u{1f473}\u{1f3fe}",
+    "\u{1f473}\u{1f3ff}",
+    "\u{1f474}\u{1f3fb}",
+    "\u{1f474}\u{1f3fc}",
+    "\u{1f474}\u{1f3fd}",
+    "\u{1f474}\u{1f3fe}",
+    "\u{1f474}\u{1f3ff}",
+    "\u{1f475}\u{1f3fb}",
+    "\u{1f475}\u{1f3fc}",
+    "\u{1f475}\u{1f3fd}",
+    "\u{1f475}\u{1f3fe}",
+    "\u{1f475}\u{1f3ff}",
+    "\u{1f476}\u{1f3fb}",
+    "\u{1f476}\u{1f3fc}",
+    "\u{1f476}\u{1f3fd}",
+    "\u{1f476}\u{1f3fe}",
+    "\u{1f476}\u{1f3ff}",
+    "\u{1f477}\u{1f3fb}",
+    "\u{1f477}\u{1f3fc}",
+    "\u{1f477}\u{1f3fd}",
+    "\u{1f477}\u{1f3fe}",
+    "\u{1f477}\u{1f3ff}",
+    "\u{1f478}\u{1f3fb}",
+    "\u{1f478}\u{1f3fc}",
+    "\u{1f478}\u{1f3fd}",
+    "\u{1f478}\u{1f3fe}",
+    "\u{1f478}\u{1f3ff}",
+    "\u{1f47c}\u{1f3fb}",
+    "\u{1f47c}\u{1f3fc}",
+    "\u{1f47c}\u{1f3fd}",
+    "\u{1f47c}\u{1f3fe}",
+    "\u{1f47c}\u{1f3ff}",
+    "\u{1f481}\u{1f3fb}",
+    "\u{1f481}\u{1f3fc}",
+    "\u{1f481}\u{1f3fd}",
+    "\u{1f481}\u{1f3fe}",
+    "\u{1f481}\u{1f3ff}",
+    "\u{1f482}\u{1f3fb}",
+    "\u{1f482}\u{1f3fc}",
+    "\u{1f482}\u{1f3fd}",
+    "\u{1f482}\u{1f3fe}",
+    "\u{1f482}\u{1f3ff}",
+    "\u{1f483}\u{1f3fb}",
+    "\u{1f483}\u{1f3fc}",
+    "\u{1f483}\u{1f3fd}",
+    "\u{1f483}\u{1f3fe}",
+    "\u{1f483}\u{1f3ff}",
+    "\u{1f485}\u{1f3fb}",
+    "\u{1f485}\u{1f3fc}",
+    "\u{1f485}\u{1f3fd}",
+    "\u{1f485}\u{1f3fe}",
+    "\u{1f485}\u{1f3ff}",
+    "\u{1f486}\u{1f3fb}",
+    "\u{1f486}\u{1f3fc}",
+    "\u{1f486}\u{1f3fd}",
+    "\u{1f486}\u{1f3fe}",
+    "\u{1f486}\u{1f3ff}",
+    "\u{1f487}\u{1f3fb}",
+    "\u{1f487}\u{1f3fc}",
+    "\u{1f487}\u{1f3fd}",
+    "\u{1f487}\u{1f3fe}",
+    "\u{1f487}\u{1f3ff}",
+    "\u{1f4aa}\u{1f3fb}",
+    "\u{1f4aa}\u{1f3fc}",
+    "\u{1f4aa}\u{1f3fd}",
+    "\u{1f4aa}\u{1f3fe}",
+    "\u{1f4aa}\u{1f3ff}",
+    "\u{1f574}\u{1f3fb}",
+    "\u{1f574}\u{1f3fc}",
+    "\u{1f574}\u{1f3fd}",
+    "\u{1f574}\u{1f3fe}",
+    "\u{1f574}\u{1f3ff}",
+    "\u{1f575}\u{1f3fb}",
+    "\u{1f575}\u{1f3fc}",
+    "\u{1f575}\u{1f3fd}",
+    "\u{1f575}\u{1f3fe}",
+    "\u{1f575}\u{1f3ff}",
+    "\u{1f57a}\u{1f3fb}",
+    "\u{1f57a}\u{1f3fc}",
+    "\u{1f57a}\u{1f3fd}",
+    "\u{1f57a}\u{1f3fe}",
+    "\u{1f57a}\u{1f3ff}",
+    "\u{1f590}\u{1f3fb}",
+    "\u{1f590}\u{1f3fc}",
+    "\u{1f590}\u{1f3fd}",
+    "\u{1f590}\u{1f3fe}",
+    "\u{1f590}\u{1f3ff}",
+    "\u{1f595}\u{1f3fb}",
+    "\u{1f595}\u{1f3fc}",
+    "\u{1f595}\u{1f3fd}",
+    "\u{1f595}\u{1f3fe}",
+    "\u{1f595}\u{1f3ff}",
+    "\u{1f596}\u{1f3fb}",
+    "\u{1f596}\u{1f3fc}",
+    "\u{1f596}\u{1f3fd}",
+    "\u{1f596}\u{1f3fe}",
+    "\u{1f596}\u{1f3ff}",
+    "\u{1f645}\u{1f3fb}",
+    "\u{1f645}\u{1f3fc}",
+    "\u{1f645}\u{1f3fd}",
+    "\u{1f645}\u{1f3fe}",
+    "\u{1f645}\u{1f3ff}",
+    "\u{1f646}\u{1f3fb}",
+    "\u{1f646}\u{1f3fc}",
+    "\u{1f646}\u{1f3fd}",
+    "\u{1f646}\u{1f3fe}",
+    "\u{1f646}\u{1f3ff}",
+    "\u{1f647}\u{1f3fb}",
+    "\u{1f647}\u{1f3fc}",
+    "\u{1f647}\u{1f3fd}",
+    "\u{1f647}\u{1f3fe}",
+    "\u{1f647}\u{1f3ff}",
+    "\u{1f64b}\u{1f3fb}",
+    "\u{1f64b}\u{1f3fc}",
+    "\u{1f64b}\u{1f3fd}",
+    "\u{1f64b}\u{1f3fe}",
+    "\u{1f64b}\u{1f3ff}",
+    "\u{1f64c}\u{1f3fb}",
+    "\u{1f64c}\u{1f3fc}",
+    "\u{1f64c}\u{1f3fd}",
+    "\u{1f64c}\u{1f3fe}",
+    "\u{1f64c}\u{1f3ff}",
+    "\u{1f64d}\u{1f3fb}",
+    "\u{1f64d}\u{1f3fc}",
+    "\u{1f64d}\u{1f3fd}",
+    "\u{1f64d}\u{1f3fe}",
+    "\u{1f64d}\u{1f3ff}",
+    "\u{1f64e}\u{1f3fb}",
+    "\u{1f64e}\u{1f3fc}",
+    "\u{1f64e}\u{1f3fd}",
+    "\u{1f64e}\u{1f3fe}",
+    "\u{1f64e}\u{1f3ff}",
+    "\u{1f64f}\u{1f3fb}",
+    "\u{1f64f}\u{1f3fc}",
+    "\u{1f64f}\u{1f3fd}",
+    "\u{1f64f}\u{1f3fe}",
+    "\u{1f64f}\u{1f3ff}",
+    "\u{1f6a3}\u{1f3fb}",
+    "\u{1f6a3}\u{1f3fc}",
+    "\u{1f6a3}\u{1f3fd}",
+    "\u{1f6a3}\u{1f3fe}",
+    "\u{1f6a3}\u{1f3ff}",
+    "\u{1f6b4}\u{1f3fb}",
+    "\u{1f6b4}\u{1f3fc}",
+    "\u{1f6b4}\u{1f3fd}",
+    "\u{1f6b4}\u{1f3fe}",
+    "\u{1f6b4}\u{1f3ff}",
+    "\u{1f6b5}\u{1f3fb}",
+    "\u{1f6b5}\u{1f3fc}",
+    "\u{1f6b5}\u{1f3fd}",
+    "\u{1f6b5}\u{1f3fe}",
+    "\u{1f6b5}\u{1f3ff}",
+    "\u{1f6b6}\u{1f3fb}",
+    "\u{1f6b6}\u{1f3fc}",
+    "\u{1f6b6}\u{1f3fd}",
+    "\u{1f6b6}\u{1f3fe}",
+    "\u{1f6b6}\u{1f3ff}",
+    "\u{1f6c0}\u{1f3fb}",
+    "\u{1f6c0}\u{1f3fc}",
+    "\u{1f6c0}\u{1f3fd}",
+    "\u{1f6c0}\u{1f3fe}",
+    "\u{1f6c0}\u{1f3ff}",
+    "\u{1f6cc}\u{1f3fb}",
+    "\u{1f6cc}\u{1f3fc}",
+    "\u{1f6cc}\u{1f3fd}",
+    "\u{1f6cc}\u{1f3fe}",
+    "\u{1f6cc}\u{1f3ff}",
+    "\u{1f918}\u{1f3fb}",
+    "\u{1f918}\u{1f3fc}",
+    "\u{1f918}\u{1f3fd}",
+    "\u{1f918}\u{1f3fe}",
+    "\u{1f918}\u{1f3ff}",
+    "\u{1f919}\u{1f3fb}",
+    "\u{1f919}\u{1f3fc}",
+    "\u{1f919}\u{1f3fd}",
+    "\u{1f919}\u{1f3fe}",
+    "\u{1f919}\u{1f3ff}",
+    "\u{1f91a}\u{1f3fb}",
+    "\u{1f91a}\u{1f3fc}",
+    "\u{1f91a}\u{1f3fd}",
+    "\u{1f91a}\u{1f3fe}",
+    "\u{1f91a}\u{1f3ff}",
+    "\u{1f91b}\u{1f3fb}",
+    "\u{1f91b}\u{1f3fc}",
+    "\u{1f91b}\u{1f3fd}",
+    "\u{1f91b}\u{1f3fe}",
+    "\u{1f91b}\u{1f3ff}",
+    "\u{1f91c}\u{1f3fb}",
+    "\u{1f91c}\u{1f3fc}",
+    "\u{1f91c}\u{1f3fd}",
+    "\u{1f91c}\u{1f3fe}",
+    "\u{1f91c}\u{1f3ff}",
+    "\u{1f91e}\u{1f3fb}",
+    "\u{1f91e}\u{1f3fc}",
+    "\u{1f91e}\u{1f3fd}",
+    "\u{1f91e}\u{1f3fe}",
+    "\u{1f91e}\u{1f3ff}",
+    "\u{1f91f}\u{1f3fb}",
+    "\u{1f91f}\u{1f3fc}",
+    "\u{1f91f}\u{1f3fd}",
+    "\u{1f91f}\u{1f3fe}",
+    "\u{1f91f}\u{1f3ff}",
+    "\u{1f926}\u{1f3fb}",
+    "\u{1f926}\u{1f3fc}",
+    "\u{1f926}\u{1f3fd}",
+    "\u{1f926}\u{1f3fe}",
+    "\u{1f926}\u{1f3ff}",
+    "\u{1f930}\u{1f3fb}",
+    "\u{1f930}\u{1f3fc}",
+    "\u{1f930}\u{1f3fd}",
+    "\u{1f930}\u{1f3fe}",
+    "\u{1f930}\u{1f3ff}",
+    "\u{1f931}\u{1f3fb}",
+    "\u{1f931}\u{1f3fc}",
+    "\u{1f931}\u{1f3fd}",
+    "\u{1f931}\u{1f3fe}",
+    "\u{1f931}\u{1f3ff}",
+    "\u{1f932}\u{1f3fb}",
+    "\u{1f932}\u{1f3fc}",
+    "\u{1f932}\u{1f3fd}",
+    "\u{1f932}\u{1f3fe}",
+    "\u{1f932}\u{1f3ff}",
+    "\u{1f933}\u{1f3fb}",
+    "\u{1f933}\u{1f3fc}",
+    "\u{1f933}\u{1f3fd}",
+    "\u{1f933}\u{1f3fe}",
+    "\u{1f933}\u{1f3ff}",
+    "\u{1f934}\u{1f3fb}",
+    "\u{1f934}\u{1f3fc}",
+    "\u{1f934}\u{1f3fd}",
+    "\u{1f934}\u{1f3fe}",
+    "\u{1f934}\u{1f3ff}",
+    "\u{1f935}\u{1f3fb}",
+    "\u{1f935}\u{1f3fc}",
+    "\u{1f935}\u{1f3fd}",
+    "\u{1f935}\u{1f3fe}",
+    "\u{1f935}\u{1f3ff}",
+    "\u{1f936}\u{1f3fb}",
+    "\u{1f936}\u{1f3fc}",
+    "\u{1f936}\u{1f3fd}",
+    "\u{1f936}\u{1f3fe}",
+    "\u{1f936}\u{1f3ff}",
+    "\u{1f937}\u{1f3fb}",
+    "\u{1f937}\u{1f3fc}",
+    "\u{1f937}\u{1f3fd}",
+    "\u{1f937}\u{1f3fe}",
+    "\u{1f937}\u{1f3ff}",
+    "\u{1f938}\u{1f3fb}",
+    "\u{1f938}\u{1f3fc}",
+    "\u{1f938}\u{1f3fd}",
+    "\u{1f938}\u{1f3fe}",
+    "\u{1f938}\u{1f3ff}",
+    "\u{1f939}\u{1f3fb}",
+    "\u{1f939}\u{1f3fc}",
+    "\u{1f939}\u{1f3fd}",
+    "\u{1f939}\u{1f3fe}",
+    "\u{1f939}\u{1f3ff}",
+    "\u{1f93d}\u{1f3fb}",
+    "\u{1f93d}\u{1f3fc}",
+    "\u{1f93d}\u{1f3fd}",
+    "\u{1f93d}\u{1f3fe}",
+    "\u{1f93d}\u{1f3ff}",
+    "\u{1f93e}\u{1f3fb}",
+    "\u{1f93e}\u{1f3fc}",
+    "\u{1f93e}\u{1f3fd}",
+    "\u{1f93e}\u{1f3fe}",
+    "\u{1f93e}\u{1f3ff}",
+    "\u{1f9b5}\u{1f3fb}",
+    "\u{1f9b5}\u{1f3fc}",
+    "\u{1f9b5}\u{1f3fd}",
+    "\u{1f9b5}\u{1f3fe}",
+    "\u{1f9b5}\u{1f3ff}",
+    "\u{1f9b6}\u{1f3fb}",
+    "\u{1f9b6}\u{1f3fc}",
+    "\u{1f9b6}\u{1f3fd}",
+    "\u{1f9b6}\u{1f3fe}",
+    "\u{1f9b6}\u{1f3ff}",
+    "\u{1f9b8}\u{1f3fb}",
+    "\u{1f9b8}\u{1f3fc}",
+    "\u{1f9b8}\u{1f3fd}",
+    "\u{1f9b8}\u{1f3fe}",
+    "\u{1f9b8}\u{1f3ff}",
+    "\u{1f9b9}\u{1f3fb}",
+    "\u{1f9b9}\u{1f3fc}",
+    "\u{1f9b9}\u{1f3fd}",
+    "\u{1f9b9}\u{1f3fe}",
+    "\u{1f9b9}\u{1f3ff}",
+    "\u{1f9d1}\u{1f3fb}",
+    "\u{1f9d1}\u{1f3fc}",
+    "\u{1f9d1}\u{1f3fd}",
+    "\u{1f9d1}\u{1f3fe}",
+    "\u{1f9d1}\u{1f3ff}",
+    "\u{1f9d2}\u{1f3fb}",
+    "\u{1f9d2}\u{1f3fc}",
+    "\u{1f9d2}\u{1f3fd}",
+    "\u{1f9d2}\u{1f3fe}",
+    "\u{1f9d2}\u{1f3ff}",
+    "\u{1f9d3}\u{1f3fb}",
+    "\u{1f9d3}\u{1f3fc}",
+    "\u{1f9d3}\u{1f3fd}",
+    "\u{1f9d3}\u{1f3fe}",
+    "\u{1f9d3}\u{1f3ff}",
+    "\u{1f9d4}\u{1f3fb}",
+    "\u{1f9d4}\u{1f3fc}",
+    "\u{1f9d4}\u{1f3fd}",
+    "\u{1f9d4}\u{1f3fe}",
+    "\u{1f9d4}\u{1f3ff}",
+    "\u{1f9d5}\u{1f3fb}",
+    "\u{1f9d5}\u{1f3fc}",
+    "\u{1f9d5}\u{1f3fd}",
+    "\u{1f9d5}\u{1f3fe}",
+    "\u{1f9d5}\u{1f3ff}",
+    "\u{1f9d6}\u{1f3fb}",
+    "\u{1f9d6}\u{1f3fc}",
+    "\u{1f9d6}\u{1f3fd}",
+    "\u{1f9d6}\u{1f3fe}",
+    "\u{1f9d6}\u{1f3ff}",
+    "\u{1f9d7}\u{1f3fb}",
+    "\u{1f9d7}\u{1f3fc}",
+    "\u{1f9d7}\u{1f3fd}",
+    "\u{1f9d7}\u{1f3fe}",
+    "\u{1f9d7}\u{1f3ff}",
+    "\u{1f9d8}\u{1f3fb}",
+    "\u{1f9d8}\u{1f3fc}",
+    "\u{1f9d8}\u{1f3fd}",
+    "\u{1f9d8}\u{1f3fe}",
+    "\u{1f9d8}\u{1f3ff}",
+    "\u{1f9d9}\u{1f3fb}",
+    "\u{1f9d9}\u{1f3fc}",
+    "\u{1f9d9}\u{1f3fd}",
+    "\u{1f9d9}\u{1f3fe}",
+    "\u{1f9d9}\u{1f3ff}",
+    "\u{1f9da}\u{1f3fb}",
+    "\u{1f9da}\u{1f3fc}",
+    "\u{1f9da}\u{1f3fd}",
+    "\u{1f9da}\u{1f3fe}",
+    "\u{1f9da}\u{1f3ff}",
+    "\u{1f9db}\u{1f3fb}",
+    "\u{1f9db}\u{1f3fc}",
+    "\u{1f9db}\u{1f3fd}",
+    "\u{1f9db}\u{1f3fe}",
+    "\u{1f9db}\u{1f3ff}",
+    "\u{1f9dc}\u{1f3fb}",
+    "\u{1f9dc}\u{1f3fc}",
+    "\u{1f9dc}\u{1f3fd}",
+    "\u{1f9dc}\u{1f3fe}",
+    "\u{1f9dc}\u{1f3ff}",
+    "\u{1f9dd}\u{1f3fb}",
+    "\u{1f9dd}\u{1f3fc}",
+    "\u{1f9dd}\u{1f3fd}",
+    "\u{1f9dd}\u{1f3fe}",
+    "\u{1f9dd}\u{1f3ff}",
+];
+
+pub const PRESENTATION: &'static [&'static str] = &[
+    "\u{a9}\u{fe0f}",
+    "\u{ae}\u{fe0f}",
+    "\u{203c}\u{fe0f}",
+    "\u{2049}\u{fe0f}",
+    "\u{2122}\u{fe0f}",
+    "\u{2139}\u{fe0f}",
+    "\u{2194}\u{fe0f}",
+    "\u{2195}\u{fe0f}",
+    "\u{2196}\u{fe0f}",
+    "\u{2197}\u{fe0f}",
+    "\u{2198}\u{fe0f}",
+    "\u{2199}\u{fe0f}",
+    "\u{21a9}\u{fe0f}",
+    "\u{21aa}\u{fe0f}",
+    "\u{2328}\u{fe0f}",
+    "\u{23cf}\u{fe0f}",
+    "\u{23ed}\u{fe0f}",
+    "\u{23ee}\u{fe0f}",
+    "\u{23ef}\u{fe0f}",
+    "\u{23f1}\u{fe0f}",
+    "\u{23f2}\u{fe0f}",
+    "\u{23f8}\u{fe0f}",
+    "\u{23f9}\u{fe0f}",
+    "\u{23fa}\u{fe0f}",
+    "\u{24c2}\u{fe0f}",
+    "\u{25aa}\u{fe0f}",
+    "\u{25ab}\u{fe0f}",
+    "\u{25b6}\u{fe0f}",
+    "\u{25c0}\u{fe0f}",
+    "\u{25fb}\u{fe0f}",
+    "\u{25fc}\u{fe0f}",
+    "\u{2600}\u{fe0f}",
+    "\u{2601}\u{fe0f}",
+    "\u{2602}\u{fe0f}",
+    "\u{2603}\u{fe0f}",
+    "\u{2604}\u{fe0f}",
+    "\u{260e}\u{fe0f}",
+    "\u{2611}\u{fe0f}",
+    "\u{2618}\u{fe0f}",
+    "\u{261d}\u{fe0f}",
+    "\u{2620}\u{fe0f}",
+    "\u{2622}\u{fe0f}",
+    "\u{2623}\u{fe0f}",
+    "\u{2626}\u{fe0f}",
+    "\u{262a}\u{fe0f}",
+    "\u{262e}\u{fe0f}",
+    "\u{262f}\u{fe0f}",
+    "\u{2638}\u{fe0f}",
+    "\u{2639}\u{fe0f}",
+    "\u{263a}\u{fe0f}",
+    "\u{2640}\u{fe0f}",
+    "\u{2642}\u{fe0f}",
+    "\u{265f}\u{fe0f}",
+    "\u{2660}\u{fe0f}",
+    "\u{2663}\u{fe0f}",
+    "\u{2665}\u{fe0f}",
+    "\u{2666}\u{fe0f}",
+    "\u{2668}\u{fe0f}",
+    "\u{267b}\u{fe0f}",
+    "\u{267e}\u{fe0f}",
+    "\u{2692}\u{fe0f}",
+    "\u{2694}\u{fe0f}",
+    "\u{2695}\u{fe0f}",
+    "\u{2696}\u{fe0f}",
+    "\u{2697}\u{fe0f}",
+    "\u{2699}\u{fe0f}",
+    "\u{269b}\u{fe0f}",
+    "\u{269c}\u{fe0f}",
+    "\u{26a0}\u{fe0f}",
+    "\u{26b0}\u{fe0f}",
+    "\u{26b1}\u{fe0f}",
+    "\u{26c8}\u{fe0f}",
+    "\u{26cf}\u{fe0f}",
+    "\u{26d1}\u{fe0f}",
+    "\u{26d3}\u{fe0f}",
+    "\u{26e9}\u{fe0f}",
+    "\u{26f0}\u{fe0f}",
+    "\u{26f1}\u{fe0f}",
+    "\u{26f4}\u{fe0f}",
+    "\u{26f7}\u{fe0f}",
+    "\u{26f8}\u{fe0f}",
+    "\u{26f9}\u{fe0f}",
+    "\u{2702}\u{fe0f}",
+    "\u{2708}\u{fe0f}",
+    "\u{2709}\u{fe0f}",
+    "\u{270c}\u{fe0f}",
+    "\u{270d}\u{fe0f}",
+    "\u{270f}\u{fe0f}",
+    "\u{2712}\u{fe0f}",
+    "\u{2714}\u{fe0f}",
+    "\u{2716}\u{fe0f}",
+    "\u{271d}\u{fe0f}",
+    "\u{2721}\u{fe0f}",
+    "\u{2733}\u{fe0f}",
+    "\u{2734}\u{fe0f}",
+    "\u{2744}\u{fe0f}",
+    "\u{2747}\u{fe0f}",
+    "\u{2763}\u{fe0f}",
+    "\u{2764}\u{fe0f}",
+    "\u{27a1}\u{fe0f}",
+    "\u{2934}\u{fe0f}",
+    "\u{2935}\u{fe0f}",
+    "\u{2b05}\u{fe0f}",
+    "\u{2b06}\u{fe0f}",
+    "\u{2b07}\u{fe0f}",
+    "\u{3030}\u{fe0f}",
+    "\u{303d}\u{fe0f}",
+    "\u{3297}\u{fe0f}",
+    "\u{3299}\u{fe0f}",
+    "\u{1f170}\u{fe0f}",
+    "\u{1f171}\u{fe0f}",
+    "\u{1f17e}\u{fe0f}",
+    "\u{1f17f}\u{fe0f}",
+    "\u{1f202}\u{fe0f}",
+    "\u{1f237}\u{fe0f}",
+    "\u{1f321}\u{fe0f}",
+    "\u{1f324}\u{fe0f}",
+    "\u{1f325}\u{fe0f}",
+    "\u{1f326}\u{fe0f}",
+    "\u{1f327}\u{fe0f}",
+    "\u{1f328}\u{fe0f}",
+    "\u{1f329}\u{fe0f}",
+    "\u{1f32a}\u{fe0f}",
+    "\u{1f32b}\u{fe0f}",
+    "\u{1f32c}\u{fe0f}",
+    "\u{1f336}\u{fe0f}",
+    "\u{1f37d}\u{fe0f}",
+    "\u{1f396}\u{fe0f}",
+    "\u{1f397}\u{fe0f}",
+    "\u{1f399}\u{fe0f}",
+    "\u{1f39a}\u{fe0f}",
+    "\u{1f39b}\u{fe0f}",
+    "\u{1f39e}\u{fe0f}",
+    "\u{1f39f}\u{fe0f}",
+    "\u{1f3cb}\u{fe0f}",
+    "\u{1f3cc}\u{fe0f}",
+    "\u{1f3cd}\u{fe0f}",
+    "\u{1f3ce}\u{fe0f}",
+    "\u{1f3d4}\u{fe0f}",
+    "\u{1f3d5}\u{fe0f}",
+    "\u{1f3d6}\u{fe0f}",
+    "\u{1f3d7}\u{fe0f}",
+    "\u{1f3d8}\u{fe0f}",
+    "\u{1f3d9}\u{fe0f}",
+    "\u{1f3da}\u{fe0f}",
+    "\u{1f3db}\u{fe0f}",
+    "\u{1f3dc}\u{fe0f}",
+    "\u{1f3dd}\u{fe0f}",
+    "\u{1f3de}\u{fe0f}",
+    "\u{1f3df}\u{fe0f}",
+    "\u{1f3f3}\u{fe0f}",
+    "\u{1f3f5}\u{fe0f}",
+    "\u{1f3f7}\u{fe0f}",
+    "\u{1f43f}\u{fe0f}",
+    "\u{1f441}\u{fe0f}",
+    "\u{1f4fd}\u{fe0f}",
+    "\u{1f549}\u{fe0f}",
+    "\u{1f54a}\u{fe0f}",
+    "\u{1f56f}\u{fe0f}",
+    "\u{1f570}\u{fe0f}",
+    "\u{1f573}\u{fe0f}",
+    "\u{1f574}\u{fe0f}",
+    "\u{1f575}\u{fe0f}",
+    "\u{1f576}\u{fe0f}",
+    "\u{1f577}\u{fe0f}",
+    "\u{1f578}\u{fe0f}",
+    "\u{1f579}\u{fe0f}",
+    "\u{1f587}\u{fe0f}",
+    "\u{1f58a}\u{fe0f}",
+    "\u{1f58b}\u{fe0f}",
+    "\u{1f58c}\u{fe0f}",
+    "\u{1f58d}\u{fe0f}",
+    "\u{1f590}\u{fe0f}",
+    "\u{1f5a5}\u{fe0f}",
+    "\u{1f5a8}\u{fe0f}",
+    "\u{1f5b1}\u{fe0f}",
+    "\u{1f5b2}\u{fe0f}",
+    "\u{1f5bc}\u{fe0f}",
+    "\u{1f5c2}\u{fe0f}",
+    "\u{1f5c3}\u{fe0f}",
+    "\u{1f5c4}\u{fe0f}",
+    "\u{1f5d1}\u{fe0f}",
+    "\u{1f5d2}\u{fe0f}",
+    "\u{1f5d3}\u{fe0f}",
+    "\u{1f5dc}\u{fe0f}",
+    "\u{1f5dd}\u{fe0f}",
+    "\u{1f5de}\u{fe0f}",
+    "\u{1f5e1}\u{fe0f}",
+    "\u{1f5e3}\u{fe0f}",
+    "\u{1f5e8}\u{fe0f}",
+    "\u{1f5ef}\u{fe0f}",
+    "\u{1f5f3}\u{fe0f}",
+    "\u{1f5fa}\u{fe0f}",
+    "\u{1f6cb}\u{fe0f}",
+    "\u{1f6cd}\u{fe0f}",
+    "\u{1f6ce}\u{fe0f}",
+    "\u{1f6cf}\u{fe0f}",
+    "\u{1f6e0}\u{fe0f}",
+    "\u{1f6e1}\u{fe0f}",
+    "\u{1f6e2}\u{fe0f}",
+    "\u{1f6e3}\u{fe0f}",
+    "\u{1f6e4}\u{fe0f}",
+    "\u{1f6e5}\u{fe0f}",
+    "\u{1f6e9}\u{fe0f}",
+    "\u{1f6f0}\u{fe0f}",
+    "\u{1f6f3}\u{fe0f}",
+];
+
+pub const TAG: &'static [&'static str] = &[
+    "\u{1f3f4}\u{e0067}\u{e0062}\u{e0065}\u{e006e}\u{e0067}\u{e007f}",
+    "\u{1f3f4}\u{e0067}\u{e0062}\u{e0073}\u{e0063}\u{e0074}\u{e007f}",
+    "\u{1f3f4}\u{e0067}\u{e0062}\u{e0077}\u{e006c}\u{e0073}\u{e007f}",
+];
+
+pub const ZWJ: &'static [&'static str] = &[
+    "\u{26f9}\u{fe0f}\u{200d}\u{2640}\u{fe0f}",
+    "\u{26f9}\u{fe0f}\u{200d}\u{2642}\u{fe0f}",
+    "\u{26f9}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{26f9}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{26f9}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{26f9}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{26f9}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{26f9}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{26f9}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{26f9}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{26f9}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{26f9}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f3c3}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f3c3}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f3c3}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f3c3}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f3c3}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f3c3}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f3c3}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f3c3}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f3c3}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f3c3}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f3c3}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f3c3}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f3c4}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f3c4}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f3c4}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f3c4}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f3c4}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f3c4}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f3c4}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f3c4}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f3c4}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f3c4}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f3c4}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f3c4}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f3ca}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f3ca}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f3ca}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f3ca}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f3ca}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f3ca}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f3ca}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f3ca}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f3ca}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f3ca}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f3ca}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f3ca}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f3cb}\u{fe0f}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f3cb}\u{fe0f}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f3cb}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f3cb}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f3cb}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f3cb}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f3cb}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f3cb}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f3cb}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f3cb}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f3cb}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f3cb}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f3cc}\u{fe0f}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f3cc}\u{fe0f}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f3cc}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f3cc}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f3cc}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f3cc}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f3cc}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f3cc}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f3cc}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f3cc}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f3cc}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f3cc}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f3f3}\u{fe0f}\u{200d}\u{1f308}",
+    "\u{1f3f4}\u{200d}\u{2620}\u{fe0f}",
+    "\u{1f441}\u{fe0f}\u{200d}\u{1f5e8}\u{fe0f}",
+    "\u{1f468}\u{200d}\u{2695}\u{fe0f}",
+    "\u{1f468}\u{200d}\u{2696}\u{fe0f}",
+    "\u{1f468}\u{200d}\u{2708}\u{fe0f}",
+    "\u{1f468}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}",
+    "\u{1f468}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}",
+    "\u{1f468}\u{200d}\u{1f33e}",
+    "\u{1f468}\u{200d}\u{1f373}",
+    "\u{1f468}\u{200d}\u{1f393}",
+    "\u{1f468}\u{200d}\u{1f3a4}",
+    "\u{1f468}\u{200d}\u{1f3a8}",
+    "\u{1f468}\u{200d}\u{1f3eb}",
+    "\u{1f468}\u{200d}\u{1f3ed}",
+    "\u{1f468}\u{200d}\u{1f466}",
+    "\u{1f468}\u{200d}\u{1f466}\u{200d}\u{1f466}",
+    "\u{1f468}\u{200d}\u{1f467}",
+    "\u{1f468}\u{200d}\u{1f467}\u{200d}\u{1f466}",
+    "\u{1f468}\u{200d}\u{1f467}\u{200d}\u{1f467}",
+    "\u{1f468}\u{200d}\u{1f468}\u{200d}\u{1f466}",
+    "\u{1f468}\u{200d}\u{1f468}\u{200d}\u{1f466}\u{200d}\u{1f466}",
+    "\u{1f468}\u{200d}\u{1f468}\u{200d}\u{1f467}",
+    "\u{1f468}\u{200d}\u{1f468}\u{200d}\u{1f467}\u{200d}\u{1f466}",
+    "\u{1f468}\u{200d}\u{1f468}\u{200d}\u{1f467}\u{200d}\u{1f467}",
+    "\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f466}",
+    "\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f466}\u{200d}\u{1f466}",
+    "\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}",
+    "\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}\u{200d}\u{1f466}",
+    "\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}\u{200d}\u{1f467}",
+    "\u{1f468}\u{200d}\u{1f4bb}",
+    "\u{1f468}\u{200d}\u{1f4bc}",
+    "\u{1f468}\u{200d}\u{1f527}",
+    "\u{1f468}\u{200d}\u{1f52c}",
+    "\u{1f468}\u{200d}\u{1f680}",
+    "\u{1f468}\u{200d}\u{1f692}",
+    "\u{1f468}\u{200d}\u{1f9b0}",
+    "\u{1f468}\u{200d}\u{1f9b1}",
+    "\u{1f468}\u{200d}\u{1f9b2}",
+    "\u{1f468}\u{200d}\u{1f9b3}",
+    "\u{1f468}\u{1f3fb}\u{200d}\u{2695}\u{fe0f}",
+    "\u{1f468}\u{1f3fb}\u{200d}\u{2696}\u{fe0f}",
+    "\u{1f468}\u{1f3fb}\u{200d}\u{2708}\u{fe0f}",
+    "\u{1f468}\u{1f3fb}\u{200d}\u{1f33e}",
+    "\u{1f468}\u{1f3fb}\u{200d}\u{1f373}",
+    "\u{1f468}\u{1f3fb}\u{200d}\u{1f393}",
+    "\u{1f468}\u{1f3fb}\u{200d}\u{1f3a4}",
+    "\u{1f468}\u{1f3fb}\u{200d}\u{1f3a8}",
+    "\u{1f468}\u{1f3fb}\u{200d}\u{1f3eb}",
+    "\u{1f468}\u{1f3fb}\u{200d}\u{1f3ed}",
+    "\u{1f468}\u{1f3fb}\u{200d}\u{1f4bb}",
+    "\u{1f468}\u{1f3fb}\u{200d}\u{1f4bc}",
+    "\u{1f468}\u{1f3fb}\u{200d}\u{1f527}",
+    "\u{1f468}\u{1f3fb}\u{200d}\u{1f52c}",
+    "\u{1f468}\u{1f3fb}\u{200d}\u{1f680}",
+    "\u{1f468}\u{1f3fb}\u{200d}\u{1f692}",
+    "\u{1f468}\u{1f3fb}\u{200d}\u{1f9b0}",
+    "\u{1f468}\u{1f3fb}\u{200d}\u{1f9b1}",
+    "\u{1f468}\u{1f3fb}\u{200d}\u{1f9b2}",
+    "\u{1f468}\u{1f3fb}\u{200d}\u{1f9b3}",
+    "\u{1f468}\u{1f3fc}\u{200d}\u{2695}\u{fe0f}",
+    "\u{1f468}\u{1f3fc}\u{200d}\u{2696}\u{fe0f}",
+    "\u{1f468}\u{1f3fc}\u{200d}\u{2708}\u{fe0f}",
+    "\u{1f468}\u{1f3fc}\u{200d}\u{1f33e}",
+    "\u{1f468}\u{1f3fc}\u{200d}\u{1f373}",
+    "\u{1f468}\u{1f3fc}\u{200d}\u{1f393}",
+    "\u{1f468}\u{1f3fc}\u{200d}\u{1f3a4}",
+    "\u{1f468}\u{1f3fc}\u{200d}\u{1f3a8}",
+    "\u{1f468}\u{1f3fc}\u{200d}\u{1f3eb}",
+    "\u{1f468}\u{1f3fc}\u{200d}\u{1f3ed}",
+    "\u{1f468}\u{1f3fc}\u{200d}\u{1f4bb}",
+    "\u{1f468}\u{1f3fc}\u{200d}\u{1f4bc}",
+    "\u{1f468}\u{1f3fc}\u{200d}\u{1f527}",
+    "\u{1f468}\u{1f3fc}\u{200d}\u{1f52c}",
+    "\u{1f468}\u{1f3fc}\u{200d}\u{1f680}",
+    "\u{1f468}\u{1f3fc}\u{200d}\u{1f692}",
+    "\u{1f468}\u{1f3fc}\u{200d}\u{1f9b0}",
+    "\u{1f468}\u{1f3fc}\u{200d}\u{1f9b1}",
+    "\u{1f468}\u{1f3fc}\u{200d}\u{1f9b2}",
+    "\u{1f468}\u{1f3fc}\u{200d}\u{1f9b3}",
+    "\u{1f468}\u{1f3fd}\u{200d}\u{2695}\u{fe0f}",
+    "\u{1f468}\u{1f3fd}\u{200d}\u{2696}\u{fe0f}",
+    "\u{1f468}\u{1f3fd}\u{200d}\u{2708}\u{fe0f}",
+    "\u{1f468}\u{1f3fd}\u{200d}\u{1f33e}",
+    "\u{1f468}\u{1f3fd}\u{200d}\u{1f373}",
+    "\u{1f468}\u{1f3fd}\u{200d}\u{1f393}",
+    "\u{1f468}\u{1f3fd}\u{200d}\u{1f3a4}",
+    "\u{1f468}\u{1f3fd}\u{200d}\u{1f3a8}",
+    "\u{1f468}\u{1f3fd}\u{200d}\u{1f3eb}",
+    "\u{1f468}\u{1f3fd}\u{200d}\u{1f3ed}",
+    "\u{1f468}\u{1f3fd}\u{200d}\u{1f4bb}",
+    "\u{1f468}\u{1f3fd}\u{200d}\u{1f4bc}",
+    "\u{1f468}\u{1f3fd}\u{200d}\u{1f527}",
+    "\u{1f468}\u{1f3fd}\u{200d}\u{1f52c}",
+    "\u{1f468}\u{1f3fd}\u{200d}\u{1f680}",
+    "\u{1f468}\u{1f3fd}\u{200d}\u{1f692}",
+    "\u{1f468}\u{1f3fd}\u{200d}\u{1f9b0}",
+    "\u{1f468}\u{1f3fd}\u{200d}\u{1f9b1}",
+    "\u{1f468}\u{1f3fd}\u{200d}\u{1f9b2}",
+    "\u{1f468}\u{1f3fd}\u{200d}\u{1f9b3}",
+    "\u{1f468}\u{1f3fe}\u{200d}\u{2695}\u{fe0f}",
+    "\u{1f468}\u{1f3fe}\u{200d}\u{2696}\u{fe0f}",
+    "\u{1f468}\u{1f3fe}\u{200d}\u{2708}\u{fe0f}",
+    "\u{1f468}\u{1f3fe}\u{200d}\u{1f33e}",
+    "\u{1f468}\u{1f3fe}\u{200d}\u{1f373}",
+    "\u{1f468}\u{1f3fe}\u{200d}\u{1f393}",
+    "\u{1f468}\u{1f3fe}\u{200d}\u{1f3a4}",
+    "\u{1f468}\u{1f3fe}\u{200d}\u{1f3a8}",
+    "\u{1f468}\u{1f3fe}\u{200d}\u{1f3eb}",
+    "\u{1f468}\u{1f3fe}\u{200d}\u{1f3ed}",
+    "\u{1f468}\u{1f3fe}\u{200d}\u{1f4bb}",
+    "\u{1f468}\u{1f3fe}\u{200d}\u{1f4bc}",
+    "\u{1f468}\u{1f3fe}\u{200d}\u{1f527}",
+    "\u{1f468}\u{1f3fe}\u{200d}\u{1f52c}",
+    "\u{1f468}\u{1f3fe}\u{200d}\u{1f680}",
+    "\u{1f468}\u{1f3fe}\u{200d}\u{1f692}",
+    "\u{1f468}\u{1f3fe}\u{200d}\u{1f9b0}",
+    "\u{1f468}\u{1f3fe}\u{200d}\u{1f9b1}",
+    "\u{1f468}\u{1f3fe}\u{200d}\u{1f9b2}",
+    "\u{1f468}\u{1f3fe}\u{200d}\u{1f9b3}",
+    "\u{1f468}\u{1f3ff}\u{200d}\u{2695}\u{fe0f}",
+    "\u{1f468}\u{1f3ff}\u{200d}\u{2696}\u{fe0f}",
+    "\u{1f468}\u{1f3ff}\u{200d}\u{2708}\u{fe0f}",
+    "\u{1f468}\u{1f3ff}\u{200d}\u{1f33e}",
+    "\u{1f468}\u{1f3ff}\u{200d}\u{1f373}",
+    "\u{1f468}\u{1f3ff}\u{200d}\u{1f393}",
+    "\u{1f468}\u{1f3ff}\u{200d}\u{1f3a4}",
+    "\u{1f468}\u{1f3ff}\u{200d}\u{1f3a8}",
+    "\u{1f468}\u{1f3ff}\u{200d}\u{1f3eb}",
+    "\u{1f468}\u{1f3ff}\u{200d}\u{1f3ed}",
+    "\u{1f468}\u{1f3ff}\u{200d}\u{1f4bb}",
+    "\u{1f468}\u{1f3ff}\u{200d}\u{1f4bc}",
+    "\u{1f468}\u{1f3ff}\u{200d}\u{1f527}",
+    "\u{1f468}\u{1f3ff}\u{200d}\u{1f52c}",
+    "\u{1f468}\u{1f3ff}\u{200d}\u{1f680}",
+    "\u{1f468}\u{1f3ff}\u{200d}\u{1f692}",
+    "\u{1f468}\u{1f3ff}\u{200d}\u{1f9b0}",
+    "\u{1f468}\u{1f3ff}\u{200d}\u{1f9b1}",
+    "\u{1f468}\u{1f3ff}\u{200d}\u{1f9b2}",
+    "\u{1f468}\u{1f3ff}\u{200d}\u{1f9b3}",
+    "\u{1f469}\u{200d}\u{2695}\u{fe0f}",
+    "\u{1f469}\u{200d}\u{2696}\u{fe0f}",
+    "\u{1f469}\u{200d}\u{2708}\u{fe0f}",
+    "\u{1f469}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}",
+    "\u{1f469}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}",
+    "\u{1f469}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}",
+    "\u{1f469}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}",
+    "\u{1f469}\u{200d}\u{1f33e}",
+    "\u{1f469}\u{200d}\u{1f373}",
+    "\u{1f469}\u{200d}\u{1f393}",
+    "\u{1f469}\u{200d}\u{1f3a4}",
+    "\u{1f469}\u{200d}\u{1f3a8}",
+    "\u{1f469}\u{200d}\u{1f3eb}",
+    "\u{1f469}\u{200d}\u{1f3ed}",
+    "\u{1f469}\u{200d}\u{1f466}",
+    "\u{1f469}\u{200d}\u{1f466}\u{200d}\u{1f466}",
+    "\u{1f469}\u{200d}\u{1f467}",
+    "\u{1f469}\u{200d}\u{1f467}\u{200d}\u{1f466}",
+    "\u{1f469}\u{200d}\u{1f467}\u{200d}\u{1f467}",
+    "\u{1f469}\u{200d}\u{1f469}\u{200d}\u{1f466}",
+    "\u{1f469}\u{200d}\u{1f469}\u{200d}\u{1f466}\u{200d}\u{1f466}",
+    "\u{1f469}\u{200d}\u{1f469}\u{200d}\u{1f467}",
+    "\u{1f469}\u{200d}\u{1f469}\u{200d}\u{1f467}\u{200d}\u{1f466}",
+    "\u{1f469}\u{200d}\u{1f469}\u{200d}\u{1f467}\u{200d}\u{1f467}",
+    "\u{1f469}\u{200d}\u{1f4bb}",
+    "\u{1f469}\u{200d}\u{1f4bc}",
+    "\u{1f469}\u{200d}\u{1f527}",
+    "\u{1f469}\u{200d}\u{1f52c}",
+    "\u{1f469}\u{200d}\u{1f680}",
+    "\u{1f469}\u{200d}\u{1f692}",
+    "\u{1f469}\u{200d}\u{1f9b0}",
+    "\u{1f469}\u{200d}\u{1f9b1}",
+    "\u{1f469}\u{200d}\u{1f9b2}",
+    "\u{1f469}\u{200d}\u{1f9b3}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{2695}\u{fe0f}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{2696}\u{fe0f}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{2708}\u{fe0f}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{1f33e}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{1f373}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{1f393}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{1f3a4}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{1f3a8}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{1f3eb}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{1f3ed}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{1f4bb}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{1f4bc}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{1f527}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{1f52c}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{1f680}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{1f692}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{1f9b0}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{1f9b1}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{1f9b2}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{1f9b3}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{2695}\u{fe0f}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{2696}\u{fe0f}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{2708}\u{fe0f}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{1f33e}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{1f373}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{1f393}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{1f3a4}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{1f3a8}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{1f3eb}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{1f3ed}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{1f4bb}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{1f4bc}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{1f527}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{1f52c}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{1f680}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{1f692}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{1f9b0}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{1f9b1}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{1f9b2}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{1f9b3}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{2695}\u{fe0f}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{2696}\u{fe0f}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{2708}\u{fe0f}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{1f33e}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{1f373}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{1f393}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{1f3a4}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{1f3a8}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{1f3eb}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{1f3ed}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{1f4bb}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{1f4bc}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{1f527}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{1f52c}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{1f680}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{1f692}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{1f9b0}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{1f9b1}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{1f9b2}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{1f9b3}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{2695}\u{fe0f}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{2696}\u{fe0f}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{2708}\u{fe0f}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{1f33e}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{1f373}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{1f393}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{1f3a4}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{1f3a8}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{1f3eb}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{1f3ed}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{1f4bb}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{1f4bc}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{1f527}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{1f52c}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{1f680}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{1f692}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{1f9b0}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{1f9b1}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{1f9b2}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{1f9b3}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{2695}\u{fe0f}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{2696}\u{fe0f}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{2708}\u{fe0f}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{1f33e}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{1f373}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{1f393}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{1f3a4}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{1f3a8}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{1f3eb}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{1f3ed}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{1f4bb}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{1f4bc}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{1f527}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{1f52c}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{1f680}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{1f692}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{1f9b0}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{1f9b1}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{1f9b2}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{1f9b3}",
+    "\u{1f46e}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f46e}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f46e}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f46e}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f46e}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f46e}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f46e}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f46e}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f46e}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f46e}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f46e}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f46e}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f46f}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f46f}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f471}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f471}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f471}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f471}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f471}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f471}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f471}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f471}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f471}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f471}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f471}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f471}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f473}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f473}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f473}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f473}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f473}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f473}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f473}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f473}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f473}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f473}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f473}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f473}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f477}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f477}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f477}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f477}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f477}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f477}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f477}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f477}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f477}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f477}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f477}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f477}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f481}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f481}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f481}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f481}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f481}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f481}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f481}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f481}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f481}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f481}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f481}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f481}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f482}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f482}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f482}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f482}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f482}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f482}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f482}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f482}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f482}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f482}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f482}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f482}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f486}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f486}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f486}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f486}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f486}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f486}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f486}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f486}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f486}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f486}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f486}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f486}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f487}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f487}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f487}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f487}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f487}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f487}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f487}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f487}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f487}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f487}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f487}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f487}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f575}\u{fe0f}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f575}\u{fe0f}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f575}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f575}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f575}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f575}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f575}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f575}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f575}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f575}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f575}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f575}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f645}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f645}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f645}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f645}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f645}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f645}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f645}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f645}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f645}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f645}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f645}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f645}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f646}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f646}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f646}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f646}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f646}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f646}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f646}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f646}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f646}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f646}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f646}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f646}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f647}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f647}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f647}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f647}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f647}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f647}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f647}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f647}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f647}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f647}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f647}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f647}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f64b}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f64b}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f64b}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f64b}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f64b}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f64b}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f64b}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f64b}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f64b}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f64b}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f64b}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f64b}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f64d}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f64d}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f64d}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f64d}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f64d}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f64d}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f64d}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f64d}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f64d}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f64d}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f64d}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f64d}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f64e}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f64e}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f64e}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f64e}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f64e}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f64e}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f64e}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f64e}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f64e}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f64e}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f64e}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f64e}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f6a3}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f6a3}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f6a3}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f6a3}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f6a3}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f6a3}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f6a3}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f6a3}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f6a3}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f6a3}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f6a3}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f6a3}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f6b4}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f6b4}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f6b4}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f6b4}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f6b4}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f6b4}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f6b4}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f6b4}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f6b4}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f6b4}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f6b4}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f6b4}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f6b5}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f6b5}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f6b5}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f6b5}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f6b5}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f6b5}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f6b5}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f6b5}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f6b5}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f6b5}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f6b5}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f6b5}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f6b6}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f6b6}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f6b6}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f6b6}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f6b6}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f6b6}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f6b6}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f6b6}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f6b6}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f6b6}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f6b6}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f6b6}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f926}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f926}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f926}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f926}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f926}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f926}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f926}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f926}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f926}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f926}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f926}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f926}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f937}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f937}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f937}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f937}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f937}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f937}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f937}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f937}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f937}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f937}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f937}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f937}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f938}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f938}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f938}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f938}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f938}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f938}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f938}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f938}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f938}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f938}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f938}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f938}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f939}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f939}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f939}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f939}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f939}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f939}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f939}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f939}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f939}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f939}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f939}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f939}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f93c}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f93c}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f93d}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f93d}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f93d}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f93d}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f93d}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f93d}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f93d}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f93d}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f93d}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f93d}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f93d}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f93d}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f93e}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f93e}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f93e}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f93e}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f93e}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f93e}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f93e}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f93e}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f93e}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f93e}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f93e}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f93e}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9b8}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9b8}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9b8}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9b8}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9b8}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9b8}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9b8}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9b8}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9b8}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9b8}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9b8}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9b8}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9b9}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9b9}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9b9}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9b9}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9b9}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9b9}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9b9}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9b9}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9b9}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9b9}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9b9}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9b9}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9d6}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9d6}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9d6}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9d6}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9d6}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9d6}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9d6}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9d6}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9d6}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9d6}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9d6}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9d6}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9d7}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9d7}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9d7}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9d7}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9d7}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9d7}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9d7}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9d7}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9d7}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9d7}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9d7}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9d7}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9d8}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9d8}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9d8}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9d8}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9d8}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9d8}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9d8}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9d8}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9d8}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9d8}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9d8}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9d8}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9d9}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9d9}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9d9}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9d9}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9d9}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9d9}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9d9}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9d9}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9d9}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9d9}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9d9}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9d9}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9da}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9da}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9da}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9da}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9da}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9da}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9da}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9da}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9da}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9da}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9da}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9da}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9db}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9db}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9db}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9db}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9db}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9db}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9db}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9db}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9db}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9db}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9db}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9db}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9dc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9dc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9dc}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9dc}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9dc}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9dc}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9dc}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9dc}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9dc}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9dc}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9dc}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9dc}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9dd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9dd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9dd}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9dd}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9dd}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9dd}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9dd}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9dd}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9dd}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9dd}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9dd}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9dd}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9de}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9de}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9df}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9df}\u{200d}\u{2642}\u{fe0f}",
+];
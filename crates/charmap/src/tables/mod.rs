@@ -1,3 +1,9 @@
+pub mod bidi_class;
+pub mod case;
+pub mod east_asian_width;
+pub mod emoji_sequences;
+pub mod grapheme_cluster_break;
+pub mod quick_check;
 pub mod v10_0_0;
 pub mod v11_0_0;
 pub mod v12_0_0;
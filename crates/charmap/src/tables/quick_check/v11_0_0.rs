@@ -0,0 +1,1063 @@
+// DO NOT EDIT THIS FILE. IT WAS AUTOMATICALLY GENERATED BY:
+//
+//   python generate_tables.py 11.0.0
+//
+// Unicode version: 11.0.0.
+
+pub const BY_NAME: &'static [(&'static str, &'static [(u32, u32)])] = &[
+    ("NFC_M", NFC_MAYBE),
+    ("NFC_N", NFC_NO),
+    ("NFD_M", NFD_MAYBE),
+    ("NFD_N", NFD_NO),
+    ("NFKC_M", NFKC_MAYBE),
+    ("NFKC_N", NFKC_NO),
+    ("NFKD_M", NFKD_MAYBE),
+    ("NFKD_N", NFKD_NO),
+];
+
+pub const NFC_MAYBE: &'static [(u32, u32)] = &[
+    (768, 772),
+    (774, 780),
+    (783, 783),
+    (785, 785),
+    (787, 788),
+    (795, 795),
+    (803, 808),
+    (813, 814),
+    (816, 817),
+    (824, 824),
+    (834, 834),
+    (837, 837),
+    (1619, 1621),
+    (2364, 2364),
+    (2494, 2494),
+    (2519, 2519),
+    (2878, 2878),
+    (2902, 2903),
+    (3006, 3006),
+    (3031, 3031),
+    (3158, 3158),
+    (3266, 3266),
+    (3285, 3286),
+    (3390, 3390),
+    (3415, 3415),
+    (3530, 3530),
+    (3535, 3535),
+    (3551, 3551),
+    (4142, 4142),
+    (4449, 4469),
+    (4520, 4546),
+    (6965, 6965),
+    (12441, 12442),
+    (69818, 69818),
+    (69927, 69927),
+    (70462, 70462),
+    (70487, 70487),
+    (70832, 70832),
+    (70842, 70842),
+    (70845, 70845),
+    (71087, 71087),
+];
+
+pub const NFC_NO: &'static [(u32, u32)] = &[
+    (832, 833),
+    (835, 836),
+    (884, 884),
+    (894, 894),
+    (903, 903),
+    (2392, 2399),
+    (2524, 2525),
+    (2527, 2527),
+    (2611, 2611),
+    (2614, 2614),
+    (2649, 2651),
+    (2654, 2654),
+    (2908, 2909),
+    (3907, 3907),
+    (3917, 3917),
+    (3922, 3922),
+    (3927, 3927),
+    (3932, 3932),
+    (3945, 3945),
+    (3955, 3955),
+    (3957, 3958),
+    (3960, 3960),
+    (3969, 3969),
+    (3987, 3987),
+    (3997, 3997),
+    (4002, 4002),
+    (4007, 4007),
+    (4012, 4012),
+    (4025, 4025),
+    (8049, 8049),
+    (8051, 8051),
+    (8053, 8053),
+    (8055, 8055),
+    (8057, 8057),
+    (8059, 8059),
+    (8061, 8061),
+    (8123, 8123),
+    (8126, 8126),
+    (8137, 8137),
+    (8139, 8139),
+    (8147, 8147),
+    (8155, 8155),
+    (8163, 8163),
+    (8171, 8171),
+    (8174, 8175),
+    (8185, 8185),
+    (8187, 8187),
+    (8189, 8189),
+    (8192, 8193),
+    (8486, 8486),
+    (8490, 8491),
+    (9001, 9002),
+    (10972, 10972),
+    (63744, 64013),
+    (64016, 64016),
+    (64018, 64018),
+    (64021, 64030),
+    (64032, 64032),
+    (64034, 64034),
+    (64037, 64038),
+    (64042, 64109),
+    (64112, 64217),
+    (64285, 64285),
+    (64287, 64287),
+    (64298, 64310),
+    (64312, 64316),
+    (64318, 64318),
+    (64320, 64321),
+    (64323, 64324),
+    (64326, 64334),
+    (119134, 119140),
+    (119227, 119232),
+    (194560, 195101),
+];
+
+pub const NFD_MAYBE: &'static [(u32, u32)] = &[];
+
+pub const NFD_NO: &'static [(u32, u32)] = &[
+    (192, 197),
+    (199, 207),
+    (209, 214),
+    (217, 221),
+    (224, 229),
+    (231, 239),
+    (241, 246),
+    (249, 253),
+    (255, 271),
+    (274, 293),
+    (296, 304),
+    (308, 311),
+    (313, 318),
+    (323, 328),
+    (332, 337),
+    (340, 357),
+    (360, 382),
+    (416, 417),
+    (431, 432),
+    (461, 476),
+    (478, 483),
+    (486, 496),
+    (500, 501),
+    (504, 539),
+    (542, 543),
+    (550, 563),
+    (832, 833),
+    (835, 836),
+    (884, 884),
+    (894, 894),
+    (901, 906),
+    (908, 908),
+    (910, 912),
+    (938, 944),
+    (970, 974),
+    (979, 980),
+    (1024, 1025),
+    (1027, 1027),
+    (1031, 1031),
+    (1036, 1038),
+    (1049, 1049),
+    (1081, 1081),
+    (1104, 1105),
+    (1107, 1107),
+    (1111, 1111),
+    (1116, 1118),
+    (1142, 1143),
+    (1217, 1218),
+    (1232, 1235),
+    (1238, 1239),
+    (1242, 1247),
+    (1250, 1255),
+    (1258, 1269),
+    (1272, 1273),
+    (1570, 1574),
+    (1728, 1728),
+    (1730, 1730),
+    (1747, 1747),
+    (2345, 2345),
+    (2353, 2353),
+    (2356, 2356),
+    (2392, 2399),
+    (2507, 2508),
+    (2524, 2525),
+    (2527, 2527),
+    (2611, 2611),
+    (2614, 2614),
+    (2649, 2651),
+    (2654, 2654),
+    (2888, 2888),
+    (2891, 2892),
+    (2908, 2909),
+    (2964, 2964),
+    (3018, 3020),
+    (3144, 3144),
+    (3264, 3264),
+    (3271, 3272),
+    (3274, 3275),
+    (3402, 3404),
+    (3546, 3546),
+    (3548, 3550),
+    (3907, 3907),
+    (3917, 3917),
+    (3922, 3922),
+    (3927, 3927),
+    (3932, 3932),
+    (3945, 3945),
+    (3955, 3955),
+    (3957, 3958),
+    (3960, 3960),
+    (3969, 3969),
+    (3987, 3987),
+    (3997, 3997),
+    (4002, 4002),
+    (4007, 4007),
+    (4012, 4012),
+    (4025, 4025),
+    (4134, 4134),
+    (6918, 6918),
+    (6920, 6920),
+    (6922, 6922),
+    (6924, 6924),
+    (6926, 6926),
+    (6930, 6930),
+    (6971, 6971),
+    (6973, 6973),
+    (6976, 6977),
+    (6979, 6979),
+    (7680, 7833),
+    (7835, 7835),
+    (7840, 7929),
+    (7936, 7957),
+    (7960, 7965),
+    (7968, 8005),
+    (8008, 8013),
+    (8016, 8023),
+    (8025, 8025),
+    (8027, 8027),
+    (8029, 8029),
+    (8031, 8061),
+    (8064, 8116),
+    (8118, 8124),
+    (8126, 8126),
+    (8129, 8132),
+    (8134, 8147),
+    (8150, 8155),
+    (8157, 8175),
+    (8178, 8180),
+    (8182, 8189),
+    (8192, 8193),
+    (8486, 8486),
+    (8490, 8491),
+    (8602, 8603),
+    (8622, 8622),
+    (8653, 8655),
+    (8708, 8708),
+    (8713, 8713),
+    (8716, 8716),
+    (8740, 8740),
+    (8742, 8742),
+    (8769, 8769),
+    (8772, 8772),
+    (8775, 8775),
+    (8777, 8777),
+    (8800, 8800),
+    (8802, 8802),
+    (8813, 8817),
+    (8820, 8821),
+    (8824, 8825),
+    (8832, 8833),
+    (8836, 8837),
+    (8840, 8841),
+    (8876, 8879),
+    (8928, 8931),
+    (8938, 8941),
+    (9001, 9002),
+    (10972, 10972),
+    (12364, 12364),
+    (12366, 12366),
+    (12368, 12368),
+    (12370, 12370),
+    (12372, 12372),
+    (12374, 12374),
+    (12376, 12376),
+    (12378, 12378),
+    (12380, 12380),
+    (12382, 12382),
+    (12384, 12384),
+    (12386, 12386),
+    (12389, 12389),
+    (12391, 12391),
+    (12393, 12393),
+    (12400, 12401),
+    (12403, 12404),
+    (12406, 12407),
+    (12409, 12410),
+    (12412, 12413),
+    (12436, 12436),
+    (12446, 12446),
+    (12460, 12460),
+    (12462, 12462),
+    (12464, 12464),
+    (12466, 12466),
+    (12468, 12468),
+    (12470, 12470),
+    (12472, 12472),
+    (12474, 12474),
+    (12476, 12476),
+    (12478, 12478),
+    (12480, 12480),
+    (12482, 12482),
+    (12485, 12485),
+    (12487, 12487),
+    (12489, 12489),
+    (12496, 12497),
+    (12499, 12500),
+    (12502, 12503),
+    (12505, 12506),
+    (12508, 12509),
+    (12532, 12532),
+    (12535, 12538),
+    (12542, 12542),
+    (44032, 55203),
+    (63744, 64013),
+    (64016, 64016),
+    (64018, 64018),
+    (64021, 64030),
+    (64032, 64032),
+    (64034, 64034),
+    (64037, 64038),
+    (64042, 64109),
+    (64112, 64217),
+    (64285, 64285),
+    (64287, 64287),
+    (64298, 64310),
+    (64312, 64316),
+    (64318, 64318),
+    (64320, 64321),
+    (64323, 64324),
+    (64326, 64334),
+    (69786, 69786),
+    (69788, 69788),
+    (69803, 69803),
+    (69934, 69935),
+    (70475, 70476),
+    (70843, 70844),
+    (70846, 70846),
+    (71098, 71099),
+    (119134, 119140),
+    (119227, 119232),
+    (194560, 195101),
+];
+
+pub const NFKC_MAYBE: &'static [(u32, u32)] = &[
+    (768, 772),
+    (774, 780),
+    (783, 783),
+    (785, 785),
+    (787, 788),
+    (795, 795),
+    (803, 808),
+    (813, 814),
+    (816, 817),
+    (824, 824),
+    (834, 834),
+    (837, 837),
+    (1619, 1621),
+    (2364, 2364),
+    (2494, 2494),
+    (2519, 2519),
+    (2878, 2878),
+    (2902, 2903),
+    (3006, 3006),
+    (3031, 3031),
+    (3158, 3158),
+    (3266, 3266),
+    (3285, 3286),
+    (3390, 3390),
+    (3415, 3415),
+    (3530, 3530),
+    (3535, 3535),
+    (3551, 3551),
+    (4142, 4142),
+    (4449, 4469),
+    (4520, 4546),
+    (6965, 6965),
+    (12441, 12442),
+    (69818, 69818),
+    (69927, 69927),
+    (70462, 70462),
+    (70487, 70487),
+    (70832, 70832),
+    (70842, 70842),
+    (70845, 70845),
+    (71087, 71087),
+];
+
+pub const NFKC_NO: &'static [(u32, u32)] = &[
+    (160, 160),
+    (168, 168),
+    (170, 170),
+    (175, 175),
+    (178, 181),
+    (184, 186),
+    (188, 190),
+    (306, 307),
+    (319, 320),
+    (329, 329),
+    (383, 383),
+    (452, 460),
+    (497, 499),
+    (688, 696),
+    (728, 733),
+    (736, 740),
+    (832, 833),
+    (835, 836),
+    (884, 884),
+    (890, 890),
+    (894, 894),
+    (900, 901),
+    (903, 903),
+    (976, 982),
+    (1008, 1010),
+    (1012, 1013),
+    (1017, 1017),
+    (1415, 1415),
+    (1653, 1656),
+    (2392, 2399),
+    (2524, 2525),
+    (2527, 2527),
+    (2611, 2611),
+    (2614, 2614),
+    (2649, 2651),
+    (2654, 2654),
+    (2908, 2909),
+    (3635, 3635),
+    (3763, 3763),
+    (3804, 3805),
+    (3852, 3852),
+    (3907, 3907),
+    (3917, 3917),
+    (3922, 3922),
+    (3927, 3927),
+    (3932, 3932),
+    (3945, 3945),
+    (3955, 3955),
+    (3957, 3961),
+    (3969, 3969),
+    (3987, 3987),
+    (3997, 3997),
+    (4002, 4002),
+    (4007, 4007),
+    (4012, 4012),
+    (4025, 4025),
+    (4348, 4348),
+    (7468, 7470),
+    (7472, 7482),
+    (7484, 7501),
+    (7503, 7530),
+    (7544, 7544),
+    (7579, 7615),
+    (7834, 7835),
+    (8049, 8049),
+    (8051, 8051),
+    (8053, 8053),
+    (8055, 8055),
+    (8057, 8057),
+    (8059, 8059),
+    (8061, 8061),
+    (8123, 8123),
+    (8125, 8129),
+    (8137, 8137),
+    (8139, 8139),
+    (8141, 8143),
+    (8147, 8147),
+    (8155, 8155),
+    (8157, 8159),
+    (8163, 8163),
+    (8171, 8171),
+    (8173, 8175),
+    (8185, 8185),
+    (8187, 8187),
+    (8189, 8190),
+    (8192, 8202),
+    (8209, 8209),
+    (8215, 8215),
+    (8228, 8230),
+    (8239, 8239),
+    (8243, 8244),
+    (8246, 8247),
+    (8252, 8252),
+    (8254, 8254),
+    (8263, 8265),
+    (8279, 8279),
+    (8287, 8287),
+    (8304, 8305),
+    (8308, 8334),
+    (8336, 8348),
+    (8360, 8360),
+    (8448, 8451),
+    (8453, 8455),
+    (8457, 8467),
+    (8469, 8470),
+    (8473, 8477),
+    (8480, 8482),
+    (8484, 8484),
+    (8486, 8486),
+    (8488, 8488),
+    (8490, 8493),
+    (8495, 8497),
+    (8499, 8505),
+    (8507, 8512),
+    (8517, 8521),
+    (8528, 8575),
+    (8585, 8585),
+    (8748, 8749),
+    (8751, 8752),
+    (9001, 9002),
+    (9312, 9450),
+    (10764, 10764),
+    (10868, 10870),
+    (10972, 10972),
+    (11388, 11389),
+    (11631, 11631),
+    (11935, 11935),
+    (12019, 12019),
+    (12032, 12245),
+    (12288, 12288),
+    (12342, 12342),
+    (12344, 12346),
+    (12443, 12444),
+    (12447, 12447),
+    (12543, 12543),
+    (12593, 12686),
+    (12690, 12703),
+    (12800, 12830),
+    (12832, 12871),
+    (12880, 12926),
+    (12928, 13054),
+    (13056, 13311),
+    (42652, 42653),
+    (42864, 42864),
+    (43000, 43001),
+    (43868, 43871),
+    (63744, 64013),
+    (64016, 64016),
+    (64018, 64018),
+    (64021, 64030),
+    (64032, 64032),
+    (64034, 64034),
+    (64037, 64038),
+    (64042, 64109),
+    (64112, 64217),
+    (64256, 64262),
+    (64275, 64279),
+    (64285, 64285),
+    (64287, 64310),
+    (64312, 64316),
+    (64318, 64318),
+    (64320, 64321),
+    (64323, 64324),
+    (64326, 64433),
+    (64467, 64829),
+    (64848, 64911),
+    (64914, 64967),
+    (65008, 65020),
+    (65040, 65049),
+    (65072, 65092),
+    (65095, 65106),
+    (65108, 65126),
+    (65128, 65131),
+    (65136, 65138),
+    (65140, 65140),
+    (65142, 65276),
+    (65281, 65470),
+    (65474, 65479),
+    (65482, 65487),
+    (65490, 65495),
+    (65498, 65500),
+    (65504, 65510),
+    (65512, 65518),
+    (119134, 119140),
+    (119227, 119232),
+    (119808, 119892),
+    (119894, 119964),
+    (119966, 119967),
+    (119970, 119970),
+    (119973, 119974),
+    (119977, 119980),
+    (119982, 119993),
+    (119995, 119995),
+    (119997, 120003),
+    (120005, 120069),
+    (120071, 120074),
+    (120077, 120084),
+    (120086, 120092),
+    (120094, 120121),
+    (120123, 120126),
+    (120128, 120132),
+    (120134, 120134),
+    (120138, 120144),
+    (120146, 120485),
+    (120488, 120779),
+    (120782, 120831),
+    (126464, 126467),
+    (126469, 126495),
+    (126497, 126498),
+    (126500, 126500),
+    (126503, 126503),
+    (126505, 126514),
+    (126516, 126519),
+    (126521, 126521),
+    (126523, 126523),
+    (126530, 126530),
+    (126535, 126535),
+    (126537, 126537),
+    (126539, 126539),
+    (126541, 126543),
+    (126545, 126546),
+    (126548, 126548),
+    (126551, 126551),
+    (126553, 126553),
+    (126555, 126555),
+    (126557, 126557),
+    (126559, 126559),
+    (126561, 126562),
+    (126564, 126564),
+    (126567, 126570),
+    (126572, 126578),
+    (126580, 126583),
+    (126585, 126588),
+    (126590, 126590),
+    (126592, 126601),
+    (126603, 126619),
+    (126625, 126627),
+    (126629, 126633),
+    (126635, 126651),
+    (127232, 127242),
+    (127248, 127278),
+    (127280, 127311),
+    (127338, 127339),
+    (127376, 127376),
+    (127488, 127490),
+    (127504, 127547),
+    (127552, 127560),
+    (127568, 127569),
+    (194560, 195101),
+];
+
+pub const NFKD_MAYBE: &'static [(u32, u32)] = &[];
+
+pub const NFKD_NO: &'static [(u32, u32)] = &[
+    (160, 160),
+    (168, 168),
+    (170, 170),
+    (175, 175),
+    (178, 181),
+    (184, 186),
+    (188, 190),
+    (192, 197),
+    (199, 207),
+    (209, 214),
+    (217, 221),
+    (224, 229),
+    (231, 239),
+    (241, 246),
+    (249, 253),
+    (255, 271),
+    (274, 293),
+    (296, 304),
+    (306, 311),
+    (313, 320),
+    (323, 329),
+    (332, 337),
+    (340, 357),
+    (360, 383),
+    (416, 417),
+    (431, 432),
+    (452, 476),
+    (478, 483),
+    (486, 501),
+    (504, 539),
+    (542, 543),
+    (550, 563),
+    (688, 696),
+    (728, 733),
+    (736, 740),
+    (832, 833),
+    (835, 836),
+    (884, 884),
+    (890, 890),
+    (894, 894),
+    (900, 906),
+    (908, 908),
+    (910, 912),
+    (938, 944),
+    (970, 974),
+    (976, 982),
+    (1008, 1010),
+    (1012, 1013),
+    (1017, 1017),
+    (1024, 1025),
+    (1027, 1027),
+    (1031, 1031),
+    (1036, 1038),
+    (1049, 1049),
+    (1081, 1081),
+    (1104, 1105),
+    (1107, 1107),
+    (1111, 1111),
+    (1116, 1118),
+    (1142, 1143),
+    (1217, 1218),
+    (1232, 1235),
+    (1238, 1239),
+    (1242, 1247),
+    (1250, 1255),
+    (1258, 1269),
+    (1272, 1273),
+    (1415, 1415),
+    (1570, 1574),
+    (1653, 1656),
+    (1728, 1728),
+    (1730, 1730),
+    (1747, 1747),
+    (2345, 2345),
+    (2353, 2353),
+    (2356, 2356),
+    (2392, 2399),
+    (2507, 2508),
+    (2524, 2525),
+    (2527, 2527),
+    (2611, 2611),
+    (2614, 2614),
+    (2649, 2651),
+    (2654, 2654),
+    (2888, 2888),
+    (2891, 2892),
+    (2908, 2909),
+    (2964, 2964),
+    (3018, 3020),
+    (3144, 3144),
+    (3264, 3264),
+    (3271, 3272),
+    (3274, 3275),
+    (3402, 3404),
+    (3546, 3546),
+    (3548, 3550),
+    (3635, 3635),
+    (3763, 3763),
+    (3804, 3805),
+    (3852, 3852),
+    (3907, 3907),
+    (3917, 3917),
+    (3922, 3922),
+    (3927, 3927),
+    (3932, 3932),
+    (3945, 3945),
+    (3955, 3955),
+    (3957, 3961),
+    (3969, 3969),
+    (3987, 3987),
+    (3997, 3997),
+    (4002, 4002),
+    (4007, 4007),
+    (4012, 4012),
+    (4025, 4025),
+    (4134, 4134),
+    (4348, 4348),
+    (6918, 6918),
+    (6920, 6920),
+    (6922, 6922),
+    (6924, 6924),
+    (6926, 6926),
+    (6930, 6930),
+    (6971, 6971),
+    (6973, 6973),
+    (6976, 6977),
+    (6979, 6979),
+    (7468, 7470),
+    (7472, 7482),
+    (7484, 7501),
+    (7503, 7530),
+    (7544, 7544),
+    (7579, 7615),
+    (7680, 7835),
+    (7840, 7929),
+    (7936, 7957),
+    (7960, 7965),
+    (7968, 8005),
+    (8008, 8013),
+    (8016, 8023),
+    (8025, 8025),
+    (8027, 8027),
+    (8029, 8029),
+    (8031, 8061),
+    (8064, 8116),
+    (8118, 8132),
+    (8134, 8147),
+    (8150, 8155),
+    (8157, 8175),
+    (8178, 8180),
+    (8182, 8190),
+    (8192, 8202),
+    (8209, 8209),
+    (8215, 8215),
+    (8228, 8230),
+    (8239, 8239),
+    (8243, 8244),
+    (8246, 8247),
+    (8252, 8252),
+    (8254, 8254),
+    (8263, 8265),
+    (8279, 8279),
+    (8287, 8287),
+    (8304, 8305),
+    (8308, 8334),
+    (8336, 8348),
+    (8360, 8360),
+    (8448, 8451),
+    (8453, 8455),
+    (8457, 8467),
+    (8469, 8470),
+    (8473, 8477),
+    (8480, 8482),
+    (8484, 8484),
+    (8486, 8486),
+    (8488, 8488),
+    (8490, 8493),
+    (8495, 8497),
+    (8499, 8505),
+    (8507, 8512),
+    (8517, 8521),
+    (8528, 8575),
+    (8585, 8585),
+    (8602, 8603),
+    (8622, 8622),
+    (8653, 8655),
+    (8708, 8708),
+    (8713, 8713),
+    (8716, 8716),
+    (8740, 8740),
+    (8742, 8742),
+    (8748, 8749),
+    (8751, 8752),
+    (8769, 8769),
+    (8772, 8772),
+    (8775, 8775),
+    (8777, 8777),
+    (8800, 8800),
+    (8802, 8802),
+    (8813, 8817),
+    (8820, 8821),
+    (8824, 8825),
+    (8832, 8833),
+    (8836, 8837),
+    (8840, 8841),
+    (8876, 8879),
+    (8928, 8931),
+    (8938, 8941),
+    (9001, 9002),
+    (9312, 9450),
+    (10764, 10764),
+    (10868, 10870),
+    (10972, 10972),
+    (11388, 11389),
+    (11631, 11631),
+    (11935, 11935),
+    (12019, 12019),
+    (12032, 12245),
+    (12288, 12288),
+    (12342, 12342),
+    (12344, 12346),
+    (12364, 12364),
+    (12366, 12366),
+    (12368, 12368),
+    (12370, 12370),
+    (12372, 12372),
+    (12374, 12374),
+    (12376, 12376),
+    (12378, 12378),
+    (12380, 12380),
+    (12382, 12382),
+    (12384, 12384),
+    (12386, 12386),
+    (12389, 12389),
+    (12391, 12391),
+    (12393, 12393),
+    (12400, 12401),
+    (12403, 12404),
+    (12406, 12407),
+    (12409, 12410),
+    (12412, 12413),
+    (12436, 12436),
+    (12443, 12444),
+    (12446, 12447),
+    (12460, 12460),
+    (12462, 12462),
+    (12464, 12464),
+    (12466, 12466),
+    (12468, 12468),
+    (12470, 12470),
+    (12472, 12472),
+    (12474, 12474),
+    (12476, 12476),
+    (12478, 12478),
+    (12480, 12480),
+    (12482, 12482),
+    (12485, 12485),
+    (12487, 12487),
+    (12489, 12489),
+    (12496, 12497),
+    (12499, 12500),
+    (12502, 12503),
+    (12505, 12506),
+    (12508, 12509),
+    (12532, 12532),
+    (12535, 12538),
+    (12542, 12543),
+    (12593, 12686),
+    (12690, 12703),
+    (12800, 12830),
+    (12832, 12871),
+    (12880, 12926),
+    (12928, 13054),
+    (13056, 13311),
+    (42652, 42653),
+    (42864, 42864),
+    (43000, 43001),
+    (43868, 43871),
+    (44032, 55203),
+    (63744, 64013),
+    (64016, 64016),
+    (64018, 64018),
+    (64021, 64030),
+    (64032, 64032),
+    (64034, 64034),
+    (64037, 64038),
+    (64042, 64109),
+    (64112, 64217),
+    (64256, 64262),
+    (64275, 64279),
+    (64285, 64285),
+    (64287, 64310),
+    (64312, 64316),
+    (64318, 64318),
+    (64320, 64321),
+    (64323, 64324),
+    (64326, 64433),
+    (64467, 64829),
+    (64848, 64911),
+    (64914, 64967),
+    (65008, 65020),
+    (65040, 65049),
+    (65072, 65092),
+    (65095, 65106),
+    (65108, 65126),
+    (65128, 65131),
+    (65136, 65138),
+    (65140, 65140),
+    (65142, 65276),
+    (65281, 65470),
+    (65474, 65479),
+    (65482, 65487),
+    (65490, 65495),
+    (65498, 65500),
+    (65504, 65510),
+    (65512, 65518),
+    (69786, 69786),
+    (69788, 69788),
+    (69803, 69803),
+    (69934, 69935),
+    (70475, 70476),
+    (70843, 70844),
+    (70846, 70846),
+    (71098, 71099),
+    (119134, 119140),
+    (119227, 119232),
+    (119808, 119892),
+    (119894, 119964),
+    (119966, 119967),
+    (119970, 119970),
+    (119973, 119974),
+    (119977, 119980),
+    (119982, 119993),
+    (119995, 119995),
+    (119997, 120003),
+    (120005, 120069),
+    (120071, 120074),
+    (120077, 120084),
+    (120086, 120092),
+    (120094, 120121),
+    (120123, 120126),
+    (120128, 120132),
+    (120134, 120134),
+    (120138, 120144),
+    (120146, 120485),
+    (120488, 120779),
+    (120782, 120831),
+    (126464, 126467),
+    (126469, 126495),
+    (126497, 126498),
+    (126500, 126500),
+    (126503, 126503),
+    (126505, 126514),
+    (126516, 126519),
+    (126521, 126521),
+    (126523, 126523),
+    (126530, 126530),
+    (126535, 126535),
+    (126537, 126537),
+    (126539, 126539),
+    (126541, 126543),
+    (126545, 126546),
+    (126548, 126548),
+    (126551, 126551),
+    (126553, 126553),
+    (126555, 126555),
+    (126557, 126557),
+    (126559, 126559),
+    (126561, 126562),
+    (126564, 126564),
+    (126567, 126570),
+    (126572, 126578),
+    (126580, 126583),
+    (126585, 126588),
+    (126590, 126590),
+    (126592, 126601),
+    (126603, 126619),
+    (126625, 126627),
+    (126629, 126633),
+    (126635, 126651),
+    (127232, 127242),
+    (127248, 127278),
+    (127280, 127311),
+    (127338, 127339),
+    (127376, 127376),
+    (127488, 127490),
+    (127504, 127547),
+    (127552, 127560),
+    (127568, 127569),
+    (194560, 195101),
+];
@@ -1,52 +1,68 @@
-use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use criterion::measurement::WallTime;
+use criterion::{
+    black_box, criterion_group, criterion_main, BenchmarkGroup, BenchmarkId, Criterion, Throughput,
+};
 use database::calculate_hash;
-use database::{DirectoryBasedExampleDatabase, ExampleDatabase, InMemoryExampleDatabase};
+use database::{
+    CacheStats, CompressedDatabase, DirectoryBasedExampleDatabase, ExampleDatabase,
+    InMemoryExampleDatabase, LogExampleDatabase, SqliteExampleDatabase, DEFAULT_CACHE_CAPACITY,
+};
 
-const DATABASE_PATH: &'static str = "/tmp/.hypothesis-db-rs-test";
+const DATABASE_PATH: &str = "/tmp/.hypothesis-db-rs-test";
+const SQLITE_DATABASE_PATH: &str = "/tmp/.hypothesis-db-rs-test.sqlite";
+const LOG_DATABASE_PATH: &str = "/tmp/.hypothesis-db-rs-test.log";
 
-fn bench_save(c: &mut Criterion, id: &str, mut db: impl ExampleDatabase) {
+fn bench_save(c: &mut Criterion, id: &str, db: impl ExampleDatabase) {
     let key = black_box(b"foo");
     let value = black_box(b"bar");
     c.bench_function(id, |b| {
         b.iter(|| {
-            db.save(key, value);
-            db.delete(key, value);
+            db.save(key, value).expect("Save failed");
+            db.delete(key, value).expect("Delete failed");
         })
     });
 }
 
-fn bench_fetch_some(c: &mut Criterion, id: &str, mut db: impl ExampleDatabase) {
-    db.save(b"foo", b"bar1");
-    db.save(b"foo", b"bar2");
+fn bench_fetch_some(c: &mut Criterion, id: &str, db: impl ExampleDatabase) {
+    db.save(b"foo", b"bar1").expect("Save failed");
+    db.save(b"foo", b"bar2").expect("Save failed");
     let key = black_box(b"foo");
-    c.bench_function(id, |b| b.iter(|| db.fetch(key).into_vec()));
+    c.bench_function(id, |b| {
+        b.iter(|| db.fetch(key).expect("Fetch failed").into_vec())
+    });
 }
 
 fn bench_fetch_empty(c: &mut Criterion, id: &str, db: impl ExampleDatabase) {
     let key = black_box(b"unknown");
-    c.bench_function(id, |b| b.iter(|| db.fetch(key).into_vec()));
+    c.bench_function(id, |b| {
+        b.iter(|| db.fetch(key).expect("Fetch failed").into_vec())
+    });
 }
 
-fn bench_delete_not_existing_key(c: &mut Criterion, id: &str, mut db: impl ExampleDatabase) {
+fn bench_delete_not_existing_key(c: &mut Criterion, id: &str, db: impl ExampleDatabase) {
     let key = black_box(b"doesntexist");
     let value = black_box(b"doesntexist");
-    c.bench_function(id, |b| b.iter(|| db.delete(key, value)));
+    c.bench_function(id, |b| {
+        b.iter(|| db.delete(key, value).expect("Delete failed"))
+    });
 }
 
-fn bench_delete_existing_key_no_value(c: &mut Criterion, id: &str, mut db: impl ExampleDatabase) {
-    db.save(b"foo", b"bar");
+fn bench_delete_existing_key_no_value(c: &mut Criterion, id: &str, db: impl ExampleDatabase) {
+    db.save(b"foo", b"bar").expect("Save failed");
     let key = black_box(b"foo");
     let value = black_box(b"doesntexist");
-    c.bench_function(id, |b| b.iter(|| db.delete(key, value)));
+    c.bench_function(id, |b| {
+        b.iter(|| db.delete(key, value).expect("Delete failed"))
+    });
 }
 
-fn bench_delete_existing_key_value(c: &mut Criterion, id: &str, mut db: impl ExampleDatabase) {
+fn bench_delete_existing_key_value(c: &mut Criterion, id: &str, db: impl ExampleDatabase) {
     let key = black_box(b"foo");
     let value = black_box(b"bar");
     c.bench_function(id, |b| {
         b.iter(|| {
-            db.save(b"foo", b"bar");
-            db.delete(key, value)
+            db.save(b"foo", b"bar").expect("Save failed");
+            db.delete(key, value).expect("Delete failed")
         })
     });
 }
@@ -81,6 +97,103 @@ fn directory_delete_existing_key_value(c: &mut Criterion) {
     bench_delete_existing_key_value(c, "directory delete existing key value", db);
 }
 
+/// Hit rate of the hash cache once it is warm, i.e. during a pass over `values` that all were
+/// saved before.
+fn steady_hit_rate(db: &DirectoryBasedExampleDatabase, values: &[Vec<u8>]) -> f64 {
+    for value in values {
+        db.save(b"cached", value).expect("Save failed");
+    }
+    let before = db.cache_stats();
+    for value in values {
+        db.save(b"cached", value).expect("Save failed");
+    }
+    let after = db.cache_stats();
+    CacheStats {
+        hits: after.hits.saturating_sub(before.hits),
+        misses: after.misses.saturating_sub(before.misses),
+    }
+    .hit_rate()
+}
+
+/// Repeatedly save the same values, which are already stored. The work is mostly hashing, unless
+/// the hashes are cached.
+fn directory_cache_capacity(c: &mut Criterion) {
+    // More values than the default cache capacity, so some of them don't fit into it
+    let values: Vec<Vec<u8>> = (0..2 * DEFAULT_CACHE_CAPACITY)
+        .map(|idx| idx.to_le_bytes().repeat(128))
+        .collect();
+    let mut group = c.benchmark_group("directory cache capacity");
+    group.throughput(Throughput::Bytes(values[0].len() as u64));
+    for capacity in &[0, DEFAULT_CACHE_CAPACITY, 4 * DEFAULT_CACHE_CAPACITY] {
+        let db = DirectoryBasedExampleDatabase::with_cache_capacity(DATABASE_PATH, *capacity);
+        let hit_rate = steady_hit_rate(&db, &values);
+        group.bench_with_input(
+            BenchmarkId::from_parameter(format!("{} (hit rate {:.2})", capacity, hit_rate)),
+            &values,
+            |b, values| {
+                let mut values = values.iter().cycle();
+                b.iter(|| {
+                    let value = values.next().expect("Cycle is infinite");
+                    db.save(b"cached", value).expect("Save failed")
+                })
+            },
+        );
+    }
+    group.finish();
+}
+
+fn bench_large_value(
+    group: &mut BenchmarkGroup<'_, WallTime>,
+    id: &str,
+    db: &impl ExampleDatabase,
+    value: &[u8],
+) {
+    let key = id.as_bytes();
+    group.bench_function(BenchmarkId::new("save", id), |b| {
+        b.iter(|| {
+            db.save(key, value).expect("Save failed");
+            db.delete(key, value).expect("Delete failed");
+        })
+    });
+    db.save(key, value).expect("Save failed");
+    group.bench_function(BenchmarkId::new("fetch", id), |b| {
+        b.iter(|| db.fetch(key).expect("Fetch failed").into_vec())
+    });
+}
+
+/// Save and fetch a large, compressible value with and without compression. The benchmark ID
+/// shows how much space the compressed value takes in the database directory.
+fn directory_compression(c: &mut Criterion) {
+    let value: Vec<u8> = (0..16384_u32)
+        .flat_map(|idx| (idx % 100).to_le_bytes())
+        .collect();
+    let plain = DirectoryBasedExampleDatabase::new(DATABASE_PATH);
+    let compressed = CompressedDatabase::new(DirectoryBasedExampleDatabase::new(DATABASE_PATH));
+    compressed
+        .save(b"stored size", &value)
+        .expect("Save failed");
+    let stored: usize = compressed
+        .get_ref()
+        .fetch(b"stored size")
+        .expect("Fetch failed")
+        .into_iter()
+        .map(|stored| stored.len())
+        .sum();
+    compressed
+        .delete(b"stored size", &value)
+        .expect("Delete failed");
+    let mut group = c.benchmark_group("directory compression");
+    group.throughput(Throughput::Bytes(value.len() as u64));
+    bench_large_value(&mut group, "plain", &plain, &value);
+    bench_large_value(
+        &mut group,
+        &format!("compressed to {} bytes", stored),
+        &compressed,
+        &value,
+    );
+    group.finish();
+}
+
 fn inmemory_save(c: &mut Criterion) {
     let db = InMemoryExampleDatabase::new();
     bench_save(c, "inmemory save", db);
@@ -111,6 +224,66 @@ fn inmemory_delete_existing_key_value(c: &mut Criterion) {
     bench_delete_existing_key_value(c, "inmemory delete existing key value", db);
 }
 
+fn sqlite_save(c: &mut Criterion) {
+    let db = SqliteExampleDatabase::new(SQLITE_DATABASE_PATH).expect("Database is created");
+    bench_save(c, "sqlite save", db);
+}
+
+fn sqlite_fetch_some(c: &mut Criterion) {
+    let db = SqliteExampleDatabase::new(SQLITE_DATABASE_PATH).expect("Database is created");
+    bench_fetch_some(c, "sqlite fetch some", db);
+}
+
+fn sqlite_fetch_empty(c: &mut Criterion) {
+    let db = SqliteExampleDatabase::new(SQLITE_DATABASE_PATH).expect("Database is created");
+    bench_fetch_empty(c, "sqlite fetch empty", db);
+}
+
+fn sqlite_delete_not_existing_key(c: &mut Criterion) {
+    let db = SqliteExampleDatabase::new(SQLITE_DATABASE_PATH).expect("Database is created");
+    bench_delete_not_existing_key(c, "sqlite delete not existing key", db);
+}
+
+fn sqlite_delete_existing_key_no_value(c: &mut Criterion) {
+    let db = SqliteExampleDatabase::new(SQLITE_DATABASE_PATH).expect("Database is created");
+    bench_delete_existing_key_no_value(c, "sqlite delete existing key no value", db);
+}
+
+fn sqlite_delete_existing_key_value(c: &mut Criterion) {
+    let db = SqliteExampleDatabase::new(SQLITE_DATABASE_PATH).expect("Database is created");
+    bench_delete_existing_key_value(c, "sqlite delete existing key value", db);
+}
+
+fn log_save(c: &mut Criterion) {
+    let db = LogExampleDatabase::new(LOG_DATABASE_PATH).expect("Log is created");
+    bench_save(c, "log save", db);
+}
+
+fn log_fetch_some(c: &mut Criterion) {
+    let db = LogExampleDatabase::new(LOG_DATABASE_PATH).expect("Log is created");
+    bench_fetch_some(c, "log fetch some", db);
+}
+
+fn log_fetch_empty(c: &mut Criterion) {
+    let db = LogExampleDatabase::new(LOG_DATABASE_PATH).expect("Log is created");
+    bench_fetch_empty(c, "log fetch empty", db);
+}
+
+fn log_delete_not_existing_key(c: &mut Criterion) {
+    let db = LogExampleDatabase::new(LOG_DATABASE_PATH).expect("Log is created");
+    bench_delete_not_existing_key(c, "log delete not existing key", db);
+}
+
+fn log_delete_existing_key_no_value(c: &mut Criterion) {
+    let db = LogExampleDatabase::new(LOG_DATABASE_PATH).expect("Log is created");
+    bench_delete_existing_key_no_value(c, "log delete existing key no value", db);
+}
+
+fn log_delete_existing_key_value(c: &mut Criterion) {
+    let db = LogExampleDatabase::new(LOG_DATABASE_PATH).expect("Log is created");
+    bench_delete_existing_key_value(c, "log delete existing key value", db);
+}
+
 fn hash_formatting(c: &mut Criterion) {
     let value = black_box(b"foo");
     c.bench_function("hash formatting", |b| {
@@ -128,6 +301,8 @@ criterion_group!(
     directory_delete_not_existing_key,
     directory_delete_existing_key_no_value,
     directory_delete_existing_key_value,
+    directory_cache_capacity,
+    directory_compression,
 );
 criterion_group!(
     inmemory,
@@ -138,5 +313,23 @@ criterion_group!(
     inmemory_delete_existing_key_no_value,
     inmemory_delete_existing_key_value,
 );
+criterion_group!(
+    sqlite,
+    sqlite_save,
+    sqlite_fetch_some,
+    sqlite_fetch_empty,
+    sqlite_delete_not_existing_key,
+    sqlite_delete_existing_key_no_value,
+    sqlite_delete_existing_key_value,
+);
+criterion_group!(
+    log,
+    log_save,
+    log_fetch_some,
+    log_fetch_empty,
+    log_delete_not_existing_key,
+    log_delete_existing_key_no_value,
+    log_delete_existing_key_value,
+);
 criterion_group!(hashes, hash_formatting);
-criterion_main!(directory, inmemory, hashes);
+criterion_main!(directory, inmemory, sqlite, log, hashes);
@@ -0,0 +1,330 @@
+use crate::directory::file_name;
+use crate::metadata;
+use crate::{DatabaseError, ExampleDatabase, Input};
+use ahash::AHashMap;
+use std::fs::{self, File};
+use std::io::{Read, Write};
+use std::path::Path;
+use std::time::SystemTime;
+use zip::result::ZipError;
+use zip::write::FileOptions;
+use zip::{ZipArchive, ZipWriter};
+
+/// Result of importing an archive.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub struct ImportSummary {
+    /// Number of examples saved into the database.
+    pub imported: usize,
+    /// Number of examples whose key is unknown, as the archive has no metadata for it.
+    pub skipped: usize,
+}
+
+pub(crate) fn zip_error(path: &Path, error: ZipError) -> DatabaseError {
+    match error {
+        ZipError::Io(error) => DatabaseError::io(path, error),
+        error => DatabaseError::Corrupted(path.to_path_buf(), error.to_string()),
+    }
+}
+
+/// Write all examples of the given keys into a zip archive at `path`.
+/// Returns the number of exported examples.
+///
+/// The archive has the same layout as a `DirectoryBasedExampleDatabase` - a directory per key and
+/// a file per example, named after their hashes, plus metadata files with original keys.
+/// Extracting it gives a directory that Python Hypothesis can use as is.
+pub fn export<'a, D, K, P>(db: &D, keys: K, path: P) -> Result<usize, DatabaseError>
+where
+    D: ExampleDatabase + ?Sized,
+    K: IntoIterator<Item = &'a Input>,
+    P: AsRef<Path>,
+{
+    let path = path.as_ref();
+    let file = File::create(path).map_err(|error| DatabaseError::io(path, error))?;
+    let result = write_archive(db, keys, ZipWriter::new(file), path);
+    if result.is_err() {
+        // Partially written archive is useless
+        let _ = fs::remove_file(path);
+    }
+    result
+}
+
+/// Write all examples of all keys into a zip archive at `path`, see `export`.
+pub fn export_all<D, P>(db: &D, path: P) -> Result<usize, DatabaseError>
+where
+    D: ExampleDatabase + ?Sized,
+    P: AsRef<Path>,
+{
+    let keys = db.keys()?;
+    export(db, keys.iter().map(Vec::as_slice), path)
+}
+
+fn write_archive<'a, D, K>(
+    db: &D,
+    keys: K,
+    mut writer: ZipWriter<File>,
+    path: &Path,
+) -> Result<usize, DatabaseError>
+where
+    D: ExampleDatabase + ?Sized,
+    K: IntoIterator<Item = &'a Input>,
+{
+    let options = FileOptions::default();
+    let mut exported = 0_usize;
+    for key in keys {
+        let key_name = file_name(key);
+        writer
+            .start_file(format!("{}.{}", key_name, metadata::EXTENSION), options)
+            .map_err(|error| zip_error(path, error))?;
+        writer
+            .write_all(metadata::encode(key, SystemTime::now()).as_bytes())
+            .map_err(|error| DatabaseError::io(path, error))?;
+        writer
+            .add_directory(key_name.as_str(), options)
+            .map_err(|error| zip_error(path, error))?;
+        for value in db.fetch(key)? {
+            writer
+                .start_file(format!("{}/{}", key_name, file_name(&value)), options)
+                .map_err(|error| zip_error(path, error))?;
+            writer
+                .write_all(&value)
+                .map_err(|error| DatabaseError::io(path, error))?;
+            exported = exported.saturating_add(1);
+        }
+    }
+    writer.finish().map_err(|error| zip_error(path, error))?;
+    Ok(exported)
+}
+
+/// Save all examples from a zip archive at `path` into the database.
+///
+/// The archive should have the layout produced by `export`. Examples of keys without metadata,
+/// e.g. from a zipped directory written by Python Hypothesis, can't be imported and are skipped.
+pub fn import<D, P>(db: &D, path: P) -> Result<ImportSummary, DatabaseError>
+where
+    D: ExampleDatabase + ?Sized,
+    P: AsRef<Path>,
+{
+    let path = path.as_ref();
+    let file = File::open(path).map_err(|error| DatabaseError::io(path, error))?;
+    let mut archive = ZipArchive::new(file).map_err(|error| zip_error(path, error))?;
+    let keys = read_keys(&mut archive, path)?;
+    let mut summary = ImportSummary::default();
+    for idx in 0..archive.len() {
+        let mut entry = archive
+            .by_index(idx)
+            .map_err(|error| zip_error(path, error))?;
+        if entry.is_dir() {
+            continue;
+        }
+        let (key_name, value_name) = match entry.name().split_once('/') {
+            Some(names) => names,
+            None => continue,
+        };
+        // Leftovers of an interrupted `save` in the archived directory
+        if value_name.contains('.') {
+            continue;
+        }
+        match keys.get(key_name) {
+            Some(key) => {
+                let mut value = vec![];
+                entry
+                    .read_to_end(&mut value)
+                    .map_err(|error| DatabaseError::io(path, error))?;
+                db.save(key, &value)?;
+                summary.imported = summary.imported.saturating_add(1);
+            }
+            None => summary.skipped = summary.skipped.saturating_add(1),
+        }
+    }
+    Ok(summary)
+}
+
+/// Original keys by their directory names.
+///
+/// Damaged metadata entries are skipped, so their keys are handled as keys without metadata and
+/// the rest of the archive stays usable.
+pub(crate) fn read_keys(
+    archive: &mut ZipArchive<File>,
+    path: &Path,
+) -> Result<AHashMap<String, Vec<u8>>, DatabaseError> {
+    let mut keys = AHashMap::new();
+    for idx in 0..archive.len() {
+        let mut entry = archive
+            .by_index(idx)
+            .map_err(|error| zip_error(path, error))?;
+        let key_name = match entry
+            .name()
+            .strip_suffix(metadata::EXTENSION)
+            .and_then(|name| name.strip_suffix('.'))
+        {
+            Some(name) if !name.contains('/') => name.to_string(),
+            _ => continue,
+        };
+        let mut contents = String::new();
+        if entry.read_to_string(&mut contents).is_err() {
+            continue;
+        }
+        let metadata_path = path.join(entry.name());
+        match metadata::decode(&metadata_path, &contents) {
+            // Metadata that belongs to a different key is damaged too
+            Ok((key, _)) if file_name(&key).as_str() == key_name => {
+                keys.insert(key_name, key);
+            }
+            _ => {}
+        }
+    }
+    Ok(keys)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DirectoryBasedExampleDatabase, Example, InMemoryExampleDatabase};
+    use tempdir::TempDir;
+
+    fn sorted(db: &impl ExampleDatabase, key: &Input) -> Vec<Example> {
+        let mut values = db.fetch(key).expect("Fetch failed").into_vec();
+        values.sort_unstable();
+        values
+    }
+
+    #[test]
+    fn roundtrip() {
+        let temp = TempDir::new("test-archive").expect("Should always work");
+        let archive = temp.path().join("examples.zip");
+        let source = InMemoryExampleDatabase::new();
+        source.save(b"foo", b"bar").expect("Save failed");
+        source.save(b"foo", b"baz").expect("Save failed");
+        source.save(b"\x00\xff", b"").expect("Save failed");
+        source.save(b"other", b"value").expect("Save failed");
+        assert_eq!(
+            export(&source, vec![&b"foo"[..], b"\x00\xff"], &archive).expect("Export failed"),
+            3
+        );
+        let target = DirectoryBasedExampleDatabase::new(temp.path().join("db"));
+        assert_eq!(
+            import(&target, &archive).expect("Import failed"),
+            ImportSummary {
+                imported: 3,
+                skipped: 0
+            }
+        );
+        assert_eq!(
+            sorted(&target, b"foo"),
+            vec![b"bar".to_vec(), b"baz".to_vec()]
+        );
+        assert_eq!(sorted(&target, b"\x00\xff"), vec![b"".to_vec()]);
+        assert!(sorted(&target, b"other").is_empty());
+    }
+
+    #[test]
+    fn layout_matches_directory_database() {
+        let temp = TempDir::new("test-archive").expect("Should always work");
+        let archive = temp.path().join("examples.zip");
+        let source = InMemoryExampleDatabase::new();
+        source.save(b"foo", b"bar").expect("Save failed");
+        assert_eq!(export_all(&source, &archive).expect("Export failed"), 1);
+        let db = DirectoryBasedExampleDatabase::new(temp.path());
+        let key_name = file_name(b"foo");
+        let mut zip =
+            ZipArchive::new(File::open(&archive).expect("Archive exists")).expect("Valid archive");
+        let mut names: Vec<_> = zip.file_names().map(str::to_string).collect();
+        names.sort_unstable();
+        assert_eq!(
+            names,
+            vec![
+                format!("{}.meta", key_name),
+                format!("{}/", key_name),
+                format!("{}/{}", key_name, file_name(b"bar")),
+            ]
+        );
+        let mut value = vec![];
+        zip.by_name(&names[2])
+            .expect("Entry exists")
+            .read_to_end(&mut value)
+            .expect("Entry is readable");
+        assert_eq!(value, b"bar");
+        // Names are the same as in the directory database
+        db.save(b"foo", b"bar").expect("Save failed");
+        assert!(temp.path().join(&names[2]).is_file());
+        assert!(temp.path().join(&names[0]).is_file());
+    }
+
+    #[test]
+    fn keys_without_metadata_are_skipped() {
+        let temp = TempDir::new("test-archive").expect("Should always work");
+        let archive = temp.path().join("examples.zip");
+        let mut writer = ZipWriter::new(File::create(&archive).expect("Can't create a file"));
+        writer
+            .start_file("0123456789abcdef/fedcba9876543210", FileOptions::default())
+            .expect("Can't add a file");
+        writer.write_all(b"value").expect("Can't write a file");
+        writer.finish().expect("Can't write an archive");
+        let db = InMemoryExampleDatabase::new();
+        assert_eq!(
+            import(&db, &archive).expect("Import failed"),
+            ImportSummary {
+                imported: 0,
+                skipped: 1
+            }
+        );
+        assert!(db.keys().expect("Keys are listable").is_empty());
+    }
+
+    #[test]
+    fn keys_with_damaged_metadata_are_skipped() {
+        let temp = TempDir::new("test-archive").expect("Should always work");
+        let archive = temp.path().join("examples.zip");
+        let mut writer = ZipWriter::new(File::create(&archive).expect("Can't create a file"));
+        for (name, contents) in &[
+            (
+                format!("{}.meta", file_name(b"foo")),
+                "key=666f6f\ncreated=0\n".to_string(),
+            ),
+            (
+                format!("{}/{}", file_name(b"foo"), file_name(b"bar")),
+                "bar".to_string(),
+            ),
+            (
+                format!("{}.meta", file_name(b"bad")),
+                "key=zz\n".to_string(),
+            ),
+            (
+                format!("{}/{}", file_name(b"bad"), file_name(b"value")),
+                "value".to_string(),
+            ),
+            // Metadata of another key
+            (
+                format!("{}.meta", file_name(b"moved")),
+                "key=666f6f\ncreated=0\n".to_string(),
+            ),
+        ] {
+            writer
+                .start_file(name.as_str(), FileOptions::default())
+                .expect("Can't add a file");
+            writer
+                .write_all(contents.as_bytes())
+                .expect("Can't write a file");
+        }
+        writer.finish().expect("Can't write an archive");
+        let db = InMemoryExampleDatabase::new();
+        assert_eq!(
+            import(&db, &archive).expect("Import failed"),
+            ImportSummary {
+                imported: 1,
+                skipped: 1
+            }
+        );
+        assert_eq!(sorted(&db, b"foo"), vec![b"bar".to_vec()]);
+    }
+
+    #[test]
+    fn invalid_archive_is_reported_as_corruption() {
+        let temp = TempDir::new("test-archive").expect("Should always work");
+        let archive = temp.path().join("examples.zip");
+        fs::write(&archive, b"not an archive").expect("Can't write a file");
+        let error =
+            import(&InMemoryExampleDatabase::new(), &archive).expect_err("Import should fail");
+        assert!(matches!(error, DatabaseError::Corrupted(path, _) if path == archive));
+    }
+}
@@ -0,0 +1,115 @@
+use crate::Input;
+use lru::LruCache;
+
+/// Cache hits and misses since the database creation.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub struct CacheStats {
+    /// Number of lookups that found a cached value.
+    pub hits: u64,
+    /// Number of lookups that had to compute the value.
+    pub misses: u64,
+}
+
+impl CacheStats {
+    /// Share of lookups that found a cached value, from `0.0` to `1.0`.
+    /// It is `0.0` if there were no lookups at all.
+    #[inline]
+    pub fn hit_rate(&self) -> f64 {
+        let total = self.hits.saturating_add(self.misses);
+        if total == 0 {
+            0.0
+        } else {
+            self.hits as f64 / total as f64
+        }
+    }
+}
+
+/// Keeps values computed for the least recently used inputs.
+#[derive(Debug)]
+pub(crate) struct BoundedCache<V> {
+    /// `None` if caching is disabled.
+    entries: Option<LruCache<Vec<u8>, V>>,
+    stats: CacheStats,
+}
+
+impl<V: Copy> BoundedCache<V> {
+    /// Create a cache holding at most `capacity` entries, zero disables caching.
+    pub(crate) fn new(capacity: usize) -> BoundedCache<V> {
+        BoundedCache {
+            entries: if capacity == 0 {
+                None
+            } else {
+                Some(LruCache::new(capacity))
+            },
+            stats: CacheStats::default(),
+        }
+    }
+
+    /// Cached value for the given input or the result of `compute`, which is cached afterwards.
+    pub(crate) fn get_or_insert_with(&mut self, input: &Input, compute: impl Fn(&Input) -> V) -> V {
+        if let Some(value) = self.entries.as_mut().and_then(|entries| entries.get(input)) {
+            self.stats.hits = self.stats.hits.saturating_add(1);
+            return *value;
+        }
+        self.stats.misses = self.stats.misses.saturating_add(1);
+        let value = compute(input);
+        if let Some(entries) = &mut self.entries {
+            entries.put(input.to_vec(), value);
+        }
+        value
+    }
+
+    /// Maximum number of cached entries.
+    pub(crate) fn capacity(&self) -> usize {
+        self.entries.as_ref().map_or(0, LruCache::cap)
+    }
+
+    /// Number of currently cached entries.
+    pub(crate) fn len(&self) -> usize {
+        self.entries.as_ref().map_or(0, LruCache::len)
+    }
+
+    pub(crate) fn stats(&self) -> CacheStats {
+        self.stats
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn length(input: &Input) -> usize {
+        input.len()
+    }
+
+    #[test]
+    fn least_recently_used_entries_are_evicted() {
+        let mut cache = BoundedCache::new(2);
+        cache.get_or_insert_with(b"a", length);
+        cache.get_or_insert_with(b"bb", length);
+        // `a` is used more recently than `bb` now
+        cache.get_or_insert_with(b"a", length);
+        cache.get_or_insert_with(b"ccc", length);
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.stats(), CacheStats { hits: 1, misses: 3 });
+        assert_eq!(cache.get_or_insert_with(b"a", length), 1);
+        assert_eq!(cache.get_or_insert_with(b"bb", length), 2);
+        assert_eq!(cache.stats(), CacheStats { hits: 2, misses: 4 });
+        assert!((cache.stats().hit_rate() - 1.0 / 3.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn zero_capacity_disables_caching() {
+        let mut cache = BoundedCache::new(0);
+        assert_eq!(cache.get_or_insert_with(b"a", length), 1);
+        assert_eq!(cache.get_or_insert_with(b"a", length), 1);
+        assert_eq!(cache.capacity(), 0);
+        assert_eq!(cache.len(), 0);
+        assert_eq!(cache.stats(), CacheStats { hits: 0, misses: 2 });
+    }
+
+    #[test]
+    fn empty_stats_have_zero_hit_rate() {
+        assert!(CacheStats::default().hit_rate().abs() < f64::EPSILON);
+    }
+}
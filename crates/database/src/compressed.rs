@@ -0,0 +1,280 @@
+use crate::fetch::Fetch;
+use crate::{DatabaseError, Example, ExampleDatabase, Input};
+use flate2::read::DeflateDecoder;
+use flate2::write::DeflateEncoder;
+use flate2::Compression;
+use std::borrow::Cow;
+use std::convert::TryFrom;
+use std::io::{Read, Write};
+
+/// Values that are shorter than this number of bytes are stored as is by
+/// `CompressedDatabase::new`.
+pub const DEFAULT_COMPRESSION_THRESHOLD: usize = 1024;
+
+/// Starts every value that is written in this wrapper's format. Python Hypothesis writes values as
+/// is, and they are very unlikely to start with this sequence.
+const MARKER: &[u8] = b"\xffHZ";
+/// The value follows the format header unchanged.
+const STORED: u8 = 0;
+/// The value follows the format header as its length (a little-endian `u32`) and a raw deflate
+/// stream.
+const DEFLATE: u8 = 1;
+
+/// A wrapper that compresses large values before passing them to the given database.
+///
+/// Compressed values get a short format header, other values are stored as is, so examples saved
+/// by Python Hypothesis or before compression was enabled remain readable. Values that don't
+/// shrink when compressed are stored as is too.
+///
+/// `delete` and `move` find stored values by their decoded form, so they work regardless of the
+/// threshold or the compression library that stored them. Therefore they fetch all values of the
+/// key from the wrapped database.
+#[derive(Debug)]
+pub struct CompressedDatabase<D: ExampleDatabase> {
+    inner: D,
+    threshold: usize,
+}
+
+impl<D: ExampleDatabase> CompressedDatabase<D> {
+    /// Wrap a database to compress values that are at least `DEFAULT_COMPRESSION_THRESHOLD` bytes
+    /// long.
+    pub fn new(inner: D) -> CompressedDatabase<D> {
+        CompressedDatabase::with_threshold(inner, DEFAULT_COMPRESSION_THRESHOLD)
+    }
+
+    /// Wrap a database to compress values that are at least `threshold` bytes long.
+    pub fn with_threshold(inner: D, threshold: usize) -> CompressedDatabase<D> {
+        CompressedDatabase { inner, threshold }
+    }
+
+    /// Minimum length of compressed values.
+    #[inline]
+    pub fn threshold(&self) -> usize {
+        self.threshold
+    }
+
+    /// Get a reference to the wrapped database.
+    #[inline]
+    pub fn get_ref(&self) -> &D {
+        &self.inner
+    }
+
+    /// Unwrap the database. Values that are already compressed stay compressed.
+    #[inline]
+    pub fn into_inner(self) -> D {
+        self.inner
+    }
+
+    /// The form of `value` that is passed to the wrapped database when it is saved.
+    fn encode<'a>(&self, value: &'a Input) -> Cow<'a, [u8]> {
+        if value.len() >= self.threshold {
+            if let Some(compressed) = compress(value) {
+                return Cow::Owned(compressed);
+            }
+        }
+        if value.starts_with(MARKER) {
+            // Otherwise the value would be mistaken for one in this format
+            Cow::Owned(with_header(STORED, value))
+        } else {
+            Cow::Borrowed(value)
+        }
+    }
+
+    /// Values stored under `key` in any form that decodes to `value`.
+    fn stored_forms(&self, key: &Input, value: &Input) -> Result<Vec<Example>, DatabaseError> {
+        Ok(self
+            .inner
+            .fetch(key)?
+            .into_iter()
+            .filter(|stored| decode(stored).map_or(stored == value, |decoded| decoded == value))
+            .collect())
+    }
+}
+
+fn with_header(method: u8, payload: &[u8]) -> Vec<u8> {
+    let mut output =
+        Vec::with_capacity(payload.len().saturating_add(MARKER.len()).saturating_add(1));
+    output.extend_from_slice(MARKER);
+    output.push(method);
+    output.extend_from_slice(payload);
+    output
+}
+
+/// Returns `None` if compression doesn't make the value shorter.
+fn compress(value: &Input) -> Option<Vec<u8>> {
+    let len = u32::try_from(value.len()).ok()?;
+    let mut encoder = DeflateEncoder::new(len.to_le_bytes().to_vec(), Compression::default());
+    encoder.write_all(value).ok()?;
+    let compressed = with_header(DEFLATE, &encoder.finish().ok()?);
+    Some(compressed).filter(|compressed| compressed.len() < value.len())
+}
+
+/// Returns `None` for values that are not in this wrapper's format, they are stored as is.
+fn decode(value: &[u8]) -> Option<Example> {
+    match value.strip_prefix(MARKER).and_then(<[u8]>::split_first) {
+        Some((&STORED, payload)) => Some(payload.to_vec()),
+        Some((&DEFLATE, payload)) => {
+            let (len, stream) = payload.split_at_checked(4)?;
+            let mut array = [0; 4];
+            array.copy_from_slice(len);
+            let len = u64::from(u32::from_le_bytes(array));
+            let mut output = vec![];
+            // The stored length bounds the output, even if the stream is damaged
+            DeflateDecoder::new(stream)
+                .take(len.saturating_add(1))
+                .read_to_end(&mut output)
+                .ok()?;
+            Some(output).filter(|output| output.len() as u64 == len)
+        }
+        _ => None,
+    }
+}
+
+impl<D: ExampleDatabase> ExampleDatabase for CompressedDatabase<D> {
+    type Source = Vec<Example>;
+
+    #[inline]
+    fn save(&self, key: &Input, value: &Input) -> Result<(), DatabaseError> {
+        self.inner.save(key, &self.encode(value))
+    }
+
+    #[inline]
+    fn delete(&self, key: &Input, value: &Input) -> Result<(), DatabaseError> {
+        for stored in self.stored_forms(key, value)? {
+            self.inner.delete(key, &stored)?;
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn r#move(&self, src: &Input, dst: &Input, value: &Input) -> Result<(), DatabaseError> {
+        if src == dst {
+            return self.save(src, value);
+        }
+        let mut stored = self.stored_forms(src, value)?;
+        match stored.pop() {
+            Some(moved) => {
+                // A value may be stored in several forms, only one of them is moved
+                for other in stored {
+                    self.inner.delete(src, &other)?;
+                }
+                self.inner.r#move(src, dst, &moved)
+            }
+            None => self.inner.r#move(src, dst, &self.encode(value)),
+        }
+    }
+
+    #[inline]
+    fn fetch(&self, key: &Input) -> Result<Fetch<Self::Source>, DatabaseError> {
+        let mut values: Vec<Example> = self
+            .inner
+            .fetch(key)?
+            .into_iter()
+            .map(|value| decode(&value).unwrap_or(value))
+            .collect();
+        // A value may be stored in both forms, e.g. if it was saved before compression was enabled
+        values.sort_unstable();
+        values.dedup();
+        Ok(Fetch::new(values))
+    }
+
+    #[inline]
+    fn keys(&self) -> Result<Vec<Vec<u8>>, DatabaseError> {
+        self.inner.keys()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::InMemoryExampleDatabase;
+
+    fn sorted(db: &impl ExampleDatabase, key: &Input) -> Vec<Example> {
+        let mut values = db.fetch(key).expect("Fetch failed").into_vec();
+        values.sort_unstable();
+        values
+    }
+
+    fn large() -> Example {
+        b"serialized buffer ".repeat(100)
+    }
+
+    #[test]
+    fn large_values_are_compressed() {
+        let db = CompressedDatabase::new(InMemoryExampleDatabase::new());
+        let value = large();
+        db.save(b"key", &value).expect("Save failed");
+        db.save(b"key", b"small").expect("Save failed");
+        let stored = sorted(db.get_ref(), b"key");
+        assert_eq!(stored.len(), 2);
+        assert_eq!(stored[0], b"small");
+        assert!(stored[1].starts_with(MARKER));
+        assert!(stored[1].len() < value.len());
+        assert_eq!(sorted(&db, b"key"), vec![value, b"small".to_vec()]);
+    }
+
+    #[test]
+    fn incompressible_values_are_stored_as_is() {
+        let db = CompressedDatabase::with_threshold(InMemoryExampleDatabase::new(), 0);
+        db.save(b"key", b"x").expect("Save failed");
+        assert_eq!(sorted(db.get_ref(), b"key"), vec![b"x".to_vec()]);
+        assert_eq!(sorted(&db, b"key"), vec![b"x".to_vec()]);
+    }
+
+    #[test]
+    fn values_that_look_like_the_format_round_trip() {
+        let db = CompressedDatabase::new(InMemoryExampleDatabase::new());
+        let value = [MARKER, &[DEFLATE], b"not compressed"].concat();
+        db.save(b"key", &value).expect("Save failed");
+        assert_eq!(sorted(&db, b"key"), vec![value.clone()]);
+        db.delete(b"key", &value).expect("Delete failed");
+        assert!(sorted(db.get_ref(), b"key").is_empty());
+    }
+
+    #[test]
+    fn legacy_values_are_readable() {
+        let inner = InMemoryExampleDatabase::new();
+        let value = large();
+        inner.save(b"key", &value).expect("Save failed");
+        inner.save(b"other", &value).expect("Save failed");
+        let db = CompressedDatabase::new(inner);
+        assert_eq!(sorted(&db, b"key"), vec![value.clone()]);
+        // Saving the value again stores it in the new format, but it is fetched once
+        db.save(b"key", &value).expect("Save failed");
+        assert_eq!(sorted(&db, b"key"), vec![value.clone()]);
+        db.delete(b"key", &value).expect("Delete failed");
+        assert!(sorted(db.get_ref(), b"key").is_empty());
+        db.r#move(b"other", b"moved", &value).expect("Move failed");
+        assert!(sorted(db.get_ref(), b"other").is_empty());
+        assert_eq!(sorted(&db, b"moved"), vec![value]);
+    }
+
+    #[test]
+    fn values_are_found_regardless_of_the_threshold() {
+        let value = large();
+        let inner = InMemoryExampleDatabase::new();
+        let db = CompressedDatabase::new(inner);
+        db.save(b"key", &value).expect("Save failed");
+        db.save(b"other", &value).expect("Save failed");
+        // E.g. the next run uses a different threshold, so the value is not compressed anymore
+        let db = CompressedDatabase::with_threshold(db.into_inner(), usize::MAX);
+        db.delete(b"key", &value).expect("Delete failed");
+        assert!(sorted(db.get_ref(), b"key").is_empty());
+        db.r#move(b"other", b"moved", &value).expect("Move failed");
+        assert!(sorted(db.get_ref(), b"other").is_empty());
+        assert_eq!(sorted(&db, b"moved"), vec![value]);
+    }
+
+    #[test]
+    fn decompressed_size_is_bounded() {
+        let mut encoder = DeflateEncoder::new(vec![], Compression::default());
+        encoder.write_all(&[0; 1 << 20]).expect("Can't compress");
+        let stream = encoder.finish().expect("Can't compress");
+        // The stream inflates to much more than the stated length
+        let value = [MARKER, &[DEFLATE], &16_u32.to_le_bytes(), &stream].concat();
+        let inner = InMemoryExampleDatabase::new();
+        inner.save(b"key", &value).expect("Save failed");
+        let db = CompressedDatabase::new(inner);
+        assert_eq!(sorted(&db, b"key"), vec![value]);
+    }
+}
@@ -1,25 +1,44 @@
+use crate::cache::{BoundedCache, CacheStats};
 use crate::fetch::Fetch;
-use crate::{Example, ExampleDatabase, Input};
+use crate::listeners::Listeners;
+use crate::metadata::{self, KeyMetadata};
+#[cfg(feature = "watch")]
+use crate::DirectoryWatcher;
+use crate::{ChangeEvent, DatabaseError, Example, ExampleDatabase, Input, ListenerHandle};
 use arrayvec::ArrayString;
 use rand::distributions::Alphanumeric;
 use rand::{thread_rng, Rng};
 use sha2::{Digest, Sha384};
-use std::cell::RefCell;
-use std::collections::HashMap;
+use std::ffi::OsStr;
 use std::fmt::Write as FmtWrite;
 use std::fs;
 use std::fs::ReadDir;
-use std::io::{Read, Write};
+use std::io::{self, Read, Write};
 use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::SystemTime;
 
-type Cache = HashMap<Vec<u8>, ArrayString<[u8; 16]>>;
+pub(crate) type Hash = ArrayString<[u8; 16]>;
+
+/// Number of key and value hashes that `DirectoryBasedExampleDatabase::new` keeps in memory.
+pub const DEFAULT_CACHE_CAPACITY: usize = 1024;
 
 #[derive(Debug)]
 /// Use a directory to store Hypothesis examples as files.
+///
+/// The database can be shared between threads, e.g. via `Arc`.
+///
+/// Hashes of recently used keys and values are cached, the least recently used ones are evicted
+/// once the cache is full.
+///
+/// Every key directory gets a metadata file next to it, that records the original key. It makes
+/// keys listable, see `DirectoryBasedExampleDatabase::key_metadata`. Python Hypothesis ignores
+/// these files, and keys created by it are not listed as they have no metadata.
 pub struct DirectoryBasedExampleDatabase {
     /// Path to the examples database.
     pub path: PathBuf,
-    cache: RefCell<Cache>,
+    cache: Mutex<BoundedCache<Hash>>,
+    listeners: Listeners,
 }
 
 macro_rules! hash {
@@ -34,70 +53,251 @@ macro_rules! hash {
     }};
 }
 
+/// Name of the file or directory that stores the given key or value.
+#[cfg(any(feature = "archive", feature = "watch"))]
+#[inline]
+pub(crate) fn file_name(input: &Input) -> Hash {
+    hash!(input)
+}
+
 impl DirectoryBasedExampleDatabase {
     /// Create a new example database that stores examples as files.
     pub fn new<P: AsRef<Path>>(path: P) -> DirectoryBasedExampleDatabase {
+        DirectoryBasedExampleDatabase::with_cache_capacity(path, DEFAULT_CACHE_CAPACITY)
+    }
+
+    /// Create a new example database that caches at most `capacity` hashes.
+    /// Zero capacity disables caching.
+    pub fn with_cache_capacity<P: AsRef<Path>>(
+        path: P,
+        capacity: usize,
+    ) -> DirectoryBasedExampleDatabase {
         DirectoryBasedExampleDatabase {
             path: path.as_ref().to_path_buf(),
-            cache: RefCell::new(HashMap::new()),
+            cache: Mutex::new(BoundedCache::new(capacity)),
+            listeners: Listeners::default(),
         }
     }
 
+    /// Register a function that is called after every change made via this database instance.
+    /// Changes made by other instances or processes are not reported.
     #[inline]
-    fn path_for_key(&self, key: &Input) -> PathBuf {
-        let mut cache = self.cache.borrow_mut();
-        if let Some(hashed) = cache.get(key) {
-            self.path.join(hashed.as_str())
-        } else {
-            let hashed = hash!(key);
-            cache.insert(key.to_vec(), hashed);
-            self.path.join(hashed.as_str())
-        }
+    pub fn add_listener(
+        &self,
+        listener: impl Fn(&ChangeEvent) + Send + Sync + 'static,
+    ) -> ListenerHandle {
+        self.listeners.add(listener)
     }
 
+    /// Stop calling the given listener. Returns whether it was registered.
     #[inline]
-    fn path_for_value(&self, key_path: &PathBuf, value: &Input) -> PathBuf {
-        let mut cache = self.cache.borrow_mut();
-        if let Some(hashed) = cache.get(value) {
-            key_path.join(hashed.as_str())
-        } else {
-            let hashed = hash!(value);
-            cache.insert(value.to_vec(), hashed);
-            key_path.join(hashed.as_str())
+    pub fn remove_listener(&self, handle: ListenerHandle) -> bool {
+        self.listeners.remove(handle)
+    }
+
+    /// Watch the database directory for changes made by any process, see `DirectoryWatcher`.
+    #[cfg(feature = "watch")]
+    #[inline]
+    pub fn watch(&self) -> Result<DirectoryWatcher, DatabaseError> {
+        DirectoryWatcher::new(&self.path)
+    }
+
+    /// Maximum number of cached hashes.
+    #[inline]
+    pub fn cache_capacity(&self) -> usize {
+        self.cache.lock().map_or(0, |cache| cache.capacity())
+    }
+
+    /// Number of currently cached hashes.
+    #[inline]
+    pub fn cache_len(&self) -> usize {
+        self.cache.lock().map_or(0, |cache| cache.len())
+    }
+
+    /// Hash cache hits and misses, see `CacheStats::hit_rate`.
+    #[inline]
+    pub fn cache_stats(&self) -> CacheStats {
+        self.cache
+            .lock()
+            .map(|cache| cache.stats())
+            .unwrap_or_default()
+    }
+
+    /// Hash of the given input. A poisoned cache is bypassed, as it is only an optimization.
+    #[inline]
+    fn hashed(&self, input: &Input) -> Hash {
+        match self.cache.lock() {
+            Ok(mut cache) => cache.get_or_insert_with(input, |input| hash!(input)),
+            Err(_) => hash!(input),
+        }
+    }
+
+    /// Metadata of all keys that have it, in arbitrary order.
+    pub fn key_metadata(&self) -> Result<Vec<KeyMetadata>, DatabaseError> {
+        let entries = match fs::read_dir(&self.path) {
+            Ok(entries) => entries,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+            Err(error) => return Err(DatabaseError::io(&self.path, error)),
+        };
+        let mut result = vec![];
+        for entry in entries {
+            let path = entry
+                .map_err(|error| DatabaseError::io(&self.path, error))?
+                .path();
+            if path.extension() == Some(OsStr::new(metadata::EXTENSION)) {
+                // Metadata may be removed together with its key directory after listing
+                if let Some(metadata) = self.read_metadata(&path)? {
+                    result.push(metadata);
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    /// Metadata of the given key, if there is any.
+    pub fn metadata(&self, key: &Input) -> Result<Option<KeyMetadata>, DatabaseError> {
+        let key_path = self.path_for_key(key);
+        self.read_metadata(&key_path.with_extension(metadata::EXTENSION))
+    }
+
+    fn read_metadata(&self, path: &Path) -> Result<Option<KeyMetadata>, DatabaseError> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(error) if error.kind() == io::ErrorKind::InvalidData => {
+                return Err(DatabaseError::Corrupted(
+                    path.to_path_buf(),
+                    "metadata is not valid UTF-8".to_string(),
+                ))
+            }
+            Err(error) => return Err(DatabaseError::io(path, error)),
+        };
+        let (key, created) = metadata::decode(path, &contents)?;
+        let key_path = self.path_for_key(&key);
+        if key_path.with_extension(metadata::EXTENSION) != path {
+            return Err(DatabaseError::Corrupted(
+                path.to_path_buf(),
+                "metadata belongs to a different key".to_string(),
+            ));
         }
+        // The count is not stored, so it stays correct when other processes, e.g. Python
+        // Hypothesis, change the examples
+        let examples = count_examples(&key_path)?;
+        Ok(Some(KeyMetadata {
+            key,
+            path: key_path,
+            created,
+            examples,
+        }))
+    }
+
+    /// Record the original key next to its newly created directory.
+    fn write_metadata(&self, key: &Input, key_path: &Path) -> Result<(), DatabaseError> {
+        let contents = metadata::encode(key, SystemTime::now());
+        write_atomically(
+            &key_path.with_extension(metadata::EXTENSION),
+            contents.as_bytes(),
+        )
+    }
+
+    #[inline]
+    fn path_for_key(&self, key: &Input) -> PathBuf {
+        self.path.join(self.hashed(key).as_str())
+    }
+
+    #[inline]
+    fn path_for_value(&self, key_path: &Path, value: &Input) -> PathBuf {
+        key_path.join(self.hashed(value).as_str())
+    }
+}
+
+/// Every key is stored as a directory, a file in its place means that the database is broken.
+/// Returns whether the directory is created.
+fn ensure_key_directory(path: &Path) -> Result<bool, DatabaseError> {
+    if path.is_dir() {
+        Ok(false)
+    } else if path.exists() {
+        Err(DatabaseError::Corrupted(
+            path.to_path_buf(),
+            "expected a key directory, found a file".to_string(),
+        ))
+    } else {
+        fs::create_dir_all(path).map_err(|error| DatabaseError::io(path, error))?;
+        Ok(true)
+    }
+}
+
+/// Number of values in the key directory, temporary files are not counted.
+fn count_examples(key_path: &Path) -> Result<usize, DatabaseError> {
+    match fs::read_dir(key_path) {
+        Ok(entries) => Ok(entries
+            .filter_map(Result::ok)
+            .filter(|entry| entry.path().extension().is_none())
+            .count()),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(0),
+        Err(error) => Err(DatabaseError::io(key_path, error)),
     }
 }
 
+fn write_file(path: &Path, value: &Input) -> io::Result<()> {
+    let mut target = fs::File::create(path)?;
+    target.write_all(value)?;
+    target.sync_all()
+}
+
+/// Write into a temporary file first, so readers never see partially written contents.
+fn write_atomically(path: &Path, value: &Input) -> Result<(), DatabaseError> {
+    let suffix: String = thread_rng().sample_iter(&Alphanumeric).take(16).collect();
+    let tmpname = path.with_extension(suffix);
+    if let Err(error) = write_file(&tmpname, value) {
+        // The temporary file may be partially written, it is not an example anymore
+        let _ = fs::remove_file(&tmpname);
+        return Err(DatabaseError::io(&tmpname, error));
+    }
+    if fs::rename(&tmpname, path).is_err() {
+        fs::remove_file(&tmpname).map_err(|error| DatabaseError::io(&tmpname, error))?;
+    }
+    Ok(())
+}
+
 impl ExampleDatabase for DirectoryBasedExampleDatabase {
     type Source = Directory;
     #[inline]
-    fn save(&mut self, key: &Input, value: &Input) {
+    fn save(&self, key: &Input, value: &Input) -> Result<(), DatabaseError> {
         let key_path = self.path_for_key(key);
-        if !key_path.exists() {
-            fs::create_dir_all(&key_path).expect("Can't create a directory");
+        if ensure_key_directory(&key_path)? {
+            self.write_metadata(key, &key_path)?;
         }
         let value_path = self.path_for_value(&key_path, value);
         if !value_path.exists() {
-            let suffix: String = thread_rng().sample_iter(&Alphanumeric).take(16).collect();
-            let tmpname = value_path.with_extension(suffix);
-            let mut target = fs::File::create(&tmpname).expect("Can't create a file");
-            target.write_all(value).expect("Write error");
-            target.sync_all().expect("Can't sync data to disk");
-            if fs::rename(&tmpname, value_path).is_err() {
-                fs::remove_file(&tmpname).expect("Can't remove a temporary file");
-            }
+            write_atomically(&value_path, value)?;
+            self.listeners.emit(|| ChangeEvent::Save {
+                key: key.to_vec(),
+                value: value.to_vec(),
+            });
         }
+        Ok(())
     }
 
     #[inline]
-    fn delete(&mut self, key: &Input, value: &Input) {
+    fn delete(&self, key: &Input, value: &Input) -> Result<(), DatabaseError> {
         let key_path = self.path_for_key(key);
         let value_path = self.path_for_value(&key_path, value);
-        let _ = fs::remove_file(&value_path);
+        match fs::remove_file(&value_path) {
+            Ok(()) => {
+                self.listeners.emit(|| ChangeEvent::Delete {
+                    key: key.to_vec(),
+                    value: Some(value.to_vec()),
+                });
+                Ok(())
+            }
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(()),
+            Err(error) => Err(DatabaseError::io(&value_path, error)),
+        }
     }
 
     #[inline]
-    fn r#move(&mut self, src: &Input, dst: &Input, value: &Input) {
+    fn r#move(&self, src: &Input, dst: &Input, value: &Input) -> Result<(), DatabaseError> {
         if src == dst {
             self.save(src, value)
         } else {
@@ -107,31 +307,111 @@ impl ExampleDatabase for DirectoryBasedExampleDatabase {
                 self.path_for_value(&src_path, value),
                 self.path_for_value(&dst_path, value),
             )
-            .is_err()
+            .is_ok()
             {
-                self.delete(src, value);
-                self.save(dst, value)
+                self.listeners.emit(|| ChangeEvent::Delete {
+                    key: src.to_vec(),
+                    value: Some(value.to_vec()),
+                });
+                self.listeners.emit(|| ChangeEvent::Save {
+                    key: dst.to_vec(),
+                    value: value.to_vec(),
+                });
+            } else {
+                self.delete(src, value)?;
+                self.save(dst, value)?;
             }
+            Ok(())
         }
     }
 
     #[inline]
-    fn fetch(&self, key: &Input) -> Fetch<Self::Source> {
-        Fetch::new(Directory {
-            path: self.path_for_key(key),
-        })
+    fn fetch(&self, key: &Input) -> Result<Fetch<Self::Source>, DatabaseError> {
+        let path = self.path_for_key(key);
+        let entries = match fs::read_dir(&path) {
+            Ok(entries) => Some(entries),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => None,
+            Err(_) if path.is_file() => {
+                return Err(DatabaseError::Corrupted(
+                    path,
+                    "expected a key directory, found a file".to_string(),
+                ))
+            }
+            Err(error) => return Err(DatabaseError::io(&path, error)),
+        };
+        Ok(Fetch::new(Directory { path, entries }))
+    }
+
+    #[inline]
+    fn keys(&self) -> Result<Vec<Vec<u8>>, DatabaseError> {
+        Ok(self
+            .key_metadata()?
+            .into_iter()
+            .filter(|metadata| metadata.examples > 0)
+            .map(|metadata| metadata.key)
+            .collect())
     }
 }
 
 #[derive(Debug)]
 pub struct Directory {
     path: PathBuf,
+    entries: Option<ReadDir>,
 }
 
 #[derive(Debug)]
 /// Iterates over files in a directory and yields examples.
+///
+/// Entries that can't be read are skipped, the corresponding errors are available via
+/// `FileIterator::errors`. Use `FileIterator::results` to handle them as they occur instead.
 pub struct FileIterator {
+    path: PathBuf,
     entries: Option<ReadDir>,
+    errors: Vec<DatabaseError>,
+}
+
+impl FileIterator {
+    /// Errors for the entries that were skipped so far.
+    #[inline]
+    pub fn errors(&self) -> &[DatabaseError] {
+        &self.errors
+    }
+
+    /// Yield errors for unreadable entries alongside examples.
+    #[inline]
+    pub fn results(self) -> FileResults {
+        FileResults { inner: self }
+    }
+
+    fn next_entry(&mut self) -> Option<Result<Example, DatabaseError>> {
+        let entries = self.entries.as_mut()?;
+        loop {
+            let path = match entries.next()? {
+                Ok(entry) => entry.path(),
+                Err(error) => return Some(Err(DatabaseError::io(&self.path, error))),
+            };
+            // Values are stored under their hashes, files with an extension are temporary ones
+            // created by an in-progress `save`
+            if path.extension().is_some() {
+                continue;
+            }
+            match read_file(&path) {
+                Ok(contents) => return Some(Ok(contents)),
+                // Files may disappear after they are listed, e.g. if other thread or process
+                // moves them away. It is not an error
+                Err(error) if error.kind() == io::ErrorKind::NotFound => continue,
+                Err(error) => return Some(Err(DatabaseError::io(path, error))),
+            }
+        }
+    }
+}
+
+fn read_file(path: &Path) -> io::Result<Example> {
+    let mut file = fs::File::open(path)?;
+    // We don't need buffered reads here, we'd like to make one read
+    let mut contents = Vec::with_capacity(32);
+    file.read_to_end(&mut contents)?;
+    Ok(contents)
 }
 
 impl Iterator for FileIterator {
@@ -139,35 +419,41 @@ impl Iterator for FileIterator {
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        if let Some(entries) = &mut self.entries {
-            if let Some(Ok(entry)) = entries.next() {
-                if let Ok(mut file) = fs::File::open(entry.path()) {
-                    // We don't need buffered reads here, we'd like to make one read
-                    let mut contents = Vec::with_capacity(32);
-                    if file.read_to_end(&mut contents).is_ok() {
-                        return Some(contents);
-                    }
-                }
+        loop {
+            match self.next_entry()? {
+                Ok(example) => return Some(example),
+                Err(error) => self.errors.push(error),
             }
-            None
-        } else {
-            None
         }
     }
 }
 
+#[derive(Debug)]
+/// Iterates over files in a directory and yields examples or errors for unreadable entries.
+pub struct FileResults {
+    inner: FileIterator,
+}
+
+impl Iterator for FileResults {
+    type Item = Result<Example, DatabaseError>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next_entry()
+    }
+}
+
 impl IntoIterator for Directory {
     type Item = Example;
     type IntoIter = FileIterator;
 
     #[inline]
     fn into_iter(self) -> Self::IntoIter {
-        let entries = if !self.path.exists() {
-            None
-        } else {
-            Some(fs::read_dir(self.path).expect("Can't read the directory"))
-        };
-        FileIterator { entries }
+        FileIterator {
+            path: self.path,
+            entries: self.entries,
+            errors: vec![],
+        }
     }
 }
 
@@ -182,51 +468,14 @@ pub(crate) mod tests_util {
     use super::*;
     use tempdir::TempDir;
 
-    pub(crate) struct TestDatabase {
-        _temp: TempDir,
-        db: DirectoryBasedExampleDatabase,
-        pub(crate) path: String,
-    }
+    pub(crate) type TestDatabase =
+        crate::tests_util::TestDatabase<DirectoryBasedExampleDatabase, TempDir>;
 
     impl TestDatabase {
         pub(crate) fn new() -> Self {
             let dir = TempDir::new("test-db").expect("Should always work");
-            let path = dir.path().to_str().expect("This path is UTF-8").to_string();
-            let db = DirectoryBasedExampleDatabase::new(path.clone());
-            TestDatabase {
-                _temp: dir,
-                db,
-                path,
-            }
-        }
-
-        pub(crate) fn from_string(path: String) -> Self {
-            let db = DirectoryBasedExampleDatabase::new(path.clone());
-            TestDatabase {
-                _temp: TempDir::new("test-db").expect("Should always work"),
-                db,
-                path,
-            }
-        }
-    }
-
-    impl ExampleDatabase for TestDatabase {
-        type Source = Directory;
-
-        fn save(&mut self, key: &Input, value: &Input) {
-            self.db.save(key, value)
-        }
-
-        fn delete(&mut self, key: &Input, value: &Input) {
-            self.db.delete(key, value)
-        }
-
-        fn r#move(&mut self, src: &Input, dst: &Input, value: &Input) {
-            self.db.r#move(src, dst, value)
-        }
-
-        fn fetch(&self, key: &Input) -> Fetch<Self::Source> {
-            self.db.fetch(key)
+            let db = DirectoryBasedExampleDatabase::new(dir.path());
+            TestDatabase::with_guard(db, dir)
         }
     }
 }
@@ -234,18 +483,195 @@ pub(crate) mod tests_util {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::sync::{Arc, Mutex};
+    use tempdir::TempDir;
 
     #[test]
     fn two_directory_databases_can_interact() {
-        let mut db1 = tests_util::TestDatabase::new();
-        let mut db2 = tests_util::TestDatabase::from_string(db1.path.clone());
-        assert_eq!(db1.path, db2.path);
-        db1.save(b"foo", b"bar");
-        assert_eq!(db2.fetch(b"foo").into_vec(), vec![b"bar"]);
-        db2.save(b"foo", b"bar");
-        db2.save(b"foo", b"baz");
-        let mut result = db1.fetch(b"foo").into_vec();
+        let db1 = tests_util::TestDatabase::new();
+        let db2 = DirectoryBasedExampleDatabase::new(&db1.db.path);
+        db1.save(b"foo", b"bar").expect("Save failed");
+        assert_eq!(
+            db2.fetch(b"foo").expect("Fetch failed").into_vec(),
+            vec![b"bar"]
+        );
+        db2.save(b"foo", b"bar").expect("Save failed");
+        db2.save(b"foo", b"baz").expect("Save failed");
+        let mut result = db1.fetch(b"foo").expect("Fetch failed").into_vec();
         result.sort_unstable();
         assert_eq!(result, vec![b"bar", b"baz"]);
     }
+
+    #[test]
+    fn key_file_is_reported_as_corruption() {
+        let temp = TempDir::new("test-db").expect("Should always work");
+        let db = DirectoryBasedExampleDatabase::new(temp.path());
+        let key_path = db.path_for_key(b"foo");
+        fs::write(&key_path, b"not a directory").expect("Can't write a file");
+        assert!(matches!(
+            db.save(b"foo", b"bar"),
+            Err(DatabaseError::Corrupted(path, _)) if path == key_path
+        ));
+        assert!(matches!(
+            db.fetch(b"foo"),
+            Err(DatabaseError::Corrupted(path, _)) if path == key_path
+        ));
+    }
+
+    #[test]
+    fn unusable_root_is_reported_as_io_error() {
+        let temp = TempDir::new("test-db").expect("Should always work");
+        let root = temp.path().join("file");
+        fs::write(&root, b"").expect("Can't write a file");
+        // The database root is a file, therefore no key directory can be created inside it
+        let db = DirectoryBasedExampleDatabase::new(&root);
+        let error = db.save(b"foo", b"bar").expect_err("Save should fail");
+        assert!(matches!(error, DatabaseError::Io(_, _)));
+        assert!(error.path().starts_with(&root));
+    }
+
+    #[test]
+    fn hash_cache_is_bounded() {
+        let temp = TempDir::new("test-db").expect("Should always work");
+        let db = DirectoryBasedExampleDatabase::with_cache_capacity(temp.path(), 2);
+        db.save(b"key", b"first").expect("Save failed");
+        db.save(b"key", b"second").expect("Save failed");
+        assert_eq!(db.cache_len(), 2);
+        assert_eq!(db.cache_stats(), CacheStats { hits: 1, misses: 3 });
+        // The hash of `first` is evicted, as it is the least recently used one
+        db.save(b"key", b"second").expect("Save failed");
+        db.save(b"key", b"first").expect("Save failed");
+        assert_eq!(db.cache_len(), 2);
+        assert_eq!(db.cache_stats(), CacheStats { hits: 4, misses: 4 });
+        assert_eq!(db.cache_capacity(), 2);
+        let mut values = db.fetch(b"key").expect("Fetch failed").into_vec();
+        values.sort_unstable();
+        assert_eq!(values, vec![b"first".to_vec(), b"second".to_vec()]);
+    }
+
+    #[test]
+    fn unreadable_entries_are_skipped_and_reported() {
+        let temp = TempDir::new("test-db").expect("Should always work");
+        let db = DirectoryBasedExampleDatabase::new(temp.path());
+        db.save(b"key", b"value").expect("Save failed");
+        let key_path = db.path_for_key(b"key");
+        // A directory in place of a value file can't be read
+        let broken = key_path.join("0123456789abcdef");
+        fs::create_dir(&broken).expect("Can't create a directory");
+        // Leftover of an interrupted `save`
+        fs::write(key_path.join("0123456789abcdef.tmp"), b"partial").expect("Can't write a file");
+        let mut examples = db.fetch(b"key").expect("Fetch failed").into_iter();
+        assert_eq!(
+            examples.by_ref().collect::<Vec<_>>(),
+            vec![b"value".to_vec()]
+        );
+        assert_eq!(examples.errors().len(), 1);
+        assert_eq!(examples.errors()[0].path(), broken);
+        let results: Vec<_> = db
+            .fetch(b"key")
+            .expect("Fetch failed")
+            .into_iter()
+            .results()
+            .collect();
+        assert_eq!(results.len(), 2);
+        assert!(results
+            .iter()
+            .any(|result| matches!(result, Ok(value) if value == b"value")));
+        assert!(results
+            .iter()
+            .any(|result| matches!(result, Err(DatabaseError::Io(path, _)) if *path == broken)));
+    }
+
+    #[test]
+    fn keys_are_listed_with_metadata() {
+        let temp = TempDir::new("test-db").expect("Should always work");
+        let db = DirectoryBasedExampleDatabase::new(temp.path());
+        db.save(b"foo", b"bar").expect("Save failed");
+        db.save(b"foo", b"baz").expect("Save failed");
+        db.save(b"\x00\xff", b"bar").expect("Save failed");
+        let metadata = db
+            .metadata(b"foo")
+            .expect("Metadata is readable")
+            .expect("Metadata exists");
+        assert_eq!(metadata.key, b"foo");
+        assert_eq!(metadata.path, db.path_for_key(b"foo"));
+        assert_eq!(metadata.examples, 2);
+        assert!(metadata.created <= SystemTime::now());
+        assert!(db
+            .metadata(b"unknown")
+            .expect("Metadata is readable")
+            .is_none());
+        let mut keys = db.keys().expect("Keys are listable");
+        keys.sort_unstable();
+        assert_eq!(keys, vec![b"\x00\xff".to_vec(), b"foo".to_vec()]);
+        // Metadata files don't turn into examples
+        assert_eq!(db.fetch(b"foo").expect("Fetch failed").into_vec().len(), 2);
+    }
+
+    #[test]
+    fn key_directories_without_metadata_are_not_listed() {
+        let temp = TempDir::new("test-db").expect("Should always work");
+        let db = DirectoryBasedExampleDatabase::new(temp.path());
+        db.save(b"foo", b"bar").expect("Save failed");
+        // E.g. created by Python Hypothesis
+        let key_path = db.path_for_key(b"foo");
+        fs::remove_file(key_path.with_extension(metadata::EXTENSION)).expect("Can't remove a file");
+        assert!(db.keys().expect("Keys are listable").is_empty());
+        assert_eq!(
+            db.fetch(b"foo").expect("Fetch failed").into_vec(),
+            vec![b"bar"]
+        );
+    }
+
+    #[test]
+    fn mismatched_metadata_is_reported_as_corruption() {
+        let temp = TempDir::new("test-db").expect("Should always work");
+        let db = DirectoryBasedExampleDatabase::new(temp.path());
+        db.save(b"foo", b"bar").expect("Save failed");
+        let meta_path = db.path_for_key(b"foo").with_extension(metadata::EXTENSION);
+        fs::write(&meta_path, metadata::encode(b"other", SystemTime::now()))
+            .expect("Can't write a file");
+        assert!(matches!(
+            db.keys(),
+            Err(DatabaseError::Corrupted(path, _)) if path == meta_path
+        ));
+    }
+
+    #[test]
+    fn listeners_receive_changes() {
+        let temp = TempDir::new("test-db").expect("Should always work");
+        let db = DirectoryBasedExampleDatabase::new(temp.path());
+        let events = Arc::new(Mutex::new(vec![]));
+        let handle = {
+            let events = Arc::clone(&events);
+            db.add_listener(move |event| events.lock().expect("Not poisoned").push(event.clone()))
+        };
+        db.save(b"foo", b"bar").expect("Save failed");
+        db.save(b"foo", b"bar").expect("Save failed");
+        db.save(b"moved", b"baz").expect("Save failed");
+        // Renamed into an existing key directory
+        db.r#move(b"foo", b"moved", b"bar").expect("Move failed");
+        db.delete(b"moved", b"baz").expect("Delete failed");
+        db.delete(b"moved", b"baz").expect("Delete failed");
+        assert!(db.remove_listener(handle));
+        db.save(b"foo", b"baz").expect("Save failed");
+        let saved = |key: &[u8], value: &[u8]| ChangeEvent::Save {
+            key: key.to_vec(),
+            value: value.to_vec(),
+        };
+        let deleted = |key: &[u8], value: &[u8]| ChangeEvent::Delete {
+            key: key.to_vec(),
+            value: Some(value.to_vec()),
+        };
+        assert_eq!(
+            *events.lock().expect("Not poisoned"),
+            vec![
+                saved(b"foo", b"bar"),
+                saved(b"moved", b"baz"),
+                deleted(b"foo", b"bar"),
+                saved(b"moved", b"bar"),
+                deleted(b"moved", b"baz"),
+            ]
+        );
+    }
 }
@@ -0,0 +1,79 @@
+use std::path::{Path, PathBuf};
+use std::{error, fmt, io};
+
+/// Errors that may happen during database operations.
+#[derive(Debug)]
+pub enum DatabaseError {
+    /// An I/O operation on the given path failed.
+    Io(PathBuf, io::Error),
+    /// The process is not allowed to access the given path.
+    PermissionDenied(PathBuf, io::Error),
+    /// The database contents at the given path are not in the expected format.
+    Corrupted(PathBuf, String),
+}
+
+impl DatabaseError {
+    /// Wrap an I/O error, distinguishing permission errors from other ones.
+    #[inline]
+    pub fn io<P: AsRef<Path>>(path: P, error: io::Error) -> DatabaseError {
+        let path = path.as_ref().to_path_buf();
+        if error.kind() == io::ErrorKind::PermissionDenied {
+            DatabaseError::PermissionDenied(path, error)
+        } else {
+            DatabaseError::Io(path, error)
+        }
+    }
+
+    /// Path that the error is related to.
+    #[inline]
+    pub fn path(&self) -> &Path {
+        match self {
+            DatabaseError::Io(path, _)
+            | DatabaseError::PermissionDenied(path, _)
+            | DatabaseError::Corrupted(path, _) => path,
+        }
+    }
+}
+
+impl error::Error for DatabaseError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            DatabaseError::Io(_, error) | DatabaseError::PermissionDenied(_, error) => Some(error),
+            DatabaseError::Corrupted(_, _) => None,
+        }
+    }
+}
+
+impl fmt::Display for DatabaseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DatabaseError::Io(path, error) => {
+                f.write_fmt(format_args!("I/O error at {}: {}", path.display(), error))
+            }
+            DatabaseError::PermissionDenied(path, error) => f.write_fmt(format_args!(
+                "Permission denied at {}: {}",
+                path.display(),
+                error
+            )),
+            DatabaseError::Corrupted(path, reason) => f.write_fmt(format_args!(
+                "Database is corrupted at {}: {}",
+                path.display(),
+                reason
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn permission_errors_are_distinguished() {
+        let error = DatabaseError::io("/db", io::Error::from(io::ErrorKind::PermissionDenied));
+        assert!(matches!(error, DatabaseError::PermissionDenied(_, _)));
+        let error = DatabaseError::io("/db", io::Error::from(io::ErrorKind::Other));
+        assert!(matches!(error, DatabaseError::Io(_, _)));
+        assert_eq!(error.path(), Path::new("/db"));
+    }
+}
@@ -1,4 +1,10 @@
 //! Proof-of-concept implementation of example databases for Hypothesis.
+//!
+//! Parts that need extra dependencies are behind cargo features:
+//! - `archive` - exporting and importing archives, and `ZipExampleDatabase`;
+//! - `compression` - `CompressedDatabase`;
+//! - `sqlite` - `SqliteExampleDatabase`;
+//! - `watch` - `DirectoryWatcher`.
 #![warn(
     clippy::cast_possible_truncation,
     clippy::doc_markdown,
@@ -19,16 +25,57 @@
     unused_qualifications,
     unreachable_pub,
     variant_size_differences,
-    clippy::integer_arithmetic,
+    clippy::arithmetic_side_effects,
     clippy::unwrap_used
 )]
 
+#[cfg(feature = "archive")]
+mod archive;
+mod cache;
+#[cfg(feature = "compression")]
+mod compressed;
 mod directory;
+mod error;
 mod fetch;
+mod listeners;
+mod log;
 mod memory;
-pub use directory::{DirectoryBasedExampleDatabase, FileIterator};
+mod metadata;
+mod multiplexed;
+mod readonly;
+mod redis;
+#[cfg(feature = "sqlite")]
+mod sqlite;
+#[cfg(test)]
+mod tests_util;
+#[cfg(feature = "watch")]
+mod watcher;
+#[cfg(feature = "archive")]
+mod zipped;
+#[cfg(feature = "archive")]
+pub use archive::{export, export_all, import, ImportSummary};
+pub use cache::CacheStats;
+#[cfg(feature = "compression")]
+pub use compressed::{CompressedDatabase, DEFAULT_COMPRESSION_THRESHOLD};
+pub use directory::{
+    DirectoryBasedExampleDatabase, FileIterator, FileResults, DEFAULT_CACHE_CAPACITY,
+};
+pub use error::DatabaseError;
 pub use fetch::Fetch;
+pub use listeners::{ChangeEvent, ListenerHandle};
+pub use log::{LogExampleDatabase, DEFAULT_COMPACTION_THRESHOLD};
 pub use memory::InMemoryExampleDatabase;
+pub use metadata::KeyMetadata;
+pub use multiplexed::{MultiplexedDatabase, MultiplexedFetch};
+pub use readonly::ReadOnlyDatabase;
+pub use redis::{RedisExampleDatabase, DEFAULT_EXPIRE_AFTER, DEFAULT_KEY_PREFIX};
+#[cfg(feature = "sqlite")]
+pub use sqlite::SqliteExampleDatabase;
+use std::sync::Arc;
+#[cfg(feature = "watch")]
+pub use watcher::{DirectoryWatcher, DEFAULT_POLL_INTERVAL};
+#[cfg(feature = "archive")]
+pub use zipped::ZipExampleDatabase;
 
 /// Any input that the database can work with.
 pub type Input = [u8];
@@ -42,25 +89,58 @@ pub trait ExampleDatabase {
 
     /// Save `value` under `key`.
     /// If this value is already present for this key, silently do nothing
-    fn save(&mut self, key: &Input, value: &Input);
+    fn save(&self, key: &Input, value: &Input) -> Result<(), DatabaseError>;
 
     /// Remove this `value` from this `key`.
     /// If this value is not present, silently do nothing.
-    fn delete(&mut self, key: &Input, value: &Input);
+    fn delete(&self, key: &Input, value: &Input) -> Result<(), DatabaseError>;
 
     /// Move `value` from key `src` to key `dst`.
     #[inline]
-    fn r#move(&mut self, src: &Input, dst: &Input, value: &Input) {
+    fn r#move(&self, src: &Input, dst: &Input, value: &Input) -> Result<(), DatabaseError> {
         if src == dst {
             self.save(src, value)
         } else {
-            self.delete(src, value);
+            self.delete(src, value)?;
             self.save(dst, value)
         }
     }
 
     /// Return an iterable over all values matching this key.
-    fn fetch(&self, key: &Input) -> Fetch<Self::Source>;
+    fn fetch(&self, key: &Input) -> Result<Fetch<Self::Source>, DatabaseError>;
+
+    /// Return all keys that have at least one value, in arbitrary order.
+    fn keys(&self) -> Result<Vec<Vec<u8>>, DatabaseError>;
+}
+
+/// Databases behind `Arc` can be shared between threads or wrapped into several other databases.
+impl<D: ExampleDatabase + ?Sized> ExampleDatabase for Arc<D> {
+    type Source = D::Source;
+
+    #[inline]
+    fn save(&self, key: &Input, value: &Input) -> Result<(), DatabaseError> {
+        (**self).save(key, value)
+    }
+
+    #[inline]
+    fn delete(&self, key: &Input, value: &Input) -> Result<(), DatabaseError> {
+        (**self).delete(key, value)
+    }
+
+    #[inline]
+    fn r#move(&self, src: &Input, dst: &Input, value: &Input) -> Result<(), DatabaseError> {
+        (**self).r#move(src, dst, value)
+    }
+
+    #[inline]
+    fn fetch(&self, key: &Input) -> Result<Fetch<Self::Source>, DatabaseError> {
+        (**self).fetch(key)
+    }
+
+    #[inline]
+    fn keys(&self) -> Result<Vec<Vec<u8>>, DatabaseError> {
+        (**self).keys()
+    }
 }
 
 #[cfg(feature = "benchmark")]
@@ -71,62 +151,226 @@ mod tests {
     use super::*;
     use test_case::test_case;
 
-    #[test_case(InMemoryExampleDatabase::new())]
-    #[test_case(directory::tests_util::TestDatabase::new())]
-    fn can_iterate(mut db: impl ExampleDatabase) {
-        db.save(b"foo", b"bar");
-        for example in db.fetch(b"foo") {
+    fn multiplexed() -> MultiplexedDatabase {
+        let mut db = MultiplexedDatabase::new();
+        db.push(InMemoryExampleDatabase::new());
+        db.push(directory::tests_util::TestDatabase::new());
+        db
+    }
+
+    #[test_case(&InMemoryExampleDatabase::new())]
+    #[test_case(&directory::tests_util::TestDatabase::new())]
+    #[cfg_attr(feature = "sqlite", test_case(&sqlite::tests_util::TestDatabase::new()))]
+    #[test_case(&log::tests_util::TestDatabase::new())]
+    #[test_case(&redis::tests_util::TestDatabase::new())]
+    #[cfg_attr(
+        feature = "compression",
+        test_case(&CompressedDatabase::with_threshold(InMemoryExampleDatabase::new(), 0))
+    )]
+    #[test_case(&multiplexed())]
+    fn can_iterate(db: &impl ExampleDatabase) {
+        db.save(b"foo", b"bar").expect("Save failed");
+        for example in db.fetch(b"foo").expect("Fetch failed") {
             assert_eq!(example, b"bar")
         }
-        assert_eq!(db.fetch(b"foo").into_vec(), vec![b"bar"])
+        assert_eq!(
+            db.fetch(b"foo").expect("Fetch failed").into_vec(),
+            vec![b"bar"]
+        )
     }
 
-    #[test_case(InMemoryExampleDatabase::new())]
-    #[test_case(directory::tests_util::TestDatabase::new())]
-    fn can_delete_a_key_that_is_not_present(mut db: impl ExampleDatabase) {
-        db.delete(b"foo", b"bar");
+    #[test_case(&InMemoryExampleDatabase::new())]
+    #[test_case(&directory::tests_util::TestDatabase::new())]
+    #[cfg_attr(feature = "sqlite", test_case(&sqlite::tests_util::TestDatabase::new()))]
+    #[test_case(&log::tests_util::TestDatabase::new())]
+    #[test_case(&redis::tests_util::TestDatabase::new())]
+    #[cfg_attr(
+        feature = "compression",
+        test_case(&CompressedDatabase::with_threshold(InMemoryExampleDatabase::new(), 0))
+    )]
+    #[test_case(&multiplexed())]
+    #[test_case(&ReadOnlyDatabase::new(InMemoryExampleDatabase::new()))]
+    fn can_delete_a_key_that_is_not_present(db: &impl ExampleDatabase) {
+        db.delete(b"foo", b"bar").expect("Delete failed");
     }
 
     #[test_case(&InMemoryExampleDatabase::new())]
     #[test_case(&directory::tests_util::TestDatabase::new())]
+    #[cfg_attr(feature = "sqlite", test_case(&sqlite::tests_util::TestDatabase::new()))]
+    #[test_case(&log::tests_util::TestDatabase::new())]
+    #[test_case(&redis::tests_util::TestDatabase::new())]
+    #[cfg_attr(
+        feature = "compression",
+        test_case(&CompressedDatabase::with_threshold(InMemoryExampleDatabase::new(), 0))
+    )]
+    #[test_case(&multiplexed())]
+    #[test_case(&ReadOnlyDatabase::new(InMemoryExampleDatabase::new()))]
     fn can_fetch_a_key_that_is_not_present(db: &impl ExampleDatabase) {
-        assert_eq!(db.fetch(b"bar").into_vec().len(), 0)
+        assert_eq!(db.fetch(b"bar").expect("Fetch failed").into_vec().len(), 0)
     }
 
-    #[test_case(InMemoryExampleDatabase::new())]
-    #[test_case(directory::tests_util::TestDatabase::new())]
-    fn saving_a_key_twice_fetches_it_once(mut db: impl ExampleDatabase) {
-        db.save(b"foo", b"bar");
-        db.save(b"foo", b"bar");
-        assert_eq!(db.fetch(b"foo").into_vec(), vec![b"bar"])
+    #[test_case(&InMemoryExampleDatabase::new())]
+    #[test_case(&directory::tests_util::TestDatabase::new())]
+    #[cfg_attr(feature = "sqlite", test_case(&sqlite::tests_util::TestDatabase::new()))]
+    #[test_case(&log::tests_util::TestDatabase::new())]
+    #[test_case(&redis::tests_util::TestDatabase::new())]
+    #[cfg_attr(
+        feature = "compression",
+        test_case(&CompressedDatabase::with_threshold(InMemoryExampleDatabase::new(), 0))
+    )]
+    #[test_case(&multiplexed())]
+    fn saving_a_key_twice_fetches_it_once(db: &impl ExampleDatabase) {
+        db.save(b"foo", b"bar").expect("Save failed");
+        db.save(b"foo", b"bar").expect("Save failed");
+        assert_eq!(
+            db.fetch(b"foo").expect("Fetch failed").into_vec(),
+            vec![b"bar"]
+        )
     }
 
-    #[test_case(InMemoryExampleDatabase::new())]
-    #[test_case(directory::tests_util::TestDatabase::new())]
-    fn an_absent_value_is_present_after_it_moves(mut db: impl ExampleDatabase) {
-        db.r#move(b"a", b"b", b"c");
-        assert_eq!(db.fetch(b"b").into_vec(), vec![b"c"])
+    #[test_case(&InMemoryExampleDatabase::new())]
+    #[test_case(&directory::tests_util::TestDatabase::new())]
+    #[cfg_attr(feature = "sqlite", test_case(&sqlite::tests_util::TestDatabase::new()))]
+    #[test_case(&log::tests_util::TestDatabase::new())]
+    #[test_case(&redis::tests_util::TestDatabase::new())]
+    #[cfg_attr(
+        feature = "compression",
+        test_case(&CompressedDatabase::with_threshold(InMemoryExampleDatabase::new(), 0))
+    )]
+    #[test_case(&multiplexed())]
+    fn an_absent_value_is_present_after_it_moves(db: &impl ExampleDatabase) {
+        db.r#move(b"a", b"b", b"c").expect("Move failed");
+        assert_eq!(db.fetch(b"b").expect("Fetch failed").into_vec(), vec![b"c"])
     }
 
-    #[test_case(InMemoryExampleDatabase::new())]
-    #[test_case(directory::tests_util::TestDatabase::new())]
-    fn an_absent_value_is_present_after_it_moves_to_self(mut db: impl ExampleDatabase) {
-        db.r#move(b"a", b"a", b"b");
-        assert_eq!(db.fetch(b"a").into_vec(), vec![b"b"])
+    #[test_case(&InMemoryExampleDatabase::new())]
+    #[test_case(&directory::tests_util::TestDatabase::new())]
+    #[cfg_attr(feature = "sqlite", test_case(&sqlite::tests_util::TestDatabase::new()))]
+    #[test_case(&log::tests_util::TestDatabase::new())]
+    #[test_case(&redis::tests_util::TestDatabase::new())]
+    #[cfg_attr(
+        feature = "compression",
+        test_case(&CompressedDatabase::with_threshold(InMemoryExampleDatabase::new(), 0))
+    )]
+    #[test_case(&multiplexed())]
+    fn an_absent_value_is_present_after_it_moves_to_self(db: &impl ExampleDatabase) {
+        db.r#move(b"a", b"a", b"b").expect("Move failed");
+        assert_eq!(db.fetch(b"a").expect("Fetch failed").into_vec(), vec![b"b"])
     }
 
-    #[test_case(InMemoryExampleDatabase::new())]
-    #[test_case(directory::tests_util::TestDatabase::new())]
-    fn appears_in_listing_after_saving(mut db: impl ExampleDatabase) {
-        db.save(b"foo", b"bar");
-        assert_eq!(db.fetch(b"foo").into_vec(), vec![b"bar"])
+    #[test_case(&InMemoryExampleDatabase::new())]
+    #[test_case(&directory::tests_util::TestDatabase::new())]
+    #[cfg_attr(feature = "sqlite", test_case(&sqlite::tests_util::TestDatabase::new()))]
+    #[test_case(&log::tests_util::TestDatabase::new())]
+    #[test_case(&redis::tests_util::TestDatabase::new())]
+    #[cfg_attr(
+        feature = "compression",
+        test_case(&CompressedDatabase::with_threshold(InMemoryExampleDatabase::new(), 0))
+    )]
+    #[test_case(&multiplexed())]
+    fn appears_in_listing_after_saving(db: &impl ExampleDatabase) {
+        db.save(b"foo", b"bar").expect("Save failed");
+        assert_eq!(
+            db.fetch(b"foo").expect("Fetch failed").into_vec(),
+            vec![b"bar"]
+        )
+    }
+
+    #[test_case(&InMemoryExampleDatabase::new())]
+    #[test_case(&directory::tests_util::TestDatabase::new())]
+    #[cfg_attr(feature = "sqlite", test_case(&sqlite::tests_util::TestDatabase::new()))]
+    #[test_case(&log::tests_util::TestDatabase::new())]
+    #[test_case(&redis::tests_util::TestDatabase::new())]
+    #[cfg_attr(
+        feature = "compression",
+        test_case(&CompressedDatabase::with_threshold(InMemoryExampleDatabase::new(), 0))
+    )]
+    #[test_case(&multiplexed())]
+    fn can_delete_key(db: &impl ExampleDatabase) {
+        db.save(b"foo", b"bar").expect("Save failed");
+        db.delete(b"foo", b"bar").expect("Delete failed");
+        assert_eq!(db.fetch(b"foo").expect("Fetch failed").into_vec().len(), 0);
+    }
+
+    #[test_case(&InMemoryExampleDatabase::new())]
+    #[test_case(&directory::tests_util::TestDatabase::new())]
+    #[cfg_attr(feature = "sqlite", test_case(&sqlite::tests_util::TestDatabase::new()))]
+    #[test_case(&log::tests_util::TestDatabase::new())]
+    #[test_case(&redis::tests_util::TestDatabase::new())]
+    #[cfg_attr(
+        feature = "compression",
+        test_case(&CompressedDatabase::with_threshold(InMemoryExampleDatabase::new(), 0))
+    )]
+    #[test_case(&multiplexed())]
+    fn keys_with_values_are_listed(db: &impl ExampleDatabase) {
+        assert!(db.keys().expect("Keys are listable").is_empty());
+        db.save(b"foo", b"bar").expect("Save failed");
+        db.save(b"foo", b"baz").expect("Save failed");
+        db.save(b"empty", b"bar").expect("Save failed");
+        db.delete(b"empty", b"bar").expect("Delete failed");
+        db.r#move(b"foo", b"moved", b"baz").expect("Move failed");
+        let mut keys = db.keys().expect("Keys are listable");
+        keys.sort_unstable();
+        assert_eq!(keys, vec![b"foo".to_vec(), b"moved".to_vec()]);
     }
 
     #[test_case(InMemoryExampleDatabase::new())]
     #[test_case(directory::tests_util::TestDatabase::new())]
-    fn can_delete_key(mut db: impl ExampleDatabase) {
-        db.save(b"foo", b"bar");
-        db.delete(b"foo", b"bar");
-        assert_eq!(db.fetch(b"foo").into_vec().len(), 0);
+    #[cfg_attr(feature = "sqlite", test_case(sqlite::tests_util::TestDatabase::new()))]
+    #[test_case(log::tests_util::TestDatabase::new())]
+    #[test_case(redis::tests_util::TestDatabase::new())]
+    #[cfg_attr(
+        feature = "compression",
+        test_case(CompressedDatabase::with_threshold(InMemoryExampleDatabase::new(), 0))
+    )]
+    #[test_case(multiplexed())]
+    fn can_be_shared_between_threads(db: impl ExampleDatabase + Send + Sync + 'static) {
+        const THREADS: u8 = 8;
+        const VALUES: u8 = 50;
+        let db = Arc::new(db);
+        let handles: Vec<_> = (0..THREADS)
+            .map(|thread| {
+                let db = Arc::clone(&db);
+                std::thread::spawn(move || {
+                    for value in 0..VALUES {
+                        let value = [thread, value];
+                        // Every thread works with its own values, but all keys are shared
+                        db.save(b"shared", &value).expect("Save failed");
+                        db.save(b"source", &value).expect("Save failed");
+                        db.r#move(b"source", b"target", &value)
+                            .expect("Move failed");
+                        db.save(b"deleted", &value).expect("Save failed");
+                        db.delete(b"deleted", &value).expect("Delete failed");
+                        assert!(db
+                            .fetch(b"shared")
+                            .expect("Fetch failed")
+                            .into_vec()
+                            .contains(&value.to_vec()));
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().expect("Thread panicked");
+        }
+        let mut expected: Vec<Example> = (0..THREADS)
+            .flat_map(|thread| (0..VALUES).map(move |value| vec![thread, value]))
+            .collect();
+        expected.sort_unstable();
+        for key in &[&b"shared"[..], b"target"] {
+            let mut values = db.fetch(key).expect("Fetch failed").into_vec();
+            values.sort_unstable();
+            assert_eq!(values, expected);
+        }
+        assert!(db
+            .fetch(b"source")
+            .expect("Fetch failed")
+            .into_vec()
+            .is_empty());
+        assert!(db
+            .fetch(b"deleted")
+            .expect("Fetch failed")
+            .into_vec()
+            .is_empty());
     }
 }
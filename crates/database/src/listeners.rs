@@ -0,0 +1,172 @@
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, PoisonError, RwLock, RwLockReadGuard};
+
+/// A change made to a database.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum ChangeEvent {
+    /// `value` is saved under `key`.
+    Save {
+        /// Key of the saved value.
+        key: Vec<u8>,
+        /// Saved value.
+        value: Vec<u8>,
+    },
+    /// `value` is deleted from `key`.
+    Delete {
+        /// Key of the deleted value.
+        key: Vec<u8>,
+        /// Deleted value. `None` if it is unknown, e.g. if the deletion is observed on the
+        /// filesystem, where the value is already gone.
+        value: Option<Vec<u8>>,
+    },
+}
+
+/// Identifies a registered listener, so it can be removed later.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct ListenerHandle(u64);
+
+type Listener = Arc<dyn Fn(&ChangeEvent) + Send + Sync>;
+
+/// Listeners of a single database.
+///
+/// Every listener is called for every event, in the order of registration. Listeners are called
+/// without holding any locks, therefore they may use the database or register other listeners.
+#[derive(Default)]
+pub(crate) struct Listeners {
+    next: AtomicU64,
+    listeners: RwLock<Vec<(ListenerHandle, Listener)>>,
+}
+
+impl fmt::Debug for Listeners {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Listeners")
+            .field("len", &self.read().len())
+            .finish()
+    }
+}
+
+impl Listeners {
+    // Adding or removing a listener doesn't panic midway, therefore the list is consistent even if
+    // the lock is poisoned
+    fn read(&self) -> RwLockReadGuard<'_, Vec<(ListenerHandle, Listener)>> {
+        self.listeners
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    pub(crate) fn add(
+        &self,
+        listener: impl Fn(&ChangeEvent) + Send + Sync + 'static,
+    ) -> ListenerHandle {
+        let handle = ListenerHandle(self.next.fetch_add(1, Ordering::Relaxed));
+        self.listeners
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push((handle, Arc::new(listener)));
+        handle
+    }
+
+    pub(crate) fn remove(&self, handle: ListenerHandle) -> bool {
+        let mut listeners = self
+            .listeners
+            .write()
+            .unwrap_or_else(PoisonError::into_inner);
+        let before = listeners.len();
+        listeners.retain(|(registered, _)| *registered != handle);
+        listeners.len() != before
+    }
+
+    /// Call all listeners with the event. It is created only if there are any listeners.
+    ///
+    /// If a listener panics, the remaining ones still receive the event, and the panic is resumed
+    /// afterwards.
+    pub(crate) fn emit(&self, event: impl FnOnce() -> ChangeEvent) {
+        let listeners: Vec<Listener> = self
+            .read()
+            .iter()
+            .map(|(_, listener)| Arc::clone(listener))
+            .collect();
+        if listeners.is_empty() {
+            return;
+        }
+        let event = event();
+        let mut panicked = None;
+        for listener in listeners {
+            if let Err(payload) = panic::catch_unwind(AssertUnwindSafe(|| listener(&event))) {
+                panicked.get_or_insert(payload);
+            }
+        }
+        if let Some(payload) = panicked {
+            panic::resume_unwind(payload)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    fn save(value: &[u8]) -> ChangeEvent {
+        ChangeEvent::Save {
+            key: b"key".to_vec(),
+            value: value.to_vec(),
+        }
+    }
+
+    #[test]
+    fn listeners_are_removable() {
+        let listeners = Listeners::default();
+        let events = Arc::new(Mutex::new(vec![]));
+        let first = {
+            let events = Arc::clone(&events);
+            listeners.add(move |event| {
+                events
+                    .lock()
+                    .expect("Not poisoned")
+                    .push((1, event.clone()))
+            })
+        };
+        let second = {
+            let events = Arc::clone(&events);
+            listeners.add(move |event| {
+                events
+                    .lock()
+                    .expect("Not poisoned")
+                    .push((2, event.clone()))
+            })
+        };
+        listeners.emit(|| save(b"a"));
+        assert!(listeners.remove(first));
+        assert!(!listeners.remove(first));
+        listeners.emit(|| save(b"b"));
+        assert!(listeners.remove(second));
+        listeners.emit(|| save(b"c"));
+        assert_eq!(
+            *events.lock().expect("Not poisoned"),
+            vec![(1, save(b"a")), (2, save(b"a")), (2, save(b"b"))]
+        );
+    }
+
+    #[test]
+    fn events_are_not_created_without_listeners() {
+        let listeners = Listeners::default();
+        listeners.emit(|| unreachable!("There are no listeners"));
+    }
+
+    #[test]
+    fn panicking_listener_does_not_affect_others() {
+        let listeners = Listeners::default();
+        let events = Arc::new(Mutex::new(vec![]));
+        listeners.add(|_| panic!("Listener failed"));
+        {
+            let events = Arc::clone(&events);
+            listeners.add(move |event| events.lock().expect("Not poisoned").push(event.clone()));
+        }
+        let result = panic::catch_unwind(|| listeners.emit(|| save(b"a")));
+        assert!(result.is_err());
+        assert_eq!(*events.lock().expect("Not poisoned"), vec![save(b"a")]);
+    }
+}
@@ -0,0 +1,619 @@
+use crate::fetch::Fetch;
+use crate::{DatabaseError, Example, ExampleDatabase, Input};
+use ahash::{AHashMap, AHashSet};
+use std::convert::TryFrom;
+use std::ffi::OsString;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+/// Number of obsolete records that `LogExampleDatabase::new` tolerates before compacting the log.
+pub const DEFAULT_COMPACTION_THRESHOLD: usize = 1024;
+
+/// Identifies the file format, the last byte is its version.
+const MAGIC: &[u8] = b"HYPOTHESIS-LOG\x01";
+const SAVE: u8 = 1;
+const DELETE: u8 = 2;
+/// Tag, key length and value length.
+const RECORD_HEADER_SIZE: usize = 9;
+const CHECKSUM_SIZE: usize = 4;
+
+#[derive(Debug)]
+/// Store Hypothesis examples as an append-only log of saves and deletions in a single file.
+///
+/// The log is replayed into an in-memory index on open, therefore fetching never touches the
+/// file. A record that was only partially written, e.g. because the process crashed, is dropped
+/// if it is the last one in the log.
+///
+/// Records that are superseded by later ones are obsolete. Once there are more of them than
+/// both the compaction threshold and the number of stored examples, the log is rewritten with
+/// only the stored examples, see `LogExampleDatabase::compact`.
+///
+/// The file must not be used by several databases at the same time, as changes made by one of
+/// them are not visible to the others. The database can be shared between threads, e.g. via
+/// `Arc`.
+pub struct LogExampleDatabase {
+    path: PathBuf,
+    compaction_threshold: usize,
+    state: Mutex<State>,
+}
+
+#[derive(Debug)]
+struct State {
+    file: File,
+    /// Length of the log that contains only complete records.
+    len: u64,
+    index: AHashMap<Vec<u8>, AHashSet<Example>>,
+    /// Number of examples in the index.
+    live: usize,
+    /// Number of records in the log that don't correspond to a stored example.
+    obsolete: usize,
+}
+
+/// FNV-1a, it detects torn writes well enough and doesn't need any dependencies.
+fn checksum(data: &[u8]) -> u32 {
+    data.iter().fold(0x811c_9dc5, |hash, byte| {
+        (hash ^ u32::from(*byte)).wrapping_mul(0x0100_0193)
+    })
+}
+
+fn length(path: &Path, input: &Input) -> Result<[u8; 4], DatabaseError> {
+    u32::try_from(input.len())
+        .map(u32::to_le_bytes)
+        .map_err(|_| {
+            DatabaseError::io(
+                path,
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "keys and values must be shorter than 4 GiB",
+                ),
+            )
+        })
+}
+
+fn encode_record(
+    path: &Path,
+    buffer: &mut Vec<u8>,
+    tag: u8,
+    key: &Input,
+    value: &Input,
+) -> Result<(), DatabaseError> {
+    let start = buffer.len();
+    buffer.push(tag);
+    buffer.extend_from_slice(&length(path, key)?);
+    buffer.extend_from_slice(&length(path, value)?);
+    buffer.extend_from_slice(key);
+    buffer.extend_from_slice(value);
+    let checksum = checksum(buffer.get(start..).unwrap_or_default());
+    buffer.extend_from_slice(&checksum.to_le_bytes());
+    Ok(())
+}
+
+/// A record that is decoded from the log.
+#[derive(Debug)]
+struct Record<'a> {
+    tag: u8,
+    key: &'a [u8],
+    value: &'a [u8],
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+    let bytes = data.get(offset..offset.checked_add(4)?)?;
+    let mut array = [0; 4];
+    array.copy_from_slice(bytes);
+    Some(u32::from_le_bytes(array))
+}
+
+/// Decode the record at the start of `data`, together with its size.
+/// Returns `None` if the record is incomplete or damaged.
+fn decode_record(data: &[u8]) -> Option<(Record<'_>, usize)> {
+    let tag = *data.first()?;
+    let key_len = usize::try_from(read_u32(data, 1)?).ok()?;
+    let value_len = usize::try_from(read_u32(data, 5)?).ok()?;
+    let key_end = RECORD_HEADER_SIZE.checked_add(key_len)?;
+    let value_end = key_end.checked_add(value_len)?;
+    let body = data.get(..value_end)?;
+    if read_u32(data, value_end)? != checksum(body) || !(tag == SAVE || tag == DELETE) {
+        return None;
+    }
+    let record = Record {
+        tag,
+        key: body.get(RECORD_HEADER_SIZE..key_end)?,
+        value: body.get(key_end..)?,
+    };
+    Some((record, value_end.checked_add(CHECKSUM_SIZE)?))
+}
+
+impl State {
+    fn apply(&mut self, tag: u8, key: &Input, value: &Input) {
+        // Every record that doesn't change the index is obsolete right away, and a deletion makes
+        // the corresponding save obsolete too
+        if tag == SAVE {
+            if self
+                .index
+                .entry(key.to_vec())
+                .or_default()
+                .insert(value.to_vec())
+            {
+                self.live = self.live.saturating_add(1);
+            } else {
+                self.obsolete = self.obsolete.saturating_add(1);
+            }
+        } else if self.remove(key, value) {
+            self.live = self.live.saturating_sub(1);
+            self.obsolete = self.obsolete.saturating_add(2);
+        } else {
+            self.obsolete = self.obsolete.saturating_add(1);
+        }
+    }
+
+    fn contains(&self, key: &Input, value: &Input) -> bool {
+        self.index
+            .get(key)
+            .is_some_and(|values| values.contains(value))
+    }
+
+    /// Empty sets are dropped, so all keys in the index have values.
+    fn remove(&mut self, key: &Input, value: &Input) -> bool {
+        match self.index.get_mut(key) {
+            Some(values) => {
+                let removed = values.remove(value);
+                if values.is_empty() {
+                    self.index.remove(key);
+                }
+                removed
+            }
+            None => false,
+        }
+    }
+
+    /// Append encoded records and apply them to the index.
+    fn append(
+        &mut self,
+        path: &Path,
+        buffer: &[u8],
+        records: &[(u8, &Input, &Input)],
+    ) -> Result<(), DatabaseError> {
+        if let Err(error) = self.file.write_all(buffer) {
+            // A partially written record would make all records appended after it unreadable
+            let _ = self.file.set_len(self.len);
+            return Err(DatabaseError::io(path, error));
+        }
+        self.len = self.len.saturating_add(buffer.len() as u64);
+        for (tag, key, value) in records {
+            self.apply(*tag, key, value);
+        }
+        Ok(())
+    }
+}
+
+impl LogExampleDatabase {
+    /// Open the log at `path`, creating it if it doesn't exist.
+    pub fn new<P: AsRef<Path>>(path: P) -> Result<LogExampleDatabase, DatabaseError> {
+        LogExampleDatabase::with_compaction_threshold(path, DEFAULT_COMPACTION_THRESHOLD)
+    }
+
+    /// Open the log at `path` and compact it automatically once it has more than `threshold`
+    /// obsolete records.
+    pub fn with_compaction_threshold<P: AsRef<Path>>(
+        path: P,
+        threshold: usize,
+    ) -> Result<LogExampleDatabase, DatabaseError> {
+        let path = path.as_ref();
+        let db = LogExampleDatabase {
+            path: path.to_path_buf(),
+            compaction_threshold: threshold,
+            state: Mutex::new(open(path)?),
+        };
+        db.compact_if_needed(db.lock())?;
+        Ok(db)
+    }
+
+    /// Path to the log file.
+    #[inline]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Number of obsolete records in the log.
+    #[inline]
+    pub fn obsolete_records(&self) -> usize {
+        self.lock().obsolete
+    }
+
+    /// Rewrite the log so it contains only the stored examples.
+    ///
+    /// The new log is written next to the current one and then replaces it, so the current log
+    /// stays intact if compaction fails.
+    pub fn compact(&self) -> Result<(), DatabaseError> {
+        let mut state = self.lock();
+        let mut buffer = MAGIC.to_vec();
+        for (key, values) in &state.index {
+            for value in values {
+                encode_record(&self.path, &mut buffer, SAVE, key, value)?;
+            }
+        }
+        let mut tmpname = OsString::from(&self.path);
+        tmpname.push(".compact");
+        let tmpname = PathBuf::from(tmpname);
+        // The new log is opened before it replaces the current one, so the state never refers to
+        // a file that is no longer the log
+        let file = match write_file(&tmpname, &buffer) {
+            Ok(file) => file,
+            Err(error) => {
+                let _ = fs::remove_file(&tmpname);
+                return Err(DatabaseError::io(&tmpname, error));
+            }
+        };
+        if let Err(error) = fs::rename(&tmpname, &self.path) {
+            let _ = fs::remove_file(&tmpname);
+            return Err(DatabaseError::io(&self.path, error));
+        }
+        state.file = file;
+        state.len = buffer.len() as u64;
+        state.obsolete = 0;
+        Ok(())
+    }
+
+    // Every change is written before the index is updated, therefore the index never has changes
+    // that are not in the log, even if the lock is poisoned
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn compact_if_needed(&self, state: MutexGuard<'_, State>) -> Result<(), DatabaseError> {
+        let needed = state.obsolete > self.compaction_threshold && state.obsolete > state.live;
+        drop(state);
+        if needed {
+            self.compact()
+        } else {
+            Ok(())
+        }
+    }
+
+    fn write(&self, records: &[(u8, &Input, &Input)]) -> Result<(), DatabaseError> {
+        let mut state = self.lock();
+        let mut buffer = vec![];
+        let mut changes = vec![];
+        for &(tag, key, value) in records {
+            // Records that don't change anything are not written at all
+            if state.contains(key, value) == (tag == DELETE) {
+                encode_record(&self.path, &mut buffer, tag, key, value)?;
+                changes.push((tag, key, value));
+            }
+        }
+        if changes.is_empty() {
+            return Ok(());
+        }
+        state.append(&self.path, &buffer, &changes)?;
+        self.compact_if_needed(state)
+    }
+}
+
+/// Write a new file and return it opened for appending.
+fn write_file(path: &Path, contents: &[u8]) -> io::Result<File> {
+    let mut target = OpenOptions::new().append(true).create(true).open(path)?;
+    // The file may be left over from a failed compaction
+    target.set_len(0)?;
+    target.write_all(contents)?;
+    target.sync_all()?;
+    Ok(target)
+}
+
+/// Replay the log into a new index, dropping an incomplete last record.
+fn open(path: &Path) -> Result<State, DatabaseError> {
+    let mut file = OpenOptions::new()
+        .read(true)
+        .append(true)
+        .create(true)
+        .open(path)
+        .map_err(|error| DatabaseError::io(path, error))?;
+    let mut data = vec![];
+    file.read_to_end(&mut data)
+        .map_err(|error| DatabaseError::io(path, error))?;
+    if data.is_empty() {
+        file.write_all(MAGIC)
+            .map_err(|error| DatabaseError::io(path, error))?;
+        data.extend_from_slice(MAGIC);
+    } else if !data.starts_with(MAGIC) {
+        return Err(DatabaseError::Corrupted(
+            path.to_path_buf(),
+            "not an example log".to_string(),
+        ));
+    }
+    let mut state = State {
+        file,
+        len: 0,
+        index: AHashMap::new(),
+        live: 0,
+        obsolete: 0,
+    };
+    let mut offset = MAGIC.len();
+    while let Some(rest) = data.get(offset..).filter(|rest| !rest.is_empty()) {
+        match decode_record(rest) {
+            Some((record, size)) => {
+                state.apply(record.tag, record.key, record.value);
+                offset = offset.saturating_add(size);
+            }
+            None if is_torn(rest) => break,
+            None => {
+                return Err(DatabaseError::Corrupted(
+                    path.to_path_buf(),
+                    format!("damaged record at offset {}", offset),
+                ))
+            }
+        }
+    }
+    state.len = offset as u64;
+    if offset < data.len() {
+        state
+            .file
+            .set_len(state.len)
+            .map_err(|error| DatabaseError::io(path, error))?;
+    }
+    Ok(state)
+}
+
+/// Whether an undecodable record is a partially written last one. Only the last record may be
+/// partially written, therefore the record is damaged if a valid one could follow it.
+fn is_torn(data: &[u8]) -> bool {
+    if data.len() < RECORD_HEADER_SIZE {
+        return true;
+    }
+    let size = read_u32(data, 1)
+        .zip(read_u32(data, 5))
+        .and_then(|(key_len, value_len)| {
+            usize::try_from(key_len)
+                .ok()?
+                .checked_add(usize::try_from(value_len).ok()?)?
+                .checked_add(RECORD_HEADER_SIZE + CHECKSUM_SIZE)
+        });
+    match size {
+        // The record ends at the end of the log, but its contents didn't reach the disk
+        Some(size) if size == data.len() => true,
+        // Either the record is cut off, or its length is damaged and it hides the records after
+        // it. The latter is unlikely if no valid record starts in the remaining data
+        Some(size) if size > data.len() => !(1..data.len())
+            .filter_map(|start| data.get(start..))
+            .any(|rest| decode_record(rest).is_some()),
+        _ => false,
+    }
+}
+
+impl ExampleDatabase for LogExampleDatabase {
+    type Source = Vec<Example>;
+
+    #[inline]
+    fn save(&self, key: &Input, value: &Input) -> Result<(), DatabaseError> {
+        self.write(&[(SAVE, key, value)])
+    }
+
+    #[inline]
+    fn delete(&self, key: &Input, value: &Input) -> Result<(), DatabaseError> {
+        self.write(&[(DELETE, key, value)])
+    }
+
+    #[inline]
+    fn r#move(&self, src: &Input, dst: &Input, value: &Input) -> Result<(), DatabaseError> {
+        if src == dst {
+            return self.save(src, value);
+        }
+        // Both records are appended at once, so the value can't get lost in between
+        self.write(&[(DELETE, src, value), (SAVE, dst, value)])
+    }
+
+    #[inline]
+    fn fetch(&self, key: &Input) -> Result<Fetch<Self::Source>, DatabaseError> {
+        Ok(Fetch::new(
+            self.lock()
+                .index
+                .get(key)
+                .map_or_else(Vec::new, |values| values.iter().cloned().collect()),
+        ))
+    }
+
+    #[inline]
+    fn keys(&self) -> Result<Vec<Vec<u8>>, DatabaseError> {
+        Ok(self.lock().index.keys().cloned().collect())
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests_util {
+    use super::*;
+    use tempdir::TempDir;
+
+    pub(crate) type TestDatabase = crate::tests_util::TestDatabase<LogExampleDatabase, TempDir>;
+
+    impl TestDatabase {
+        pub(crate) fn new() -> Self {
+            let dir = TempDir::new("test-log").expect("Should always work");
+            let db =
+                LogExampleDatabase::new(dir.path().join("examples.log")).expect("Log is created");
+            TestDatabase::with_guard(db, dir)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempdir::TempDir;
+
+    fn sorted(db: &LogExampleDatabase, key: &Input) -> Vec<Example> {
+        let mut values = db.fetch(key).expect("Fetch failed").into_vec();
+        values.sort_unstable();
+        values
+    }
+
+    #[test]
+    fn examples_persist() {
+        let temp = TempDir::new("test-log").expect("Should always work");
+        let path = temp.path().join("examples.log");
+        {
+            let db = LogExampleDatabase::new(&path).expect("Log is created");
+            db.save(b"foo", b"bar").expect("Save failed");
+            db.save(b"foo", b"baz").expect("Save failed");
+            db.save(b"foo", b"deleted").expect("Save failed");
+            db.delete(b"foo", b"deleted").expect("Delete failed");
+            db.r#move(b"foo", b"moved", b"baz").expect("Move failed");
+        }
+        let db = LogExampleDatabase::new(&path).expect("Log is opened");
+        assert_eq!(sorted(&db, b"foo"), vec![b"bar".to_vec()]);
+        assert_eq!(sorted(&db, b"moved"), vec![b"baz".to_vec()]);
+        assert_eq!(db.path(), path);
+    }
+
+    #[test]
+    fn torn_last_record_is_dropped() {
+        let temp = TempDir::new("test-log").expect("Should always work");
+        let path = temp.path().join("examples.log");
+        {
+            let db = LogExampleDatabase::new(&path).expect("Log is created");
+            db.save(b"foo", b"bar").expect("Save failed");
+            db.save(b"foo", b"torn").expect("Save failed");
+        }
+        let len = fs::metadata(&path).expect("Log exists").len();
+        let file = OpenOptions::new()
+            .write(true)
+            .open(&path)
+            .expect("Log is writable");
+        file.set_len(len - 3).expect("Log is truncated");
+        drop(file);
+        let db = LogExampleDatabase::new(&path).expect("Log is opened");
+        assert_eq!(sorted(&db, b"foo"), vec![b"bar".to_vec()]);
+        // The torn record is removed, so new records are readable after it
+        db.save(b"foo", b"new").expect("Save failed");
+        drop(db);
+        let db = LogExampleDatabase::new(&path).expect("Log is opened");
+        assert_eq!(sorted(&db, b"foo"), vec![b"bar".to_vec(), b"new".to_vec()]);
+    }
+
+    #[test]
+    fn damaged_record_in_the_middle_is_corruption() {
+        let temp = TempDir::new("test-log").expect("Should always work");
+        let path = temp.path().join("examples.log");
+        {
+            let db = LogExampleDatabase::new(&path).expect("Log is created");
+            db.save(b"foo", b"bar").expect("Save failed");
+            db.save(b"foo", b"baz").expect("Save failed");
+        }
+        let mut data = fs::read(&path).expect("Log is readable");
+        // The value of the first record
+        data[MAGIC.len() + RECORD_HEADER_SIZE + 3] ^= 0xff;
+        fs::write(&path, data).expect("Log is writable");
+        assert!(matches!(
+            LogExampleDatabase::new(&path),
+            Err(DatabaseError::Corrupted(error_path, _)) if error_path == path
+        ));
+    }
+
+    #[test]
+    fn damaged_length_in_the_middle_is_corruption() {
+        let temp = TempDir::new("test-log").expect("Should always work");
+        let path = temp.path().join("examples.log");
+        {
+            let db = LogExampleDatabase::new(&path).expect("Log is created");
+            db.save(b"foo", b"bar").expect("Save failed");
+            db.save(b"foo", b"baz").expect("Save failed");
+        }
+        let mut data = fs::read(&path).expect("Log is readable");
+        // The value length of the first record points past the end of the log
+        data[MAGIC.len() + 8] = 0x7f;
+        fs::write(&path, &data).expect("Log is writable");
+        assert!(matches!(
+            LogExampleDatabase::new(&path),
+            Err(DatabaseError::Corrupted(error_path, _)) if error_path == path
+        ));
+        // The log is left as is
+        assert_eq!(fs::read(&path).expect("Log is readable"), data);
+    }
+
+    #[test]
+    fn other_files_are_not_logs() {
+        let temp = TempDir::new("test-log").expect("Should always work");
+        let path = temp.path().join("examples.log");
+        fs::write(&path, b"not a log").expect("Can't write a file");
+        assert!(matches!(
+            LogExampleDatabase::new(&path),
+            Err(DatabaseError::Corrupted(error_path, _)) if error_path == path
+        ));
+    }
+
+    #[test]
+    fn compaction_keeps_stored_examples() {
+        let temp = TempDir::new("test-log").expect("Should always work");
+        let path = temp.path().join("examples.log");
+        let db = LogExampleDatabase::new(&path).expect("Log is created");
+        db.save(b"foo", b"bar").expect("Save failed");
+        for _ in 0..10 {
+            db.save(b"foo", b"baz").expect("Save failed");
+            db.delete(b"foo", b"baz").expect("Delete failed");
+        }
+        assert_eq!(db.obsolete_records(), 20);
+        let before = fs::metadata(&path).expect("Log exists").len();
+        db.compact().expect("Compaction failed");
+        assert_eq!(db.obsolete_records(), 0);
+        assert!(fs::metadata(&path).expect("Log exists").len() < before);
+        db.save(b"foo", b"after").expect("Save failed");
+        drop(db);
+        let db = LogExampleDatabase::new(&path).expect("Log is opened");
+        assert_eq!(
+            sorted(&db, b"foo"),
+            vec![b"after".to_vec(), b"bar".to_vec()]
+        );
+    }
+
+    #[test]
+    fn compaction_overwrites_leftovers() {
+        let temp = TempDir::new("test-log").expect("Should always work");
+        let path = temp.path().join("examples.log");
+        // E.g. from a compaction that failed
+        fs::write(temp.path().join("examples.log.compact"), [0xff; 1024])
+            .expect("Can't write a file");
+        let db = LogExampleDatabase::new(&path).expect("Log is created");
+        db.save(b"foo", b"bar").expect("Save failed");
+        db.compact().expect("Compaction failed");
+        db.save(b"foo", b"after").expect("Save failed");
+        drop(db);
+        let db = LogExampleDatabase::new(&path).expect("Log is opened");
+        assert_eq!(
+            sorted(&db, b"foo"),
+            vec![b"after".to_vec(), b"bar".to_vec()]
+        );
+    }
+
+    #[test]
+    fn log_is_compacted_automatically() {
+        let temp = TempDir::new("test-log").expect("Should always work");
+        let path = temp.path().join("examples.log");
+        let db = LogExampleDatabase::with_compaction_threshold(&path, 4).expect("Log is created");
+        db.save(b"foo", b"bar").expect("Save failed");
+        db.save(b"foo", b"baz").expect("Save failed");
+        db.delete(b"foo", b"baz").expect("Delete failed");
+        // Two obsolete records for one stored example
+        assert_eq!(db.obsolete_records(), 2);
+        db.save(b"foo", b"baz").expect("Save failed");
+        db.delete(b"foo", b"baz").expect("Delete failed");
+        assert_eq!(db.obsolete_records(), 4);
+        db.save(b"foo", b"baz").expect("Save failed");
+        db.delete(b"foo", b"baz").expect("Delete failed");
+        assert_eq!(db.obsolete_records(), 0);
+        assert_eq!(sorted(&db, b"foo"), vec![b"bar".to_vec()]);
+    }
+
+    #[test]
+    fn unchanged_examples_are_not_logged() {
+        let temp = TempDir::new("test-log").expect("Should always work");
+        let path = temp.path().join("examples.log");
+        let db = LogExampleDatabase::new(&path).expect("Log is created");
+        db.save(b"foo", b"bar").expect("Save failed");
+        let len = fs::metadata(&path).expect("Log exists").len();
+        db.save(b"foo", b"bar").expect("Save failed");
+        db.delete(b"foo", b"unknown").expect("Delete failed");
+        db.r#move(b"unknown", b"foo", b"bar").expect("Move failed");
+        assert_eq!(fs::metadata(&path).expect("Log exists").len(), len);
+        assert_eq!(db.obsolete_records(), 0);
+    }
+}
@@ -1,20 +1,41 @@
 use crate::fetch::Fetch;
-use crate::{Example, ExampleDatabase, Input};
+use crate::listeners::Listeners;
+use crate::{ChangeEvent, DatabaseError, Example, ExampleDatabase, Input, ListenerHandle};
 use ahash::{AHashMap, AHashSet};
+use std::sync::{PoisonError, RwLock};
 
 #[derive(Debug)]
 /// A non-persistent example database, implemented in terms of a hashmaps of sets.
+///
+/// The database can be shared between threads, e.g. via `Arc`.
 pub struct InMemoryExampleDatabase {
-    data: AHashMap<Vec<u8>, AHashSet<Example>>,
+    data: RwLock<AHashMap<Vec<u8>, AHashSet<Example>>>,
+    listeners: Listeners,
 }
 
 impl InMemoryExampleDatabase {
     /// Create a new non-persistent example database.
     pub fn new() -> InMemoryExampleDatabase {
         InMemoryExampleDatabase {
-            data: AHashMap::with_capacity(64),
+            data: RwLock::new(AHashMap::with_capacity(64)),
+            listeners: Listeners::default(),
         }
     }
+
+    /// Register a function that is called after every change of the stored values.
+    #[inline]
+    pub fn add_listener(
+        &self,
+        listener: impl Fn(&ChangeEvent) + Send + Sync + 'static,
+    ) -> ListenerHandle {
+        self.listeners.add(listener)
+    }
+
+    /// Stop calling the given listener. Returns whether it was registered.
+    #[inline]
+    pub fn remove_listener(&self, handle: ListenerHandle) -> bool {
+        self.listeners.remove(handle)
+    }
 }
 impl Default for InMemoryExampleDatabase {
     fn default() -> InMemoryExampleDatabase {
@@ -25,48 +46,141 @@ impl Default for InMemoryExampleDatabase {
 impl ExampleDatabase for InMemoryExampleDatabase {
     type Source = Vec<Example>;
     #[inline]
-    fn save(&mut self, key: &Input, value: &Input) {
-        self.data
+    fn save(&self, key: &Input, value: &Input) -> Result<(), DatabaseError> {
+        // Set operations don't panic midway, therefore the data is consistent even if the lock is
+        // poisoned
+        let inserted = self
+            .data
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
             .entry(key.to_vec())
-            .or_insert_with(AHashSet::new)
+            .or_default()
             .insert(value.to_vec());
+        if inserted {
+            self.listeners.emit(|| ChangeEvent::Save {
+                key: key.to_vec(),
+                value: value.to_vec(),
+            });
+        }
+        Ok(())
     }
 
     #[inline]
-    fn delete(&mut self, key: &Input, value: &Input) {
-        if let Some(entry) = self.data.get_mut(key) {
-            entry.remove(value);
+    fn delete(&self, key: &Input, value: &Input) -> Result<(), DatabaseError> {
+        let removed = self
+            .data
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get_mut(key)
+            .is_some_and(|entry| entry.remove(value));
+        if removed {
+            self.listeners.emit(|| ChangeEvent::Delete {
+                key: key.to_vec(),
+                value: Some(value.to_vec()),
+            });
         }
+        Ok(())
     }
 
     #[inline]
-    fn fetch(&self, key: &Input) -> Fetch<Self::Source> {
+    fn fetch(&self, key: &Input) -> Result<Fetch<Self::Source>, DatabaseError> {
         // Collecting into a vector is faster than cloning a hashset
-        Fetch::new(
+        Ok(Fetch::new(
             self.data
+                .read()
+                .unwrap_or_else(PoisonError::into_inner)
                 .get(key)
                 .map_or_else(Vec::new, |hs| hs.iter().cloned().collect()),
-        )
+        ))
+    }
+
+    #[inline]
+    fn keys(&self) -> Result<Vec<Vec<u8>>, DatabaseError> {
+        Ok(self
+            .data
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .iter()
+            .filter(|(_, values)| !values.is_empty())
+            .map(|(key, _)| key.clone())
+            .collect())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::sync::{Arc, Mutex};
 
     #[test]
     fn test_can_delete_keys() {
-        let mut db = InMemoryExampleDatabase::new();
-        db.save(b"foo", b"bar");
-        db.save(b"foo", b"baz");
-        db.delete(b"foo", b"bar");
-        assert_eq!(db.fetch(b"foo").into_vec(), vec![b"baz".to_vec()])
+        let db = InMemoryExampleDatabase::new();
+        db.save(b"foo", b"bar").expect("Save failed");
+        db.save(b"foo", b"baz").expect("Save failed");
+        db.delete(b"foo", b"bar").expect("Delete failed");
+        assert_eq!(
+            db.fetch(b"foo").expect("Fetch failed").into_vec(),
+            vec![b"baz".to_vec()]
+        )
     }
 
     #[test]
     fn test_does_not_error_when_fetching_when_not_exist() {
         let db = InMemoryExampleDatabase::new();
         let expected: Vec<Vec<u8>> = vec![];
-        assert_eq!(db.fetch(b"foo").into_vec(), expected);
+        assert_eq!(db.fetch(b"foo").expect("Fetch failed").into_vec(), expected);
+    }
+
+    #[test]
+    fn listeners_receive_changes() {
+        let db = InMemoryExampleDatabase::new();
+        let events = Arc::new(Mutex::new(vec![]));
+        let handle = {
+            let events = Arc::clone(&events);
+            db.add_listener(move |event| events.lock().expect("Not poisoned").push(event.clone()))
+        };
+        db.save(b"foo", b"bar").expect("Save failed");
+        // Nothing changes
+        db.save(b"foo", b"bar").expect("Save failed");
+        db.delete(b"foo", b"baz").expect("Delete failed");
+        db.r#move(b"foo", b"moved", b"bar").expect("Move failed");
+        assert!(db.remove_listener(handle));
+        db.save(b"foo", b"baz").expect("Save failed");
+        assert_eq!(
+            *events.lock().expect("Not poisoned"),
+            vec![
+                ChangeEvent::Save {
+                    key: b"foo".to_vec(),
+                    value: b"bar".to_vec()
+                },
+                ChangeEvent::Delete {
+                    key: b"foo".to_vec(),
+                    value: Some(b"bar".to_vec())
+                },
+                ChangeEvent::Save {
+                    key: b"moved".to_vec(),
+                    value: b"bar".to_vec()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn listeners_can_use_the_database() {
+        let db = Arc::new(InMemoryExampleDatabase::new());
+        let fetched = Arc::new(Mutex::new(vec![]));
+        {
+            let inner = Arc::clone(&db);
+            let fetched = Arc::clone(&fetched);
+            db.add_listener(move |_| {
+                let values = inner.fetch(b"foo").expect("Fetch failed").into_vec();
+                fetched.lock().expect("Not poisoned").push(values)
+            });
+        }
+        db.save(b"foo", b"bar").expect("Save failed");
+        assert_eq!(
+            *fetched.lock().expect("Not poisoned"),
+            vec![vec![b"bar".to_vec()]]
+        );
     }
 }
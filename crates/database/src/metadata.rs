@@ -0,0 +1,129 @@
+use crate::{DatabaseError, Input};
+use std::convert::TryFrom;
+use std::fmt::Write as FmtWrite;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Extension of metadata files that are stored next to key directories.
+pub(crate) const EXTENSION: &str = "meta";
+
+/// Information about a key stored in a `DirectoryBasedExampleDatabase`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct KeyMetadata {
+    /// Original key.
+    pub key: Vec<u8>,
+    /// Directory that contains examples for this key.
+    pub path: PathBuf,
+    /// When the key directory was created.
+    pub created: SystemTime,
+    /// Number of examples currently stored for this key.
+    pub examples: usize,
+}
+
+/// Contents of a metadata file.
+///
+/// ```text
+/// key=666f6f
+/// created=1609459200
+/// ```
+///
+/// The key is hex-encoded, the creation time is in seconds since the Unix epoch. Unknown fields
+/// are ignored.
+pub(crate) fn encode(key: &Input, created: SystemTime) -> String {
+    let created = created
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let mut out = String::with_capacity(key.len().saturating_mul(2).saturating_add(32));
+    out.push_str("key=");
+    for byte in key {
+        out.write_fmt(format_args!("{:02x}", byte))
+            .expect("Writing to a string never fails");
+    }
+    out.write_fmt(format_args!("\ncreated={}\n", created))
+        .expect("Writing to a string never fails");
+    out
+}
+
+/// Parse a metadata file, returning the key and its creation time.
+pub(crate) fn decode(path: &Path, contents: &str) -> Result<(Vec<u8>, SystemTime), DatabaseError> {
+    let corrupted = |reason: &str| DatabaseError::Corrupted(path.to_path_buf(), reason.to_string());
+    let mut key = None;
+    let mut created = None;
+    for line in contents.lines() {
+        match line.split_once('=') {
+            Some(("key", value)) => {
+                key = Some(decode_hex(value).ok_or_else(|| corrupted("invalid key"))?)
+            }
+            Some(("created", value)) => {
+                let seconds = value
+                    .parse()
+                    .map_err(|_| corrupted("invalid creation time"))?;
+                created = UNIX_EPOCH.checked_add(Duration::from_secs(seconds));
+            }
+            _ => {}
+        }
+    }
+    match (key, created) {
+        (Some(key), Some(created)) => Ok((key, created)),
+        (None, _) => Err(corrupted("missing key")),
+        (_, None) => Err(corrupted("missing creation time")),
+    }
+}
+
+fn decode_hex(value: &str) -> Option<Vec<u8>> {
+    if !value.len().is_multiple_of(2) {
+        return None;
+    }
+    // Only hex digits are accepted, unlike `u8::from_str_radix`, which also accepts a sign
+    let digit = |byte: u8| {
+        char::from(byte)
+            .to_digit(16)
+            .and_then(|d| u8::try_from(d).ok())
+    };
+    value
+        .as_bytes()
+        .chunks(2)
+        .map(|pair| match pair {
+            [high, low] => Some(digit(*high)?.checked_mul(16)?.checked_add(digit(*low)?)?),
+            _ => None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test]
+    fn roundtrip() {
+        let created = UNIX_EPOCH + Duration::from_secs(1_609_459_200);
+        let contents = encode(b"\x00foo\xff", created);
+        assert_eq!(contents, "key=00666f6fff\ncreated=1609459200\n");
+        assert_eq!(
+            decode(Path::new("key.meta"), &contents).expect("Valid metadata"),
+            (b"\x00foo\xff".to_vec(), created)
+        );
+    }
+
+    #[test]
+    fn unknown_fields_are_ignored() {
+        let (key, _) =
+            decode(Path::new("key.meta"), "version=2\nkey=\ncreated=0\n").expect("Valid metadata");
+        assert!(key.is_empty());
+    }
+
+    #[test_case("created=0\n"; "missing key")]
+    #[test_case("key=abc\ncreated=0\n"; "odd key length")]
+    #[test_case("key=zz\ncreated=0\n"; "invalid hex")]
+    #[test_case("key=+f\ncreated=0\n"; "sign in hex")]
+    #[test_case("key=00\ncreated=yesterday\n"; "invalid time")]
+    #[test_case("key=00\n"; "missing time")]
+    fn invalid_metadata_is_corrupted(contents: &str) {
+        assert!(matches!(
+            decode(Path::new("key.meta"), contents),
+            Err(DatabaseError::Corrupted(_, _))
+        ));
+    }
+}
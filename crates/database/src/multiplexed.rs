@@ -0,0 +1,254 @@
+use crate::fetch::Fetch;
+use crate::{DatabaseError, Example, ExampleDatabase, Input};
+use ahash::AHashSet;
+use std::fmt;
+
+/// Object-safe view of an `ExampleDatabase` with any example source.
+trait Child: fmt::Debug + Send + Sync {
+    fn save_value(&self, key: &Input, value: &Input) -> Result<(), DatabaseError>;
+    fn delete_value(&self, key: &Input, value: &Input) -> Result<(), DatabaseError>;
+    fn move_value(&self, src: &Input, dst: &Input, value: &Input) -> Result<(), DatabaseError>;
+    fn fetch_boxed(&self, key: &Input) -> Result<Box<dyn Iterator<Item = Example>>, DatabaseError>;
+    fn list_keys(&self) -> Result<Vec<Vec<u8>>, DatabaseError>;
+}
+
+impl<D> Child for D
+where
+    D: ExampleDatabase + fmt::Debug + Send + Sync,
+    <D::Source as IntoIterator>::IntoIter: 'static,
+{
+    fn save_value(&self, key: &Input, value: &Input) -> Result<(), DatabaseError> {
+        ExampleDatabase::save(self, key, value)
+    }
+
+    fn delete_value(&self, key: &Input, value: &Input) -> Result<(), DatabaseError> {
+        ExampleDatabase::delete(self, key, value)
+    }
+
+    fn move_value(&self, src: &Input, dst: &Input, value: &Input) -> Result<(), DatabaseError> {
+        ExampleDatabase::r#move(self, src, dst, value)
+    }
+
+    fn fetch_boxed(&self, key: &Input) -> Result<Box<dyn Iterator<Item = Example>>, DatabaseError> {
+        Ok(Box::new(ExampleDatabase::fetch(self, key)?.into_iter()))
+    }
+
+    fn list_keys(&self) -> Result<Vec<Vec<u8>>, DatabaseError> {
+        ExampleDatabase::keys(self)
+    }
+}
+
+/// A wrapper around multiple databases.
+///
+/// Each `save`, `fetch`, `move`, or `delete` operation will be run against all of the wrapped
+/// databases. `fetch` does not yield duplicate values, even if the same value is present in two
+/// or more of the wrapped databases. The same goes for `keys`.
+///
+/// This combines well with a `ReadOnlyDatabase`, as follows: a local database is used for all
+/// operations, and a shared database is only read from.
+#[derive(Debug, Default)]
+pub struct MultiplexedDatabase {
+    children: Vec<Box<dyn Child>>,
+}
+
+impl MultiplexedDatabase {
+    /// Create a new multiplexer without any wrapped databases.
+    pub fn new() -> MultiplexedDatabase {
+        MultiplexedDatabase { children: vec![] }
+    }
+
+    /// Add a database to the wrapped ones.
+    pub fn push<D>(&mut self, db: D)
+    where
+        D: ExampleDatabase + fmt::Debug + Send + Sync + 'static,
+        <D::Source as IntoIterator>::IntoIter: 'static,
+    {
+        self.children.push(Box::new(db))
+    }
+
+    /// Number of wrapped databases.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.children.len()
+    }
+
+    /// Whether there are no wrapped databases.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.children.is_empty()
+    }
+}
+
+impl ExampleDatabase for MultiplexedDatabase {
+    type Source = MultiplexedFetch;
+
+    #[inline]
+    fn save(&self, key: &Input, value: &Input) -> Result<(), DatabaseError> {
+        for child in &self.children {
+            child.save_value(key, value)?;
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn delete(&self, key: &Input, value: &Input) -> Result<(), DatabaseError> {
+        for child in &self.children {
+            child.delete_value(key, value)?;
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn r#move(&self, src: &Input, dst: &Input, value: &Input) -> Result<(), DatabaseError> {
+        for child in &self.children {
+            child.move_value(src, dst, value)?;
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn fetch(&self, key: &Input) -> Result<Fetch<Self::Source>, DatabaseError> {
+        let sources = self
+            .children
+            .iter()
+            .map(|child| child.fetch_boxed(key))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Fetch::new(MultiplexedFetch {
+            sources: sources.into_iter(),
+            current: None,
+            seen: AHashSet::new(),
+        }))
+    }
+
+    #[inline]
+    fn keys(&self) -> Result<Vec<Vec<u8>>, DatabaseError> {
+        let mut seen = AHashSet::new();
+        let mut keys = vec![];
+        for child in &self.children {
+            for key in child.list_keys()? {
+                if !seen.contains(&key) {
+                    seen.insert(key.clone());
+                    keys.push(key);
+                }
+            }
+        }
+        Ok(keys)
+    }
+}
+
+/// Lazily yields unique examples from all wrapped databases, in their order.
+pub struct MultiplexedFetch {
+    sources: std::vec::IntoIter<Box<dyn Iterator<Item = Example>>>,
+    current: Option<Box<dyn Iterator<Item = Example>>>,
+    seen: AHashSet<Example>,
+}
+
+impl fmt::Debug for MultiplexedFetch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MultiplexedFetch")
+            .field("seen", &self.seen)
+            .finish()
+    }
+}
+
+impl Iterator for MultiplexedFetch {
+    type Item = Example;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(current) = &mut self.current {
+                for example in current {
+                    if !self.seen.contains(&example) {
+                        self.seen.insert(example.clone());
+                        return Some(example);
+                    }
+                }
+            }
+            self.current = Some(self.sources.next()?);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{InMemoryExampleDatabase, ReadOnlyDatabase};
+    use std::sync::Arc;
+
+    fn sorted(db: &impl ExampleDatabase, key: &Input) -> Vec<Example> {
+        let mut values = db.fetch(key).expect("Fetch failed").into_vec();
+        values.sort_unstable();
+        values
+    }
+
+    #[test]
+    fn multiplexed_dbs_read_and_write_all() {
+        let a = Arc::new(InMemoryExampleDatabase::new());
+        let b = Arc::new(InMemoryExampleDatabase::new());
+        let mut multi = MultiplexedDatabase::new();
+        multi.push(a.clone());
+        multi.push(b.clone());
+        a.save(b"a", b"aa").expect("Save failed");
+        b.save(b"b", b"bb").expect("Save failed");
+        multi.save(b"c", b"cc").expect("Save failed");
+        multi.r#move(b"a", b"b", b"aa").expect("Move failed");
+        for db in &[&a, &b] {
+            assert!(sorted(*db, b"a").is_empty());
+            assert_eq!(sorted(*db, b"c"), vec![b"cc".to_vec()]);
+        }
+        assert!(sorted(&multi, b"a").is_empty());
+        assert_eq!(sorted(&multi, b"c"), vec![b"cc".to_vec()]);
+        let got = multi.fetch(b"b").expect("Fetch failed").into_vec();
+        assert_eq!(got.len(), 2);
+        assert_eq!(sorted(&multi, b"b"), vec![b"aa".to_vec(), b"bb".to_vec()]);
+        multi.delete(b"c", b"cc").expect("Delete failed");
+        for db in &[&a, &b] {
+            assert!(sorted(*db, b"c").is_empty());
+        }
+        assert!(sorted(&multi, b"c").is_empty());
+    }
+
+    #[test]
+    fn shared_readonly_database_is_not_written() {
+        let shared = Arc::new(InMemoryExampleDatabase::new());
+        shared.save(b"key", b"shared").expect("Save failed");
+        let local = Arc::new(InMemoryExampleDatabase::new());
+        let mut multi = MultiplexedDatabase::new();
+        multi.push(local.clone());
+        multi.push(ReadOnlyDatabase::new(shared.clone()));
+        multi.save(b"key", b"local").expect("Save failed");
+        multi.delete(b"key", b"shared").expect("Delete failed");
+        assert_eq!(
+            sorted(&multi, b"key"),
+            vec![b"local".to_vec(), b"shared".to_vec()]
+        );
+        assert_eq!(sorted(&shared, b"key"), vec![b"shared".to_vec()]);
+        assert_eq!(sorted(&local, b"key"), vec![b"local".to_vec()]);
+    }
+
+    #[test]
+    fn fetch_yields_unique_values() {
+        let mut multi = MultiplexedDatabase::new();
+        multi.push(InMemoryExampleDatabase::new());
+        multi.push(InMemoryExampleDatabase::new());
+        multi.push(InMemoryExampleDatabase::new());
+        multi.save(b"foo", b"bar").expect("Save failed");
+        assert_eq!(
+            multi.fetch(b"foo").expect("Fetch failed").into_vec(),
+            vec![b"bar".to_vec()]
+        );
+    }
+
+    #[test]
+    fn empty_multiplexer_has_no_values() {
+        let multi = MultiplexedDatabase::new();
+        assert!(multi.is_empty());
+        multi.save(b"foo", b"bar").expect("Save failed");
+        assert!(multi
+            .fetch(b"foo")
+            .expect("Fetch failed")
+            .into_vec()
+            .is_empty());
+    }
+}
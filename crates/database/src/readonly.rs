@@ -0,0 +1,94 @@
+use crate::fetch::Fetch;
+use crate::{DatabaseError, ExampleDatabase, Input};
+
+/// A wrapper to make the given database read-only.
+///
+/// The implementation passes through `fetch`, and turns `save`, `delete`, and `move` into silent
+/// no-ops.
+///
+/// Note that this disables Hypothesis' automatic discarding of stale examples. It is designed to
+/// allow local machines access to a shared database (e.g. from CI servers), without propagating
+/// changes back from a local or in-development branch.
+#[derive(Debug)]
+pub struct ReadOnlyDatabase<D: ExampleDatabase> {
+    inner: D,
+}
+
+impl<D: ExampleDatabase> ReadOnlyDatabase<D> {
+    /// Wrap a database to make it read-only.
+    pub fn new(inner: D) -> ReadOnlyDatabase<D> {
+        ReadOnlyDatabase { inner }
+    }
+
+    /// Get a reference to the wrapped database.
+    #[inline]
+    pub fn get_ref(&self) -> &D {
+        &self.inner
+    }
+
+    /// Unwrap the database, making it writable again.
+    #[inline]
+    pub fn into_inner(self) -> D {
+        self.inner
+    }
+}
+
+impl<D: ExampleDatabase> ExampleDatabase for ReadOnlyDatabase<D> {
+    type Source = D::Source;
+
+    #[inline]
+    fn save(&self, _: &Input, _: &Input) -> Result<(), DatabaseError> {
+        Ok(())
+    }
+
+    #[inline]
+    fn delete(&self, _: &Input, _: &Input) -> Result<(), DatabaseError> {
+        Ok(())
+    }
+
+    #[inline]
+    fn r#move(&self, _: &Input, _: &Input, _: &Input) -> Result<(), DatabaseError> {
+        Ok(())
+    }
+
+    #[inline]
+    fn fetch(&self, key: &Input) -> Result<Fetch<Self::Source>, DatabaseError> {
+        self.inner.fetch(key)
+    }
+
+    #[inline]
+    fn keys(&self) -> Result<Vec<Vec<u8>>, DatabaseError> {
+        self.inner.keys()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Example, InMemoryExampleDatabase};
+
+    fn sorted(db: &impl ExampleDatabase, key: &Input) -> Vec<Example> {
+        let mut values = db.fetch(key).expect("Fetch failed").into_vec();
+        values.sort_unstable();
+        values
+    }
+
+    #[test]
+    fn readonly_db_is_not_writable() {
+        let inner = InMemoryExampleDatabase::new();
+        inner.save(b"key", b"value").expect("Save failed");
+        inner.save(b"key", b"value2").expect("Save failed");
+        let wrapped = ReadOnlyDatabase::new(inner);
+        wrapped.delete(b"key", b"value").expect("Delete failed");
+        wrapped
+            .r#move(b"key", b"key2", b"value2")
+            .expect("Move failed");
+        wrapped.save(b"key", b"value3").expect("Save failed");
+        assert_eq!(
+            sorted(&wrapped, b"key"),
+            vec![b"value".to_vec(), b"value2".to_vec()]
+        );
+        assert!(sorted(&wrapped, b"key2").is_empty());
+        assert_eq!(sorted(wrapped.get_ref(), b"key").len(), 2);
+    }
+}
@@ -0,0 +1,824 @@
+use crate::fetch::Fetch;
+use crate::{DatabaseError, Example, ExampleDatabase, Input};
+use ahash::AHashSet;
+use std::convert::TryFrom;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::TcpStream;
+use std::sync::{Mutex, PoisonError};
+use std::time::Duration;
+
+/// Prefix of Redis keys that `RedisExampleDatabase::new` uses, the same as in `hypothesis-redis`.
+pub const DEFAULT_KEY_PREFIX: &[u8] = b"hypothesis-example:";
+/// How long examples are kept after the last access to their key, the same as in
+/// `hypothesis-redis`.
+pub const DEFAULT_EXPIRE_AFTER: Duration = Duration::from_secs(8 * 24 * 60 * 60);
+/// How long to wait for the server before giving up.
+const TIMEOUT: Duration = Duration::from_secs(5);
+/// Number of keys that the server is asked to check per `SCAN` call.
+const SCAN_COUNT: &[u8] = b"1000";
+
+/// A reply in the Redis serialization protocol (RESP).
+#[derive(Debug, Clone, Eq, PartialEq)]
+enum Reply {
+    Status(String),
+    Error(String),
+    Integer(i64),
+    Bulk(Option<Vec<u8>>),
+    Array(Option<Vec<Reply>>),
+}
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+fn parse_integer(input: &[u8]) -> io::Result<i64> {
+    std::str::from_utf8(input)
+        .ok()
+        .and_then(|input| input.parse().ok())
+        .ok_or_else(|| invalid_data("invalid integer in reply"))
+}
+
+/// Longest bulk string that is accepted from the server, the same as Redis' default
+/// `proto-max-bulk-len`.
+const MAX_BULK_LEN: usize = 512 * 1024 * 1024;
+
+fn read_line(reader: &mut impl BufRead) -> io::Result<Vec<u8>> {
+    let mut line = vec![];
+    reader.read_until(b'\n', &mut line)?;
+    if !line.ends_with(b"\r\n") {
+        return Err(io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            "incomplete reply",
+        ));
+    }
+    line.truncate(line.len().saturating_sub(2));
+    Ok(line)
+}
+
+fn read_reply(reader: &mut impl BufRead) -> io::Result<Reply> {
+    let line = read_line(reader)?;
+    let (kind, rest) = line
+        .split_first()
+        .ok_or_else(|| invalid_data("empty reply"))?;
+    match kind {
+        b'+' => Ok(Reply::Status(String::from_utf8_lossy(rest).into_owned())),
+        b'-' => Ok(Reply::Error(String::from_utf8_lossy(rest).into_owned())),
+        b':' => Ok(Reply::Integer(parse_integer(rest)?)),
+        b'$' => match usize::try_from(parse_integer(rest)?) {
+            // Negative lengths are nulls
+            Err(_) => Ok(Reply::Bulk(None)),
+            Ok(len) if len > MAX_BULK_LEN => Err(invalid_data("bulk string is too long")),
+            Ok(len) => {
+                let expected = len.saturating_add(2);
+                // Read incrementally, so a wrong length doesn't allocate more than received
+                let mut data = vec![];
+                reader
+                    .by_ref()
+                    .take(expected as u64)
+                    .read_to_end(&mut data)?;
+                if data.len() != expected {
+                    return Err(io::Error::new(
+                        io::ErrorKind::UnexpectedEof,
+                        "incomplete reply",
+                    ));
+                }
+                if !data.ends_with(b"\r\n") {
+                    return Err(invalid_data("bulk string is not terminated"));
+                }
+                data.truncate(len);
+                Ok(Reply::Bulk(Some(data)))
+            }
+        },
+        b'*' => match usize::try_from(parse_integer(rest)?) {
+            Err(_) => Ok(Reply::Array(None)),
+            Ok(len) => {
+                // Not preallocated, the length comes from the server as well
+                let mut items = vec![];
+                for _ in 0..len {
+                    items.push(read_reply(reader)?);
+                }
+                Ok(Reply::Array(Some(items)))
+            }
+        },
+        _ => Err(invalid_data("unknown reply type")),
+    }
+}
+
+/// Commands are sent as arrays of bulk strings.
+fn encode_command(buffer: &mut Vec<u8>, args: &[&[u8]]) {
+    buffer.extend_from_slice(format!("*{}\r\n", args.len()).as_bytes());
+    for arg in args {
+        buffer.extend_from_slice(format!("${}\r\n", arg.len()).as_bytes());
+        buffer.extend_from_slice(arg);
+        buffer.extend_from_slice(b"\r\n");
+    }
+}
+
+/// Escape glob special characters, so the prefix is matched literally by `SCAN`.
+fn escape_pattern(input: &[u8]) -> Vec<u8> {
+    let mut escaped = Vec::with_capacity(input.len());
+    for byte in input {
+        if matches!(byte, b'*' | b'?' | b'[' | b']' | b'\\') {
+            escaped.push(b'\\');
+        }
+        escaped.push(*byte);
+    }
+    escaped
+}
+
+#[derive(Debug)]
+struct Connection {
+    stream: BufReader<TcpStream>,
+}
+
+impl Connection {
+    fn open(address: &str) -> io::Result<Connection> {
+        let stream = TcpStream::connect(address)?;
+        stream.set_read_timeout(Some(TIMEOUT))?;
+        stream.set_write_timeout(Some(TIMEOUT))?;
+        stream.set_nodelay(true)?;
+        Ok(Connection {
+            stream: BufReader::new(stream),
+        })
+    }
+
+    /// Send all commands at once and read their replies.
+    fn pipeline(&mut self, commands: &[&[&[u8]]]) -> io::Result<Vec<Reply>> {
+        let mut buffer = vec![];
+        for command in commands {
+            encode_command(&mut buffer, command);
+        }
+        self.stream.get_mut().write_all(&buffer)?;
+        commands
+            .iter()
+            .map(|_| read_reply(&mut self.stream))
+            .collect()
+    }
+}
+
+#[derive(Debug)]
+/// Store Hypothesis examples in Redis, so they can be shared between several machines.
+///
+/// The layout is compatible with `RedisExampleDatabase` from `hypothesis-redis`: every key is a
+/// Redis set of values, named as the key with a prefix. Keys expire if they aren't used for a
+/// while, every operation on a key extends its lifetime.
+///
+/// Errors refer to the server address instead of a path. The connection is reopened if it fails,
+/// and the database can be shared between threads, e.g. via `Arc`.
+pub struct RedisExampleDatabase {
+    address: String,
+    key_prefix: Vec<u8>,
+    expire_after: Duration,
+    connection: Mutex<Option<Connection>>,
+}
+
+impl RedisExampleDatabase {
+    /// Connect to the Redis server at `address`, e.g. `127.0.0.1:6379`.
+    pub fn new<A: AsRef<str>>(address: A) -> Result<RedisExampleDatabase, DatabaseError> {
+        RedisExampleDatabase::with_options(address, DEFAULT_KEY_PREFIX, DEFAULT_EXPIRE_AFTER)
+    }
+
+    /// Connect to the Redis server at `address`, storing keys under `key_prefix` and expiring
+    /// them once they are unused for `expire_after`.
+    pub fn with_options<A: AsRef<str>>(
+        address: A,
+        key_prefix: &[u8],
+        expire_after: Duration,
+    ) -> Result<RedisExampleDatabase, DatabaseError> {
+        let address = address.as_ref();
+        let connection =
+            Connection::open(address).map_err(|error| DatabaseError::io(address, error))?;
+        Ok(RedisExampleDatabase {
+            address: address.to_string(),
+            key_prefix: key_prefix.to_vec(),
+            expire_after,
+            connection: Mutex::new(Some(connection)),
+        })
+    }
+
+    /// Address of the Redis server.
+    #[inline]
+    pub fn address(&self) -> &str {
+        &self.address
+    }
+
+    /// Prefix of the Redis keys.
+    #[inline]
+    pub fn key_prefix(&self) -> &[u8] {
+        &self.key_prefix
+    }
+
+    fn redis_key(&self, key: &Input) -> Vec<u8> {
+        let mut redis_key = self.key_prefix.clone();
+        redis_key.extend_from_slice(key);
+        redis_key
+    }
+
+    fn pipeline(&self, commands: &[&[&[u8]]]) -> Result<Vec<Reply>, DatabaseError> {
+        // A failed connection is dropped, therefore the stored one is usable even if the lock is
+        // poisoned
+        let mut connection = self
+            .connection
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        let result = match connection.as_mut() {
+            Some(connection) => connection.pipeline(commands),
+            None => Connection::open(&self.address)
+                .and_then(|new| connection.insert(new).pipeline(commands)),
+        };
+        result.map_err(|error| {
+            // Replies to the failed commands may arrive later, so the connection is out of sync
+            *connection = None;
+            DatabaseError::io(&self.address, error)
+        })
+    }
+
+    /// Run commands in a transaction, returning their results.
+    fn transaction(&self, commands: &[&[&[u8]]]) -> Result<Vec<Reply>, DatabaseError> {
+        let mut all: Vec<&[&[u8]]> = Vec::with_capacity(commands.len().saturating_add(2));
+        all.push(&[b"MULTI"]);
+        all.extend_from_slice(commands);
+        all.push(&[b"EXEC"]);
+        match self.pipeline(&all)?.pop() {
+            Some(Reply::Array(Some(results))) => {
+                for result in &results {
+                    if let Reply::Error(message) = result {
+                        return Err(self.reply_error(message));
+                    }
+                }
+                Ok(results)
+            }
+            Some(Reply::Error(message)) => Err(self.reply_error(&message)),
+            _ => Err(self.protocol_error("unexpected reply to EXEC")),
+        }
+    }
+
+    /// Refresh the expiration of the given keys after running commands on them.
+    fn update(&self, keys: &[&[u8]], commands: &[&[&[u8]]]) -> Result<Vec<Reply>, DatabaseError> {
+        let seconds = self.expire_after.as_secs().to_string();
+        let expirations: Vec<[&[u8]; 3]> = keys
+            .iter()
+            .map(|key| [&b"EXPIRE"[..], key, seconds.as_bytes()])
+            .collect();
+        let mut all = commands.to_vec();
+        all.extend(expirations.iter().map(|command| &command[..]));
+        self.transaction(&all)
+    }
+
+    fn reply_error(&self, message: &str) -> DatabaseError {
+        let address = self.address.clone().into();
+        if message.starts_with("WRONGTYPE") {
+            DatabaseError::Corrupted(address, message.to_string())
+        } else if message.starts_with("NOAUTH") || message.starts_with("NOPERM") {
+            DatabaseError::PermissionDenied(
+                address,
+                io::Error::new(io::ErrorKind::PermissionDenied, message),
+            )
+        } else {
+            DatabaseError::Io(address, io::Error::other(message))
+        }
+    }
+
+    fn protocol_error(&self, message: &str) -> DatabaseError {
+        DatabaseError::Io(self.address.clone().into(), invalid_data(message))
+    }
+}
+
+impl ExampleDatabase for RedisExampleDatabase {
+    type Source = Vec<Example>;
+
+    #[inline]
+    fn save(&self, key: &Input, value: &Input) -> Result<(), DatabaseError> {
+        let key = self.redis_key(key);
+        self.update(&[&key], &[&[b"SADD", &key, value]]).map(|_| ())
+    }
+
+    #[inline]
+    fn delete(&self, key: &Input, value: &Input) -> Result<(), DatabaseError> {
+        let key = self.redis_key(key);
+        self.update(&[&key], &[&[b"SREM", &key, value]]).map(|_| ())
+    }
+
+    #[inline]
+    fn r#move(&self, src: &Input, dst: &Input, value: &Input) -> Result<(), DatabaseError> {
+        if src == dst {
+            return self.save(src, value);
+        }
+        let src = self.redis_key(src);
+        let dst = self.redis_key(dst);
+        self.update(
+            &[&src, &dst],
+            &[&[b"SREM", &src, value], &[b"SADD", &dst, value]],
+        )
+        .map(|_| ())
+    }
+
+    #[inline]
+    fn fetch(&self, key: &Input) -> Result<Fetch<Self::Source>, DatabaseError> {
+        let key = self.redis_key(key);
+        match self.update(&[&key], &[&[b"SMEMBERS", &key]])?.first() {
+            Some(Reply::Array(Some(members))) => members
+                .iter()
+                .map(|member| match member {
+                    Reply::Bulk(Some(value)) => Ok(value.clone()),
+                    _ => Err(self.protocol_error("set member is not a string")),
+                })
+                .collect::<Result<_, _>>()
+                .map(Fetch::new),
+            _ => Err(self.protocol_error("unexpected reply to SMEMBERS")),
+        }
+    }
+
+    #[inline]
+    fn keys(&self) -> Result<Vec<Vec<u8>>, DatabaseError> {
+        let mut pattern = escape_pattern(&self.key_prefix);
+        pattern.push(b'*');
+        let mut cursor = b"0".to_vec();
+        // `SCAN` may return the same key several times
+        let mut keys = AHashSet::new();
+        loop {
+            let reply = self
+                .pipeline(&[&[b"SCAN", &cursor, b"MATCH", &pattern, b"COUNT", SCAN_COUNT]])?
+                .pop();
+            let items = match reply {
+                Some(Reply::Array(Some(items))) => items,
+                Some(Reply::Error(message)) => return Err(self.reply_error(&message)),
+                _ => return Err(self.protocol_error("unexpected reply to SCAN")),
+            };
+            match items.as_slice() {
+                [Reply::Bulk(Some(next)), Reply::Array(Some(found))] => {
+                    for key in found {
+                        if let Reply::Bulk(Some(key)) = key {
+                            if let Some(key) = key.strip_prefix(self.key_prefix.as_slice()) {
+                                keys.insert(key.to_vec());
+                            }
+                        }
+                    }
+                    cursor = next.clone();
+                }
+                _ => return Err(self.protocol_error("unexpected reply to SCAN")),
+            }
+            if cursor == b"0" {
+                return Ok(keys.into_iter().collect());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests_util {
+    use super::*;
+    use ahash::AHashMap;
+    use std::net::{Shutdown, TcpListener};
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+    use std::thread;
+
+    #[derive(Debug)]
+    enum Value {
+        Set(AHashSet<Vec<u8>>),
+        String,
+    }
+
+    #[derive(Debug, Default)]
+    struct Data {
+        values: AHashMap<Vec<u8>, Value>,
+        expirations: AHashMap<Vec<u8>, i64>,
+    }
+
+    /// A minimal in-process stand-in for a Redis server that supports only the commands used by
+    /// `RedisExampleDatabase`. Keys are never expired, their expiration is only recorded.
+    #[derive(Debug)]
+    pub(crate) struct TestServer {
+        address: String,
+        data: Arc<Mutex<Data>>,
+        stopped: Arc<AtomicBool>,
+        connections: Arc<Mutex<Vec<TcpStream>>>,
+    }
+
+    fn write_reply(buffer: &mut Vec<u8>, reply: &Reply) {
+        match reply {
+            Reply::Status(status) => {
+                buffer.extend_from_slice(format!("+{}\r\n", status).as_bytes())
+            }
+            Reply::Error(error) => buffer.extend_from_slice(format!("-{}\r\n", error).as_bytes()),
+            Reply::Integer(value) => buffer.extend_from_slice(format!(":{}\r\n", value).as_bytes()),
+            Reply::Bulk(None) => buffer.extend_from_slice(b"$-1\r\n"),
+            Reply::Bulk(Some(data)) => {
+                buffer.extend_from_slice(format!("${}\r\n", data.len()).as_bytes());
+                buffer.extend_from_slice(data);
+                buffer.extend_from_slice(b"\r\n");
+            }
+            Reply::Array(None) => buffer.extend_from_slice(b"*-1\r\n"),
+            Reply::Array(Some(items)) => {
+                buffer.extend_from_slice(format!("*{}\r\n", items.len()).as_bytes());
+                for item in items {
+                    write_reply(buffer, item);
+                }
+            }
+        }
+    }
+
+    /// Glob matching with `*`, `?` and `\` escapes, enough for `SCAN` patterns.
+    fn matches(pattern: &[u8], input: &[u8]) -> bool {
+        match pattern.split_first() {
+            None => input.is_empty(),
+            Some((b'*', rest)) => (0..=input.len()).any(|start| matches(rest, &input[start..])),
+            Some((b'?', rest)) => !input.is_empty() && matches(rest, &input[1..]),
+            Some((b'\\', rest)) if !rest.is_empty() => {
+                input.first() == rest.first() && matches(&rest[1..], &input[1..])
+            }
+            Some((byte, rest)) => input.first() == Some(byte) && matches(rest, &input[1..]),
+        }
+    }
+
+    fn wrong_type() -> Reply {
+        Reply::Error(
+            "WRONGTYPE Operation against a key holding the wrong kind of value".to_string(),
+        )
+    }
+
+    impl Data {
+        fn remove(&mut self, key: &[u8]) {
+            self.values.remove(key);
+            self.expirations.remove(key);
+        }
+
+        fn execute(&mut self, args: &[Vec<u8>]) -> Reply {
+            let name = args[0].to_ascii_uppercase();
+            match (name.as_slice(), &args[1..]) {
+                (b"PING", []) => Reply::Status("PONG".to_string()),
+                (b"SET", [key, _value]) => {
+                    self.remove(key);
+                    self.values.insert(key.clone(), Value::String);
+                    Reply::Status("OK".to_string())
+                }
+                (b"SADD", [key, members @ ..]) if !members.is_empty() => {
+                    match self
+                        .values
+                        .entry(key.clone())
+                        .or_insert_with(|| Value::Set(AHashSet::new()))
+                    {
+                        Value::Set(set) => Reply::Integer(
+                            members
+                                .iter()
+                                .filter(|member| set.insert(member.to_vec()))
+                                .count() as i64,
+                        ),
+                        Value::String => wrong_type(),
+                    }
+                }
+                (b"SREM", [key, members @ ..]) if !members.is_empty() => {
+                    match self.values.get_mut(key) {
+                        None => Reply::Integer(0),
+                        Some(Value::Set(set)) => {
+                            let removed =
+                                members.iter().filter(|member| set.remove(*member)).count();
+                            // Redis removes empty sets
+                            if set.is_empty() {
+                                self.remove(key);
+                            }
+                            Reply::Integer(removed as i64)
+                        }
+                        Some(Value::String) => wrong_type(),
+                    }
+                }
+                (b"SMEMBERS", [key]) => match self.values.get(key) {
+                    None => Reply::Array(Some(vec![])),
+                    Some(Value::Set(set)) => Reply::Array(Some(
+                        set.iter()
+                            .map(|member| Reply::Bulk(Some(member.clone())))
+                            .collect(),
+                    )),
+                    Some(Value::String) => wrong_type(),
+                },
+                (b"EXPIRE", [key, seconds]) => {
+                    if self.values.contains_key(key) {
+                        let seconds = parse_integer(seconds).expect("Valid expiration");
+                        self.expirations.insert(key.clone(), seconds);
+                        Reply::Integer(1)
+                    } else {
+                        Reply::Integer(0)
+                    }
+                }
+                // The whole keyspace is returned in one go
+                (b"SCAN", [_cursor, options @ ..]) => {
+                    let pattern = match options {
+                        [option, pattern, ..] if option.eq_ignore_ascii_case(b"MATCH") => {
+                            pattern.as_slice()
+                        }
+                        _ => b"*",
+                    };
+                    let keys = self
+                        .values
+                        .keys()
+                        .filter(|key| matches(pattern, key))
+                        .map(|key| Reply::Bulk(Some(key.clone())))
+                        .collect();
+                    Reply::Array(Some(vec![
+                        Reply::Bulk(Some(b"0".to_vec())),
+                        Reply::Array(Some(keys)),
+                    ]))
+                }
+                _ => Reply::Error(format!(
+                    "ERR unknown command or wrong number of arguments for '{}'",
+                    String::from_utf8_lossy(&args[0])
+                )),
+            }
+        }
+    }
+
+    fn read_command(reader: &mut impl BufRead) -> io::Result<Vec<Vec<u8>>> {
+        match read_reply(reader)? {
+            Reply::Array(Some(items)) if !items.is_empty() => items
+                .into_iter()
+                .map(|item| match item {
+                    Reply::Bulk(Some(arg)) => Ok(arg),
+                    _ => Err(invalid_data("command arguments must be strings")),
+                })
+                .collect(),
+            _ => Err(invalid_data("commands must be arrays")),
+        }
+    }
+
+    fn serve(stream: TcpStream, data: &Mutex<Data>) -> io::Result<()> {
+        // Replies to pipelined commands are written one by one, they shouldn't wait for each other
+        stream.set_nodelay(true)?;
+        let mut writer = stream.try_clone()?;
+        let mut reader = BufReader::new(stream);
+        let mut queued: Option<Vec<Vec<Vec<u8>>>> = None;
+        loop {
+            let args = read_command(&mut reader)?;
+            let name = args[0].to_ascii_uppercase();
+            let reply = match (name.as_slice(), queued.as_mut()) {
+                (b"MULTI", None) => {
+                    queued = Some(vec![]);
+                    Reply::Status("OK".to_string())
+                }
+                (b"EXEC", Some(_)) => {
+                    let commands = queued.take().unwrap_or_default();
+                    let mut data = data.lock().expect("Not poisoned");
+                    Reply::Array(Some(
+                        commands
+                            .iter()
+                            .map(|command| data.execute(command))
+                            .collect(),
+                    ))
+                }
+                (b"MULTI", Some(_)) => {
+                    Reply::Error("ERR MULTI calls can not be nested".to_string())
+                }
+                (b"EXEC", None) => Reply::Error("ERR EXEC without MULTI".to_string()),
+                (_, Some(commands)) => {
+                    commands.push(args);
+                    Reply::Status("QUEUED".to_string())
+                }
+                (_, None) => data.lock().expect("Not poisoned").execute(&args),
+            };
+            let mut buffer = vec![];
+            write_reply(&mut buffer, &reply);
+            writer.write_all(&buffer)?;
+        }
+    }
+
+    impl TestServer {
+        pub(crate) fn start() -> TestServer {
+            let listener = TcpListener::bind("127.0.0.1:0").expect("Can't bind a port");
+            let address = listener
+                .local_addr()
+                .expect("Listener has an address")
+                .to_string();
+            let data = Arc::new(Mutex::new(Data::default()));
+            let stopped = Arc::new(AtomicBool::new(false));
+            let connections = Arc::new(Mutex::new(vec![]));
+            {
+                let data = Arc::clone(&data);
+                let stopped = Arc::clone(&stopped);
+                let connections = Arc::clone(&connections);
+                thread::spawn(move || {
+                    for stream in listener.incoming() {
+                        if stopped.load(Ordering::SeqCst) {
+                            break;
+                        }
+                        let stream = match stream {
+                            Ok(stream) => stream,
+                            Err(_) => continue,
+                        };
+                        if let Ok(clone) = stream.try_clone() {
+                            connections.lock().expect("Not poisoned").push(clone);
+                        }
+                        let data = Arc::clone(&data);
+                        // Disconnected clients end their threads
+                        thread::spawn(move || serve(stream, &data));
+                    }
+                });
+            }
+            TestServer {
+                address,
+                data,
+                stopped,
+                connections,
+            }
+        }
+
+        pub(crate) fn address(&self) -> &str {
+            &self.address
+        }
+
+        /// Members of the set stored under the given Redis key, sorted.
+        pub(crate) fn members(&self, key: &[u8]) -> Vec<Vec<u8>> {
+            let data = self.data.lock().expect("Not poisoned");
+            let mut members: Vec<_> = match data.values.get(key) {
+                Some(Value::Set(set)) => set.iter().cloned().collect(),
+                _ => vec![],
+            };
+            members.sort_unstable();
+            members
+        }
+
+        /// Expiration of the given Redis key in seconds, if it is set.
+        pub(crate) fn expiration(&self, key: &[u8]) -> Option<i64> {
+            self.data
+                .lock()
+                .expect("Not poisoned")
+                .expirations
+                .get(key)
+                .copied()
+        }
+
+        /// Store a plain string, which is not a valid key for the database.
+        pub(crate) fn set(&self, key: &[u8], value: &[u8]) {
+            self.data.lock().expect("Not poisoned").execute(&[
+                b"SET".to_vec(),
+                key.to_vec(),
+                value.to_vec(),
+            ]);
+        }
+
+        /// Close all open connections, as if the server restarted.
+        pub(crate) fn disconnect_clients(&self) {
+            for stream in self.connections.lock().expect("Not poisoned").drain(..) {
+                let _ = stream.shutdown(Shutdown::Both);
+            }
+        }
+    }
+
+    impl Drop for TestServer {
+        fn drop(&mut self) {
+            self.stopped.store(true, Ordering::SeqCst);
+            self.disconnect_clients();
+            // Wake up the accepting thread, so it notices that the server is stopped
+            let _ = TcpStream::connect(&self.address);
+        }
+    }
+
+    pub(crate) type TestDatabase =
+        crate::tests_util::TestDatabase<RedisExampleDatabase, TestServer>;
+
+    impl TestDatabase {
+        pub(crate) fn new() -> Self {
+            let server = TestServer::start();
+            let db = RedisExampleDatabase::new(server.address()).expect("Server is reachable");
+            TestDatabase::with_guard(db, server)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::tests_util::TestServer;
+    use super::*;
+    use std::net::TcpListener;
+
+    #[test]
+    fn replies_are_parsed() {
+        let mut input = &b"*3\r\n$3\r\nfoo\r\n$-1\r\n:5\r\n"[..];
+        assert!(matches!(
+            read_reply(&mut input),
+            Ok(Reply::Array(Some(items))) if matches!(
+                items.as_slice(),
+                [Reply::Bulk(Some(value)), Reply::Bulk(None), Reply::Integer(5)] if value == b"foo"
+            )
+        ));
+    }
+
+    #[test]
+    fn bulk_strings_longer_than_the_limit_are_rejected() {
+        let mut input = &b"$1000000000000\r\nfoo\r\n"[..];
+        assert_eq!(
+            read_reply(&mut input).map_err(|error| error.kind()).err(),
+            Some(io::ErrorKind::InvalidData)
+        );
+    }
+
+    #[test]
+    fn truncated_bulk_strings_are_rejected() {
+        // The length is within the limit, but the data isn't there
+        let mut input = &b"$100000000\r\nfoo\r\n"[..];
+        assert_eq!(
+            read_reply(&mut input).map_err(|error| error.kind()).err(),
+            Some(io::ErrorKind::UnexpectedEof)
+        );
+    }
+
+    #[test]
+    fn two_redis_databases_can_interact() {
+        let server = TestServer::start();
+        let db1 = RedisExampleDatabase::new(server.address()).expect("Server is reachable");
+        let db2 = RedisExampleDatabase::new(server.address()).expect("Server is reachable");
+        db1.save(b"foo", b"bar").expect("Save failed");
+        assert_eq!(
+            db2.fetch(b"foo").expect("Fetch failed").into_vec(),
+            vec![b"bar"]
+        );
+        db2.r#move(b"foo", b"moved", b"bar").expect("Move failed");
+        assert!(db1
+            .fetch(b"foo")
+            .expect("Fetch failed")
+            .into_vec()
+            .is_empty());
+        assert_eq!(db1.keys().expect("Keys are listable"), vec![b"moved"]);
+    }
+
+    #[test]
+    fn layout_is_compatible_with_hypothesis_redis() {
+        let server = TestServer::start();
+        let db = RedisExampleDatabase::new(server.address()).expect("Server is reachable");
+        db.save(b"foo", b"bar").expect("Save failed");
+        db.save(b"foo", b"\x00baz").expect("Save failed");
+        assert_eq!(
+            server.members(b"hypothesis-example:foo"),
+            vec![b"\x00baz".to_vec(), b"bar".to_vec()]
+        );
+        assert_eq!(
+            server.expiration(b"hypothesis-example:foo"),
+            Some(8 * 24 * 60 * 60)
+        );
+        assert_eq!(db.address(), server.address());
+        assert_eq!(db.key_prefix(), DEFAULT_KEY_PREFIX);
+    }
+
+    #[test]
+    fn keys_outside_of_the_prefix_are_ignored() {
+        let server = TestServer::start();
+        let db = RedisExampleDatabase::with_options(
+            server.address(),
+            b"custom*:",
+            Duration::from_secs(60),
+        )
+        .expect("Server is reachable");
+        let other = RedisExampleDatabase::new(server.address()).expect("Server is reachable");
+        db.save(b"foo", b"bar").expect("Save failed");
+        other.save(b"other", b"bar").expect("Save failed");
+        server.set(b"custom-not-a-prefix:key", b"value");
+        assert_eq!(db.keys().expect("Keys are listable"), vec![b"foo"]);
+        assert_eq!(server.members(b"custom*:foo"), vec![b"bar".to_vec()]);
+        assert_eq!(server.expiration(b"custom*:foo"), Some(60));
+        assert!(db
+            .fetch(b"other")
+            .expect("Fetch failed")
+            .into_vec()
+            .is_empty());
+    }
+
+    #[test]
+    fn other_values_are_reported_as_corruption() {
+        let server = TestServer::start();
+        let db = RedisExampleDatabase::new(server.address()).expect("Server is reachable");
+        server.set(b"hypothesis-example:foo", b"value");
+        assert!(matches!(
+            db.fetch(b"foo"),
+            Err(DatabaseError::Corrupted(path, _)) if path.to_str() == Some(server.address())
+        ));
+        assert!(matches!(
+            db.save(b"foo", b"bar"),
+            Err(DatabaseError::Corrupted(_, _))
+        ));
+    }
+
+    #[test]
+    fn connection_is_reopened() {
+        let server = TestServer::start();
+        let db = RedisExampleDatabase::new(server.address()).expect("Server is reachable");
+        db.save(b"foo", b"bar").expect("Save failed");
+        server.disconnect_clients();
+        assert!(matches!(db.fetch(b"foo"), Err(DatabaseError::Io(_, _))));
+        assert_eq!(
+            db.fetch(b"foo").expect("Fetch failed").into_vec(),
+            vec![b"bar"]
+        );
+    }
+
+    #[test]
+    fn unreachable_server_is_an_error() {
+        let address = TcpListener::bind("127.0.0.1:0")
+            .expect("Can't bind a port")
+            .local_addr()
+            .expect("Listener has an address")
+            .to_string();
+        assert!(matches!(
+            RedisExampleDatabase::new(&address),
+            Err(DatabaseError::Io(path, _)) if path.to_str() == Some(address.as_str())
+        ));
+    }
+}
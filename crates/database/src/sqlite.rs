@@ -0,0 +1,224 @@
+use crate::fetch::Fetch;
+use crate::{DatabaseError, Example, ExampleDatabase, Input};
+use rusqlite::{params, Connection, ErrorCode, TransactionBehavior};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, PoisonError};
+use std::time::Duration;
+
+/// How long to wait for other connections, e.g. from other processes, to release the database.
+const BUSY_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Debug)]
+/// Store Hypothesis examples in a single `SQLite` file.
+///
+/// Every operation is a single transaction, so the file is always consistent, even if it is shared
+/// between several processes. The database can be shared between threads, e.g. via `Arc`.
+pub struct SqliteExampleDatabase {
+    path: PathBuf,
+    connection: Mutex<Connection>,
+}
+
+fn sqlite_error(path: &Path, error: rusqlite::Error) -> DatabaseError {
+    let code = match &error {
+        rusqlite::Error::SqliteFailure(failure, _) => Some(failure.code),
+        _ => None,
+    };
+    match code {
+        Some(ErrorCode::DatabaseCorrupt) | Some(ErrorCode::NotADatabase) => {
+            DatabaseError::Corrupted(path.to_path_buf(), error.to_string())
+        }
+        Some(ErrorCode::PermissionDenied) | Some(ErrorCode::ReadOnly) => {
+            DatabaseError::PermissionDenied(
+                path.to_path_buf(),
+                io::Error::new(io::ErrorKind::PermissionDenied, error),
+            )
+        }
+        _ => DatabaseError::io(path, io::Error::other(error)),
+    }
+}
+
+impl SqliteExampleDatabase {
+    /// Open the `SQLite` database at `path`, creating it if it doesn't exist.
+    pub fn new<P: AsRef<Path>>(path: P) -> Result<SqliteExampleDatabase, DatabaseError> {
+        let path = path.as_ref();
+        let connection = Connection::open(path).map_err(|error| sqlite_error(path, error))?;
+        connection
+            .busy_timeout(BUSY_TIMEOUT)
+            .map_err(|error| sqlite_error(path, error))?;
+        // Examples of one key are stored next to each other, which makes fetching them cheap
+        connection
+            .execute(
+                "CREATE TABLE IF NOT EXISTS examples (
+                    key BLOB NOT NULL,
+                    value BLOB NOT NULL,
+                    PRIMARY KEY (key, value)
+                ) WITHOUT ROWID",
+                [],
+            )
+            .map_err(|error| sqlite_error(path, error))?;
+        Ok(SqliteExampleDatabase {
+            path: path.to_path_buf(),
+            connection: Mutex::new(connection),
+        })
+    }
+
+    /// Path to the `SQLite` file.
+    #[inline]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn with_connection<T>(
+        &self,
+        f: impl FnOnce(&mut Connection) -> rusqlite::Result<T>,
+    ) -> Result<T, DatabaseError> {
+        // SQLite rolls back unfinished transactions, therefore the connection is usable even if
+        // the lock is poisoned
+        let mut connection = self
+            .connection
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        f(&mut connection).map_err(|error| sqlite_error(&self.path, error))
+    }
+}
+
+impl ExampleDatabase for SqliteExampleDatabase {
+    type Source = Vec<Example>;
+
+    #[inline]
+    fn save(&self, key: &Input, value: &Input) -> Result<(), DatabaseError> {
+        self.with_connection(|connection| {
+            connection
+                .prepare_cached("INSERT OR IGNORE INTO examples (key, value) VALUES (?, ?)")?
+                .execute(params![key, value])
+                .map(|_| ())
+        })
+    }
+
+    #[inline]
+    fn delete(&self, key: &Input, value: &Input) -> Result<(), DatabaseError> {
+        self.with_connection(|connection| {
+            connection
+                .prepare_cached("DELETE FROM examples WHERE key = ? AND value = ?")?
+                .execute(params![key, value])
+                .map(|_| ())
+        })
+    }
+
+    #[inline]
+    fn r#move(&self, src: &Input, dst: &Input, value: &Input) -> Result<(), DatabaseError> {
+        if src == dst {
+            return self.save(src, value);
+        }
+        self.with_connection(|connection| {
+            // Take the write lock upfront. A deferred transaction that starts with a read can't be
+            // upgraded while another connection writes, and then it fails without waiting for the
+            // busy timeout
+            let transaction =
+                connection.transaction_with_behavior(TransactionBehavior::Immediate)?;
+            transaction
+                .prepare_cached("DELETE FROM examples WHERE key = ? AND value = ?")?
+                .execute(params![src, value])?;
+            transaction
+                .prepare_cached("INSERT OR IGNORE INTO examples (key, value) VALUES (?, ?)")?
+                .execute(params![dst, value])?;
+            transaction.commit()
+        })
+    }
+
+    #[inline]
+    fn fetch(&self, key: &Input) -> Result<Fetch<Self::Source>, DatabaseError> {
+        let values = self.with_connection(|connection| {
+            connection
+                .prepare_cached("SELECT value FROM examples WHERE key = ?")?
+                .query_map(params![key], |row| row.get(0))?
+                .collect()
+        })?;
+        Ok(Fetch::new(values))
+    }
+
+    #[inline]
+    fn keys(&self) -> Result<Vec<Vec<u8>>, DatabaseError> {
+        self.with_connection(|connection| {
+            connection
+                .prepare_cached("SELECT DISTINCT key FROM examples")?
+                .query_map([], |row| row.get(0))?
+                .collect()
+        })
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests_util {
+    use super::*;
+    use tempdir::TempDir;
+
+    pub(crate) type TestDatabase = crate::tests_util::TestDatabase<SqliteExampleDatabase, TempDir>;
+
+    impl TestDatabase {
+        pub(crate) fn new() -> Self {
+            let dir = TempDir::new("test-sqlite").expect("Should always work");
+            let db = SqliteExampleDatabase::new(dir.path().join("examples.sqlite"))
+                .expect("Database is created");
+            TestDatabase::with_guard(db, dir)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempdir::TempDir;
+
+    #[test]
+    fn two_sqlite_databases_can_interact() {
+        let temp = TempDir::new("test-sqlite").expect("Should always work");
+        let path = temp.path().join("examples.sqlite");
+        let db1 = SqliteExampleDatabase::new(&path).expect("Database is created");
+        let db2 = SqliteExampleDatabase::new(&path).expect("Database is opened");
+        db1.save(b"foo", b"bar").expect("Save failed");
+        assert_eq!(
+            db2.fetch(b"foo").expect("Fetch failed").into_vec(),
+            vec![b"bar"]
+        );
+        db2.r#move(b"foo", b"moved", b"bar").expect("Move failed");
+        assert!(db1
+            .fetch(b"foo")
+            .expect("Fetch failed")
+            .into_vec()
+            .is_empty());
+        assert_eq!(
+            db1.fetch(b"moved").expect("Fetch failed").into_vec(),
+            vec![b"bar"]
+        );
+    }
+
+    #[test]
+    fn examples_persist() {
+        let temp = TempDir::new("test-sqlite").expect("Should always work");
+        let path = temp.path().join("examples.sqlite");
+        SqliteExampleDatabase::new(&path)
+            .expect("Database is created")
+            .save(b"foo", b"\x00bar")
+            .expect("Save failed");
+        let db = SqliteExampleDatabase::new(&path).expect("Database is opened");
+        assert_eq!(
+            db.fetch(b"foo").expect("Fetch failed").into_vec(),
+            vec![b"\x00bar"]
+        );
+        assert_eq!(db.path(), path);
+    }
+
+    #[test]
+    fn invalid_file_is_reported_as_corruption() {
+        let temp = TempDir::new("test-sqlite").expect("Should always work");
+        let path = temp.path().join("examples.sqlite");
+        fs::write(&path, vec![b'x'; 4096]).expect("Can't write a file");
+        assert!(matches!(
+            SqliteExampleDatabase::new(&path),
+            Err(DatabaseError::Corrupted(error_path, _)) if error_path == path
+        ));
+    }
+}
@@ -0,0 +1,41 @@
+use crate::fetch::Fetch;
+use crate::{DatabaseError, ExampleDatabase, Input};
+
+/// A database for tests, together with a resource that must outlive it, e.g. a temporary
+/// directory or a test server. Every backend provides its own constructor.
+#[derive(Debug)]
+pub(crate) struct TestDatabase<D, G> {
+    // Fields are dropped in order, so the database is closed before its resource is cleaned up
+    pub(crate) db: D,
+    _guard: G,
+}
+
+impl<D, G> TestDatabase<D, G> {
+    pub(crate) fn with_guard(db: D, guard: G) -> Self {
+        TestDatabase { db, _guard: guard }
+    }
+}
+
+impl<D: ExampleDatabase, G> ExampleDatabase for TestDatabase<D, G> {
+    type Source = D::Source;
+
+    fn save(&self, key: &Input, value: &Input) -> Result<(), DatabaseError> {
+        self.db.save(key, value)
+    }
+
+    fn delete(&self, key: &Input, value: &Input) -> Result<(), DatabaseError> {
+        self.db.delete(key, value)
+    }
+
+    fn r#move(&self, src: &Input, dst: &Input, value: &Input) -> Result<(), DatabaseError> {
+        self.db.r#move(src, dst, value)
+    }
+
+    fn fetch(&self, key: &Input) -> Result<Fetch<Self::Source>, DatabaseError> {
+        self.db.fetch(key)
+    }
+
+    fn keys(&self) -> Result<Vec<Vec<u8>>, DatabaseError> {
+        self.db.keys()
+    }
+}
@@ -0,0 +1,469 @@
+use crate::directory::file_name;
+use crate::listeners::Listeners;
+use crate::metadata;
+use crate::{ChangeEvent, DatabaseError, ListenerHandle};
+use ahash::{AHashMap, AHashSet};
+use notify::event::{ModifyKind, RenameMode};
+use notify::{EventKind, PollWatcher, RecursiveMode, Watcher};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration;
+
+/// How often `DirectoryWatcher::new` polls the directory if native notifications are unavailable.
+pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Watches a `DirectoryBasedExampleDatabase` directory and reports changes made by any process.
+///
+/// File creations and removals are translated into `ChangeEvent`s. The original key is taken from
+/// the metadata file of the key directory, changes of keys without metadata are not reported.
+/// Deleted values are unknown, as their files are already gone.
+///
+/// Watching stops when the watcher is dropped.
+pub struct DirectoryWatcher {
+    path: PathBuf,
+    listeners: Arc<Listeners>,
+    polling: bool,
+    _watcher: Box<dyn Watcher + Send>,
+}
+
+impl fmt::Debug for DirectoryWatcher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DirectoryWatcher")
+            .field("path", &self.path)
+            .field("listeners", &self.listeners)
+            .field("polling", &self.polling)
+            .finish()
+    }
+}
+
+fn notify_error(path: &Path, error: notify::Error) -> DatabaseError {
+    match error.kind {
+        notify::ErrorKind::Io(error) => DatabaseError::io(path, error),
+        _ => DatabaseError::io(path, io::Error::other(error)),
+    }
+}
+
+impl DirectoryWatcher {
+    /// Watch the directory with native notifications, e.g. inotify on Linux. If they are
+    /// unavailable, e.g. because the limit of inotify watches is reached, poll the directory
+    /// every `DEFAULT_POLL_INTERVAL` instead.
+    pub fn new<P: AsRef<Path>>(path: P) -> Result<DirectoryWatcher, DatabaseError> {
+        let (path, state) = DirectoryWatcher::prepare(path.as_ref())?;
+        let handler = Arc::clone(&state);
+        let native = notify::recommended_watcher(move |event| handle(&handler, event)).and_then(
+            |mut watcher| {
+                watcher
+                    .watch(&path, RecursiveMode::Recursive)
+                    .map(|_| watcher)
+            },
+        );
+        match native {
+            Ok(watcher) => Ok(DirectoryWatcher::start(path, &state, watcher, false)),
+            Err(_) => DirectoryWatcher::poll_with(path, &state, DEFAULT_POLL_INTERVAL),
+        }
+    }
+
+    /// Watch the directory by polling it with the given interval.
+    pub fn polling<P: AsRef<Path>>(
+        path: P,
+        interval: Duration,
+    ) -> Result<DirectoryWatcher, DatabaseError> {
+        let (path, state) = DirectoryWatcher::prepare(path.as_ref())?;
+        DirectoryWatcher::poll_with(path, &state, interval)
+    }
+
+    fn prepare(path: &Path) -> Result<(PathBuf, Arc<Mutex<State>>), DatabaseError> {
+        // The directory should exist to be watched
+        fs::create_dir_all(path).map_err(|error| DatabaseError::io(path, error))?;
+        let state = State {
+            root: path.to_path_buf(),
+            listeners: Arc::new(Listeners::default()),
+            keys: AHashMap::new(),
+            values: AHashSet::new(),
+        };
+        Ok((path.to_path_buf(), Arc::new(Mutex::new(state))))
+    }
+
+    fn poll_with(
+        path: PathBuf,
+        state: &Arc<Mutex<State>>,
+        interval: Duration,
+    ) -> Result<DirectoryWatcher, DatabaseError> {
+        let handler = Arc::clone(state);
+        let config = notify::Config::default().with_poll_interval(interval);
+        let mut watcher = PollWatcher::new(move |event| handle(&handler, event), config)
+            .map_err(|error| notify_error(&path, error))?;
+        watcher
+            .watch(&path, RecursiveMode::Recursive)
+            .map_err(|error| notify_error(&path, error))?;
+        Ok(DirectoryWatcher::start(path, state, watcher, true))
+    }
+
+    fn start(
+        path: PathBuf,
+        state: &Mutex<State>,
+        watcher: impl Watcher + Send + 'static,
+        polling: bool,
+    ) -> DirectoryWatcher {
+        // The scan happens after watching starts, so no changes are missed. Changes that are both
+        // scanned and reported by the watcher are deduplicated via the set of known values
+        let mut locked = state.lock().unwrap_or_else(PoisonError::into_inner);
+        locked.scan();
+        let listeners = Arc::clone(&locked.listeners);
+        drop(locked);
+        DirectoryWatcher {
+            path,
+            listeners,
+            polling,
+            _watcher: Box::new(watcher),
+        }
+    }
+
+    /// Register a function that is called after every observed change.
+    #[inline]
+    pub fn add_listener(
+        &self,
+        listener: impl Fn(&ChangeEvent) + Send + Sync + 'static,
+    ) -> ListenerHandle {
+        self.listeners.add(listener)
+    }
+
+    /// Stop calling the given listener. Returns whether it was registered.
+    #[inline]
+    pub fn remove_listener(&self, handle: ListenerHandle) -> bool {
+        self.listeners.remove(handle)
+    }
+
+    /// Whether the directory is polled instead of using native notifications.
+    #[inline]
+    pub fn is_polling(&self) -> bool {
+        self.polling
+    }
+}
+
+fn handle(state: &Mutex<State>, event: notify::Result<notify::Event>) {
+    // Errors can't be reported from the watcher thread, the affected changes are not reported
+    if let Ok(event) = event {
+        let mut changes = vec![];
+        let mut state = state.lock().unwrap_or_else(PoisonError::into_inner);
+        match event.kind {
+            EventKind::Create(_) | EventKind::Modify(ModifyKind::Name(RenameMode::To)) => {
+                for path in &event.paths {
+                    state.created(path, &mut changes);
+                }
+            }
+            EventKind::Remove(_) | EventKind::Modify(ModifyKind::Name(RenameMode::From)) => {
+                for path in &event.paths {
+                    state.removed(path, &mut changes);
+                }
+            }
+            // Some backends don't tell which side of a rename a path is on, or report both sides
+            // in one event. Whatever is still present was the destination
+            EventKind::Modify(ModifyKind::Name(RenameMode::Any | RenameMode::Both)) => {
+                for path in &event.paths {
+                    if path.exists() {
+                        state.created(path, &mut changes);
+                    } else {
+                        state.removed(path, &mut changes);
+                    }
+                }
+            }
+            _ => {}
+        }
+        let listeners = Arc::clone(&state.listeners);
+        // Listeners are called without holding any locks
+        drop(state);
+        for change in changes {
+            listeners.emit(|| change);
+        }
+    }
+}
+
+/// Translates filesystem changes into change events.
+struct State {
+    root: PathBuf,
+    listeners: Arc<Listeners>,
+    /// Original keys by names of their directories.
+    keys: AHashMap<String, Vec<u8>>,
+    /// Paths of all known values, so every change is reported once.
+    values: AHashSet<PathBuf>,
+}
+
+/// Kinds of paths in the database directory.
+enum Entry<'a> {
+    Metadata(&'a str),
+    KeyDirectory,
+    Value(&'a str),
+}
+
+impl State {
+    fn classify<'a>(&self, path: &'a Path) -> Option<Entry<'a>> {
+        let name = path.file_name()?.to_str()?;
+        let parent = path.parent()?;
+        if parent == self.root {
+            match name.split_once('.') {
+                Some((key_name, extension)) if extension == metadata::EXTENSION => {
+                    Some(Entry::Metadata(key_name))
+                }
+                Some(_) => None,
+                None => Some(Entry::KeyDirectory),
+            }
+        } else if parent.parent()? == self.root && path.extension().is_none() {
+            // Files with an extension are temporary ones, created by an in-progress `save`
+            Some(Entry::Value(parent.file_name()?.to_str()?))
+        } else {
+            None
+        }
+    }
+
+    /// Find existing keys and values without reporting them.
+    fn scan(&mut self) {
+        let entries = match fs::read_dir(&self.root) {
+            Ok(entries) => entries,
+            Err(_) => return,
+        };
+        for entry in entries.filter_map(Result::ok) {
+            let path = entry.path();
+            match self.classify(&path) {
+                Some(Entry::Metadata(key_name)) => {
+                    self.load_key(key_name, &path);
+                }
+                Some(Entry::KeyDirectory) => {
+                    if let Ok(values) = fs::read_dir(&path) {
+                        for value in values.filter_map(Result::ok) {
+                            if value.path().extension().is_none() {
+                                self.values.insert(value.path());
+                            }
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn load_key(&mut self, key_name: &str, path: &Path) -> Option<&Vec<u8>> {
+        if !self.keys.contains_key(key_name) {
+            let contents = fs::read_to_string(path).ok()?;
+            let (key, _) = metadata::decode(path, &contents).ok()?;
+            if file_name(&key).as_str() != key_name {
+                return None;
+            }
+            self.keys.insert(key_name.to_string(), key);
+        }
+        self.keys.get(key_name)
+    }
+
+    /// Original key of the given key directory, if it has valid metadata.
+    fn key(&mut self, key_name: &str) -> Option<Vec<u8>> {
+        let path = self
+            .root
+            .join(format!("{}.{}", key_name, metadata::EXTENSION));
+        self.load_key(key_name, &path).cloned()
+    }
+
+    /// Record a created path, adding the resulting changes to `changes`.
+    fn created(&mut self, path: &Path, changes: &mut Vec<ChangeEvent>) {
+        match self.classify(path) {
+            Some(Entry::Metadata(key_name)) => {
+                self.load_key(key_name, path);
+            }
+            // Values may be saved before the directory itself is watched
+            Some(Entry::KeyDirectory) => {
+                if let Ok(values) = fs::read_dir(path) {
+                    for value in values.filter_map(Result::ok) {
+                        self.created(&value.path(), changes);
+                    }
+                }
+            }
+            Some(Entry::Value(key_name)) => {
+                if self.values.contains(path) {
+                    return;
+                }
+                let key = match self.key(key_name) {
+                    Some(key) => key,
+                    None => return,
+                };
+                // The file may be already moved away
+                if let Ok(value) = fs::read(path) {
+                    self.values.insert(path.to_path_buf());
+                    changes.push(ChangeEvent::Save { key, value });
+                }
+            }
+            None => {}
+        }
+    }
+
+    /// Record a removed path, adding the resulting changes to `changes`.
+    fn removed(&mut self, path: &Path, changes: &mut Vec<ChangeEvent>) {
+        match self.classify(path) {
+            // A key directory may be removed or moved away as a whole
+            Some(Entry::KeyDirectory) => {
+                let removed: Vec<PathBuf> = self
+                    .values
+                    .iter()
+                    .filter(|value| value.parent() == Some(path))
+                    .cloned()
+                    .collect();
+                for value in removed {
+                    self.removed(&value, changes);
+                }
+            }
+            Some(Entry::Value(key_name)) => {
+                if self.values.remove(path) {
+                    if let Some(key) = self.key(key_name) {
+                        changes.push(ChangeEvent::Delete { key, value: None });
+                    }
+                }
+            }
+            Some(Entry::Metadata(_)) | None => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DirectoryBasedExampleDatabase, ExampleDatabase};
+    use notify::event::CreateKind;
+    use notify::Event;
+    use std::sync::mpsc;
+    use tempdir::TempDir;
+    use test_case::test_case;
+
+    const TIMEOUT: Duration = Duration::from_secs(10);
+
+    fn listen(watcher: &DirectoryWatcher) -> mpsc::Receiver<ChangeEvent> {
+        let (sender, receiver) = mpsc::channel();
+        let sender = Mutex::new(sender);
+        watcher.add_listener(move |event| {
+            let _ = sender.lock().expect("Not poisoned").send(event.clone());
+        });
+        receiver
+    }
+
+    fn saved(key: &[u8], value: &[u8]) -> ChangeEvent {
+        ChangeEvent::Save {
+            key: key.to_vec(),
+            value: value.to_vec(),
+        }
+    }
+
+    fn deleted(key: &[u8]) -> ChangeEvent {
+        ChangeEvent::Delete {
+            key: key.to_vec(),
+            value: None,
+        }
+    }
+
+    fn assert_reports_changes(watcher: &DirectoryWatcher, path: &Path) {
+        let events = listen(watcher);
+        // E.g. another process
+        let other = DirectoryBasedExampleDatabase::new(path);
+        other.save(b"foo", b"bar").expect("Save failed");
+        assert_eq!(events.recv_timeout(TIMEOUT), Ok(saved(b"foo", b"bar")));
+        other.r#move(b"foo", b"moved", b"bar").expect("Move failed");
+        other.delete(b"existing", b"value").expect("Delete failed");
+        let mut received = vec![];
+        for _ in 0..3 {
+            received.push(events.recv_timeout(TIMEOUT).expect("Event is reported"));
+        }
+        // The order depends on the notification mechanism
+        for event in &[
+            deleted(b"foo"),
+            saved(b"moved", b"bar"),
+            deleted(b"existing"),
+        ] {
+            assert!(received.contains(event), "{:?} in {:?}", event, received);
+        }
+        // Without metadata the key is unknown
+        fs::create_dir(path.join("0123456789abcdef")).expect("Can't create a directory");
+        fs::write(path.join("0123456789abcdef/fedcba9876543210"), b"").expect("Can't write");
+        other.save(b"last", b"value").expect("Save failed");
+        assert_eq!(events.recv_timeout(TIMEOUT), Ok(saved(b"last", b"value")));
+    }
+
+    #[test]
+    fn native_watcher_reports_changes() {
+        let temp = TempDir::new("test-watch").expect("Should always work");
+        let db = DirectoryBasedExampleDatabase::new(temp.path());
+        db.save(b"existing", b"value").expect("Save failed");
+        let watcher = db.watch().expect("Directory is watchable");
+        assert!(!watcher.is_polling());
+        assert_reports_changes(&watcher, temp.path());
+    }
+
+    #[test]
+    fn polling_watcher_reports_changes() {
+        let temp = TempDir::new("test-watch").expect("Should always work");
+        let db = DirectoryBasedExampleDatabase::new(temp.path());
+        db.save(b"existing", b"value").expect("Save failed");
+        let watcher = DirectoryWatcher::polling(temp.path(), Duration::from_millis(20))
+            .expect("Directory is watchable");
+        assert!(watcher.is_polling());
+        assert_reports_changes(&watcher, temp.path());
+    }
+
+    #[test_case(RenameMode::Both, 2; "both")]
+    #[test_case(RenameMode::Any, 1; "any")]
+    fn renames_without_direction_are_reported(mode: RenameMode, paths_per_event: usize) {
+        let temp = TempDir::new("test-watch").expect("Should always work");
+        let db = DirectoryBasedExampleDatabase::new(temp.path());
+        db.save(b"foo", b"bar").expect("Save failed");
+        db.save(b"moved", b"other").expect("Save failed");
+        let (_, state) = DirectoryWatcher::prepare(temp.path()).expect("Directory is watchable");
+        let mut locked = state.lock().expect("Not poisoned");
+        locked.scan();
+        let (sender, receiver) = mpsc::channel();
+        let sender = Mutex::new(sender);
+        locked.listeners.add(move |event| {
+            let _ = sender.lock().expect("Not poisoned").send(event.clone());
+        });
+        drop(locked);
+        let from = temp
+            .path()
+            .join(file_name(b"foo").as_str())
+            .join(file_name(b"bar").as_str());
+        let to = temp
+            .path()
+            .join(file_name(b"moved").as_str())
+            .join(file_name(b"bar").as_str());
+        fs::rename(&from, &to).expect("Can't rename");
+        for paths in [from, to].chunks(paths_per_event) {
+            let event = paths.iter().fold(
+                Event::new(EventKind::Modify(ModifyKind::Name(mode))),
+                |event, path| event.add_path(path.clone()),
+            );
+            handle(&state, Ok(event));
+        }
+        let received: Vec<ChangeEvent> = receiver.try_iter().collect();
+        assert_eq!(received, vec![deleted(b"foo"), saved(b"moved", b"bar")]);
+    }
+
+    #[test]
+    fn listeners_are_called_without_the_lock() {
+        let temp = TempDir::new("test-watch").expect("Should always work");
+        let db = DirectoryBasedExampleDatabase::new(temp.path());
+        let (_, state) = DirectoryWatcher::prepare(temp.path()).expect("Directory is watchable");
+        let (sender, receiver) = mpsc::channel();
+        let sender = Mutex::new(sender);
+        let shared = Arc::clone(&state);
+        state.lock().expect("Not poisoned").listeners.add(move |_| {
+            let unlocked = shared.try_lock().is_ok();
+            let _ = sender.lock().expect("Not poisoned").send(unlocked);
+        });
+        db.save(b"foo", b"bar").expect("Save failed");
+        let path = temp
+            .path()
+            .join(file_name(b"foo").as_str())
+            .join(file_name(b"bar").as_str());
+        handle(
+            &state,
+            Ok(Event::new(EventKind::Create(CreateKind::File)).add_path(path)),
+        );
+        assert_eq!(receiver.try_iter().collect::<Vec<_>>(), vec![true]);
+    }
+}
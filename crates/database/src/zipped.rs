@@ -0,0 +1,253 @@
+use crate::archive::{read_keys, zip_error};
+use crate::directory::file_name;
+use crate::fetch::Fetch;
+use crate::{DatabaseError, Example, ExampleDatabase, Input};
+use ahash::AHashMap;
+use std::fs::File;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, PoisonError};
+use zip::ZipArchive;
+
+/// A read-only database that serves examples from a zip archive with a
+/// `DirectoryBasedExampleDatabase` tree, without extracting it.
+///
+/// It is the equivalent of Python Hypothesis' `GitHubArtifactDatabase`, but for an artifact that
+/// is already downloaded. Archives produced by `export` work as well. As with `ReadOnlyDatabase`,
+/// `save`, `delete`, and `move` are silent no-ops, so it can be combined with a local database
+/// via `MultiplexedDatabase`.
+///
+/// Keys are listed only if the archive has metadata for them, see `export`.
+#[derive(Debug)]
+pub struct ZipExampleDatabase {
+    path: PathBuf,
+    archive: Mutex<ZipArchive<File>>,
+    /// Indices of value entries by names of their key directories.
+    values: AHashMap<String, Vec<usize>>,
+    /// Original keys by names of their key directories.
+    keys: AHashMap<String, Vec<u8>>,
+}
+
+impl ZipExampleDatabase {
+    /// Open a zip archive at `path` and index its entries.
+    pub fn new<P: AsRef<Path>>(path: P) -> Result<ZipExampleDatabase, DatabaseError> {
+        let path = path.as_ref();
+        let file = File::open(path).map_err(|error| DatabaseError::io(path, error))?;
+        let mut archive = ZipArchive::new(file).map_err(|error| zip_error(path, error))?;
+        let keys = read_keys(&mut archive, path)?;
+        let mut values: AHashMap<String, Vec<usize>> = AHashMap::new();
+        for idx in 0..archive.len() {
+            let entry = archive
+                .by_index(idx)
+                .map_err(|error| zip_error(path, error))?;
+            if entry.is_dir() {
+                continue;
+            }
+            if let Some((key_name, value_name)) = entry.name().split_once('/') {
+                // Leftovers of an interrupted `save` in the archived directory
+                if !value_name.contains('.') && !value_name.contains('/') {
+                    values.entry(key_name.to_string()).or_default().push(idx);
+                }
+            }
+        }
+        Ok(ZipExampleDatabase {
+            path: path.to_path_buf(),
+            archive: Mutex::new(archive),
+            values,
+            keys,
+        })
+    }
+
+    /// Path to the archive.
+    #[inline]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl ExampleDatabase for ZipExampleDatabase {
+    type Source = Vec<Example>;
+
+    #[inline]
+    fn save(&self, _: &Input, _: &Input) -> Result<(), DatabaseError> {
+        Ok(())
+    }
+
+    #[inline]
+    fn delete(&self, _: &Input, _: &Input) -> Result<(), DatabaseError> {
+        Ok(())
+    }
+
+    #[inline]
+    fn r#move(&self, _: &Input, _: &Input, _: &Input) -> Result<(), DatabaseError> {
+        Ok(())
+    }
+
+    #[inline]
+    fn fetch(&self, key: &Input) -> Result<Fetch<Self::Source>, DatabaseError> {
+        let indices = match self.values.get(file_name(key).as_str()) {
+            Some(indices) => indices,
+            None => return Ok(Fetch::new(vec![])),
+        };
+        // Every read seeks to its entry first, therefore a panic midway doesn't affect other reads
+        let mut archive = self.archive.lock().unwrap_or_else(PoisonError::into_inner);
+        let mut examples = Vec::with_capacity(indices.len());
+        for idx in indices {
+            let mut entry = archive
+                .by_index(*idx)
+                .map_err(|error| zip_error(&self.path, error))?;
+            let mut value = vec![];
+            entry
+                .read_to_end(&mut value)
+                .map_err(|error| DatabaseError::io(&self.path, error))?;
+            examples.push(value);
+        }
+        Ok(Fetch::new(examples))
+    }
+
+    #[inline]
+    fn keys(&self) -> Result<Vec<Vec<u8>>, DatabaseError> {
+        Ok(self
+            .keys
+            .iter()
+            .filter(|(key_name, _)| self.values.contains_key(key_name.as_str()))
+            .map(|(_, key)| key.clone())
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{export_all, InMemoryExampleDatabase, MultiplexedDatabase};
+    use std::fs;
+    use std::io::Write;
+    use tempdir::TempDir;
+    use zip::write::FileOptions;
+    use zip::ZipWriter;
+
+    fn sorted(db: &impl ExampleDatabase, key: &Input) -> Vec<Example> {
+        let mut values = db.fetch(key).expect("Fetch failed").into_vec();
+        values.sort_unstable();
+        values
+    }
+
+    fn exported(temp: &TempDir) -> ZipExampleDatabase {
+        let source = InMemoryExampleDatabase::new();
+        source.save(b"foo", b"bar").expect("Save failed");
+        source.save(b"foo", b"baz").expect("Save failed");
+        source.save(b"key", b"value").expect("Save failed");
+        let archive = temp.path().join("examples.zip");
+        export_all(&source, &archive).expect("Export failed");
+        ZipExampleDatabase::new(&archive).expect("Valid archive")
+    }
+
+    #[test]
+    fn serves_exported_examples() {
+        let temp = TempDir::new("test-zip").expect("Should always work");
+        let db = exported(&temp);
+        assert_eq!(sorted(&db, b"foo"), vec![b"bar".to_vec(), b"baz".to_vec()]);
+        assert_eq!(sorted(&db, b"key"), vec![b"value".to_vec()]);
+        assert!(sorted(&db, b"unknown").is_empty());
+        let mut keys = db.keys().expect("Keys are listable");
+        keys.sort_unstable();
+        assert_eq!(keys, vec![b"foo".to_vec(), b"key".to_vec()]);
+    }
+
+    #[test]
+    fn writes_are_ignored() {
+        let temp = TempDir::new("test-zip").expect("Should always work");
+        let db = exported(&temp);
+        db.save(b"foo", b"new").expect("Save failed");
+        db.delete(b"foo", b"bar").expect("Delete failed");
+        db.r#move(b"key", b"other", b"value").expect("Move failed");
+        assert_eq!(sorted(&db, b"foo"), vec![b"bar".to_vec(), b"baz".to_vec()]);
+        assert_eq!(sorted(&db, b"key"), vec![b"value".to_vec()]);
+        assert!(sorted(&db, b"other").is_empty());
+    }
+
+    #[test]
+    fn can_be_multiplexed_with_local_database() {
+        let temp = TempDir::new("test-zip").expect("Should always work");
+        let mut multi = MultiplexedDatabase::new();
+        multi.push(InMemoryExampleDatabase::new());
+        multi.push(exported(&temp));
+        multi.save(b"foo", b"local").expect("Save failed");
+        multi.delete(b"foo", b"bar").expect("Delete failed");
+        assert_eq!(
+            sorted(&multi, b"foo"),
+            vec![b"bar".to_vec(), b"baz".to_vec(), b"local".to_vec()]
+        );
+    }
+
+    #[test]
+    fn reads_archives_without_metadata() {
+        // E.g. a zipped directory written by Python Hypothesis
+        let temp = TempDir::new("test-zip").expect("Should always work");
+        let archive = temp.path().join("examples.zip");
+        let key_name = file_name(b"foo");
+        let mut writer = ZipWriter::new(File::create(&archive).expect("Can't create a file"));
+        for (name, value) in &[
+            (format!("{}/{}", key_name, file_name(b"bar")), b"bar"),
+            (format!("{}/0123456789abcdef.tmp", key_name), b"tmp"),
+        ] {
+            writer
+                .start_file(name.as_str(), FileOptions::default())
+                .expect("Can't add a file");
+            writer.write_all(*value).expect("Can't write a file");
+        }
+        writer.finish().expect("Can't write an archive");
+        let db = ZipExampleDatabase::new(&archive).expect("Valid archive");
+        assert_eq!(sorted(&db, b"foo"), vec![b"bar".to_vec()]);
+        assert!(db.keys().expect("Keys are listable").is_empty());
+    }
+
+    #[test]
+    fn damaged_metadata_loses_only_its_key() {
+        let temp = TempDir::new("test-zip").expect("Should always work");
+        let archive = temp.path().join("examples.zip");
+        let mut writer = ZipWriter::new(File::create(&archive).expect("Can't create a file"));
+        for (name, contents) in &[
+            (
+                format!("{}.meta", file_name(b"foo")),
+                "key=666f6f\ncreated=0\n",
+            ),
+            (
+                format!("{}/{}", file_name(b"foo"), file_name(b"bar")),
+                "bar",
+            ),
+            (format!("{}.meta", file_name(b"bad")), "key=+f\ncreated=0\n"),
+            (
+                format!("{}/{}", file_name(b"bad"), file_name(b"value")),
+                "value",
+            ),
+        ] {
+            writer
+                .start_file(name.as_str(), FileOptions::default())
+                .expect("Can't add a file");
+            writer
+                .write_all(contents.as_bytes())
+                .expect("Can't write a file");
+        }
+        writer.finish().expect("Can't write an archive");
+        let db = ZipExampleDatabase::new(&archive).expect("Valid archive");
+        assert_eq!(db.keys().expect("Keys are listable"), vec![b"foo".to_vec()]);
+        assert_eq!(sorted(&db, b"foo"), vec![b"bar".to_vec()]);
+        assert_eq!(sorted(&db, b"bad"), vec![b"value".to_vec()]);
+    }
+
+    #[test]
+    fn missing_archive_is_an_error() {
+        let temp = TempDir::new("test-zip").expect("Should always work");
+        let archive = temp.path().join("missing.zip");
+        assert!(matches!(
+            ZipExampleDatabase::new(&archive),
+            Err(DatabaseError::Io(path, _)) if path == archive
+        ));
+        fs::write(&archive, b"not an archive").expect("Can't write a file");
+        assert!(matches!(
+            ZipExampleDatabase::new(&archive),
+            Err(DatabaseError::Corrupted(path, _)) if path == archive
+        ));
+    }
+}
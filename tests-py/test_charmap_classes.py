@@ -0,0 +1,85 @@
+import unicodedata
+
+import pytest
+
+import charmap
+from charmap import IntervalSet, UnicodeVersion
+
+
+def test_module_version():
+    assert charmap.unicode_version == unicodedata.unidata_version
+
+
+@pytest.mark.parametrize("value, expected", (("12.1", "12.1.0"), ("13", "13.0.0"), ("9.0.0", "9.0.0")))
+def test_version(value, expected):
+    version = UnicodeVersion(value)
+    assert version.version == expected
+    assert str(version) == expected
+    assert version == UnicodeVersion(expected)
+    assert hash(version) == hash(UnicodeVersion(expected))
+
+
+def test_unsupported_version():
+    with pytest.raises(ValueError):
+        UnicodeVersion("8.0.0")
+
+
+def test_versions_differ():
+    # Georgian Mtavruli capital letters were added in Unicode 11.0
+    old = UnicodeVersion("10.0.0").query(include_categories=["Lu"])
+    new = UnicodeVersion("11.0.0").query(include_categories=["Lu"])
+    assert 0x1C90 not in old
+    assert 0x1C90 in new
+    assert old != new
+    assert len(new - old) > 0
+
+
+def test_same_as_module():
+    version = UnicodeVersion(charmap.unicode_version)
+    assert version.charmap() == charmap.charmap()
+    assert version.categories() == charmap.categories()
+    assert version.as_general_categories(["N"]) == charmap.as_general_categories(["N"])
+    assert version.query(include_categories=["Lu"]).intervals == charmap.query(include_categories=["Lu"])
+
+
+def test_query_errors():
+    version = UnicodeVersion("13.0.0")
+    with pytest.raises(TypeError):
+        version.query(include_categories=["XX"])
+    with pytest.raises(AssertionError):
+        version.query(min_codepoint=10, max_codepoint=1)
+
+
+def test_interval_set():
+    letters = IntervalSet([(65, 90)])
+    digits = IntervalSet.from_string("0123456789")
+    both = letters | digits
+    assert both.intervals == ((48, 57), (65, 90))
+    assert len(both) == 36
+    assert both[0] == 48
+    assert both[10] == 65
+    assert both[-1] == 90
+    assert list(both) == list(range(48, 58)) + list(range(65, 91))
+    assert "A" in both
+    assert 48 in both
+    assert "a" not in both
+    assert both.index(65) == 10
+    assert both & digits == digits
+    assert both - digits == letters
+    assert repr(letters) == "IntervalSet([(65, 90)])"
+
+
+def test_interval_set_errors():
+    value = IntervalSet([(1, 2)])
+    with pytest.raises(IndexError):
+        value[2]
+    with pytest.raises(IndexError):
+        value[-3]
+    with pytest.raises(ValueError):
+        value.index(3)
+    with pytest.raises(ValueError):
+        IntervalSet([(2, 1)])
+    with pytest.raises(ValueError):
+        IntervalSet([(0, 2 ** 32 - 1), (1, 2)])
+    with pytest.raises(TypeError):
+        "ab" in value
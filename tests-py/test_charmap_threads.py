@@ -0,0 +1,66 @@
+import threading
+from concurrent.futures import ThreadPoolExecutor
+
+import charmap
+from charmap import UnicodeVersion
+
+THREADS = 16
+ITERATIONS = 200
+
+QUERIES = (
+    {},
+    {"include_categories": ["Lu", "Nd"]},
+    {"exclude_categories": ["Cn", "Co", "Cs"], "max_codepoint": 0x3000},
+    {"include_categories": ["Zs"], "include_characters": "☃", "exclude_characters": " "},
+    {"min_codepoint": 100, "max_codepoint": 50000, "include_characters": "\U00010A07"},
+)
+
+
+def test_concurrent_queries():
+    expected = [charmap.query(**kwargs) for kwargs in QUERIES]
+    versions = UnicodeVersion.supported()
+    expected_sets = {str(version): [version.query(**kwargs) for kwargs in QUERIES] for version in versions}
+    barrier = threading.Barrier(THREADS)
+
+    def worker(seed):
+        barrier.wait()
+        for iteration in range(ITERATIONS):
+            index = (seed + iteration) % len(QUERIES)
+            assert charmap.query(**QUERIES[index]) == expected[index]
+            version = versions[(seed + iteration) % len(versions)]
+            assert version.query(**QUERIES[index]) == expected_sets[str(version)][index]
+        return charmap.charmap(), charmap.categories()
+
+    with ThreadPoolExecutor(max_workers=THREADS) as executor:
+        results = list(executor.map(worker, range(THREADS)))
+    # Cached objects are shared by all threads
+    for charmap_, categories in results:
+        assert charmap_ is charmap.charmap()
+        assert categories is charmap.categories()
+
+
+def test_concurrent_cache_initialization():
+    versions = UnicodeVersion.supported()
+    barrier = threading.Barrier(THREADS)
+
+    def worker(seed):
+        barrier.wait()
+        version = versions[seed % len(versions)]
+        return str(version), version.charmap(), version.categories()
+
+    with ThreadPoolExecutor(max_workers=THREADS) as executor:
+        for version, charmap_, categories in executor.map(worker, range(THREADS)):
+            assert charmap_ == UnicodeVersion(version).charmap()
+            assert categories == UnicodeVersion(version).categories()
+
+
+def test_errors_in_threads():
+    def worker(_):
+        try:
+            charmap.query(include_categories=["XX"])
+        except TypeError:
+            return True
+        return False
+
+    with ThreadPoolExecutor(max_workers=THREADS) as executor:
+        assert all(executor.map(worker, range(THREADS * 4)))
@@ -16,7 +16,11 @@ import os
 
 import pytest
 
-from database import InMemoryExampleDatabase, DirectoryBasedExampleDatabase
+from database import (
+    InMemoryExampleDatabase,
+    DirectoryBasedExampleDatabase,
+    ReadOnlyDatabase,
+)
 
 # These tests are adapted from the following file:
 # https://github.com/HypothesisWorks/hypothesis/blob/master/hypothesis-python/tests/cover/test_database_backend.py
@@ -96,3 +100,51 @@ def test_can_handle_disappearing_files(tmpdir, monkeypatch):
         os, "listdir", lambda d: base_listdir(d) + ["this-does-not-exist"]
     )
     assert list(db.fetch(b"foo")) == [b"bar"]
+
+
+def test_readonly_db_is_not_writable():
+    inner = InMemoryExampleDatabase()
+    wrapped = ReadOnlyDatabase(inner)
+    inner.save(b"key", b"value")
+    inner.save(b"key", b"value2")
+    wrapped.delete(b"key", b"value")
+    wrapped.move(b"key", b"key2", b"value2")
+    wrapped.save(b"key", b"value3")
+    assert set(wrapped.fetch(b"key")) == {b"value", b"value2"}
+    assert set(wrapped.fetch(b"key2")) == set()
+
+
+def test_readonly_db_wraps_directory_db(tmpdir):
+    inner = DirectoryBasedExampleDatabase(str(tmpdir))
+    inner.save(b"key", b"value")
+    wrapped = ReadOnlyDatabase(inner)
+    wrapped.save(b"key", b"value2")
+    assert list(wrapped.fetch(b"key")) == [b"value"]
+    assert "ReadOnlyDatabase" in repr(wrapped)
+
+
+def test_unusable_root_raises_os_error(tmpdir):
+    root = tmpdir.join("file")
+    root.write_binary(b"")
+    db = DirectoryBasedExampleDatabase(str(root))
+    with pytest.raises(OSError) as exc_info:
+        db.save(b"foo", b"bar")
+    assert exc_info.value.filename.startswith(str(root))
+
+
+def test_corrupted_key_raises_os_error(tmpdir):
+    path = str(tmpdir)
+    db = DirectoryBasedExampleDatabase(path)
+    db.save(b"foo", b"bar")
+    # Metadata files are stored next to key directories
+    (key,) = [
+        name for name in os.listdir(path) if os.path.isdir(os.path.join(path, name))
+    ]
+    key_path = os.path.join(path, key)
+    for name in os.listdir(key_path):
+        os.remove(os.path.join(key_path, name))
+    os.rmdir(key_path)
+    with open(key_path, "wb"):
+        pass
+    with pytest.raises(OSError, match="corrupted"):
+        db.fetch(b"foo")